@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::api_key::ApiKey;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiKeyResponse {
+  pub id: String,
+  pub name: String,
+  pub prefix: String,
+  pub restaurant_id: Option<String>,
+  pub scopes: Vec<String>,
+  pub is_active: bool,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub last_used_at: Option<DateTime<Utc>>,
+  pub revoked_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+  fn from(api_key: ApiKey) -> Self {
+    Self {
+      id: api_key.id.map(|id| id.id.to_string()).unwrap_or_default(),
+      name: api_key.name,
+      prefix: api_key.prefix,
+      restaurant_id: api_key.restaurant_id.map(|restaurant| restaurant.id.to_string()),
+      scopes: api_key.scopes.iter().map(|scope| scope.to_string()).collect(),
+      is_active: api_key.is_active,
+      expires_at: api_key.expires_at,
+      last_used_at: api_key.last_used_at,
+      revoked_at: api_key.revoked_at,
+      created_at: api_key.created_at,
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateApiKeyRequest {
+  pub name: String,
+  // Permisos en formato "recurso:acción", ej. ["orders:read", "orders:update"]
+  pub scopes: Vec<String>,
+  pub expires_at: Option<DateTime<Utc>>,
+}
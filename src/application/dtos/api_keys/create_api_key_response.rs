@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+use crate::application::dtos::api_keys::api_key_response::ApiKeyResponse;
+
+// `key` solo se devuelve al crearla, después no se puede recuperar
+#[derive(Debug, Serialize)]
+pub struct CreateApiKeyResponse {
+  pub key: String,
+  #[serde(flatten)]
+  pub api_key: ApiKeyResponse,
+}
@@ -0,0 +1,3 @@
+pub mod create_api_key_request;
+pub mod create_api_key_response;
+pub mod api_key_response;
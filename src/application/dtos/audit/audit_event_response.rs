@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domain::entities::audit_event::AuditEvent;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditEventResponse {
+  pub id: String,
+  pub action: String,
+  pub outcome: String,
+  pub actor_id: Option<String>,
+  pub impersonator_id: Option<String>,
+  pub target_type: Option<String>,
+  pub target_id: Option<String>,
+  pub ip_address: Option<String>,
+  pub user_agent: Option<String>,
+  pub reason: Option<String>,
+  pub changes: Option<Value>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditEvent> for AuditEventResponse {
+  fn from(event: AuditEvent) -> Self {
+    Self {
+      id: event.id.map(|id| id.id.to_string()).unwrap_or_default(),
+      action: event.action.to_string(),
+      outcome: event.outcome.to_string(),
+      actor_id: event.actor_id.map(|actor| actor.id.to_string()),
+      impersonator_id: event.impersonator_id.map(|impersonator| impersonator.id.to_string()),
+      target_type: event.target_type,
+      target_id: event.target_id,
+      ip_address: event.ip_address,
+      user_agent: event.user_agent,
+      reason: event.reason,
+      changes: event.changes,
+      created_at: event.created_at,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditPageResponse {
+  pub items: Vec<AuditEventResponse>,
+  pub page: u64,
+  pub per_page: u64,
+  pub total: u64,
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// Parámetros de `GET /api/v1/audit`; todos opcionales. `from` y `to` en RFC 3339.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AuditQueryRequest {
+  pub actor_id: Option<String>,
+  pub impersonator_id: Option<String>,
+  pub target_type: Option<String>,
+  pub target_id: Option<String>,
+  pub action: Option<String>,
+  pub outcome: Option<String>,
+  pub ip_address: Option<String>,
+  pub from: Option<DateTime<Utc>>,
+  pub to: Option<DateTime<Utc>>,
+  pub page: Option<u64>,
+  pub per_page: Option<u64>,
+}
@@ -0,0 +1,2 @@
+pub mod audit_query_request;
+pub mod audit_event_response;
\ No newline at end of file
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::address::Address;
+use crate::domain::value_objects::business_hours::BusinessHours;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateLocationRequest {
+  pub name: String,
+  pub address: Address,
+  pub timezone: String,
+  pub currency: String,
+  #[serde(default)]
+  pub business_hours: Vec<BusinessHours>,
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::location::Location;
+use crate::domain::value_objects::address::Address;
+use crate::domain::value_objects::business_hours::BusinessHours;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocationResponse {
+  pub id: String,
+  pub name: String,
+  pub address: Address,
+  pub timezone: String,
+  pub currency: String,
+  pub business_hours: Vec<BusinessHours>,
+  pub is_active: bool,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl From<Location> for LocationResponse {
+  fn from(location: Location) -> Self {
+    Self {
+      id: location.id.map(|id| id.id.to_string()).unwrap_or_default(),
+      name: location.name,
+      address: location.address,
+      timezone: location.timezone,
+      currency: location.currency,
+      business_hours: location.business_hours,
+      is_active: location.is_active,
+      created_at: location.created_at,
+      updated_at: location.updated_at,
+    }
+  }
+}
@@ -0,0 +1,3 @@
+pub mod create_location_request;
+pub mod update_location_request;
+pub mod location_response;
\ No newline at end of file
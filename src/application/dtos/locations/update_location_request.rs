@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::address::Address;
+use crate::domain::value_objects::business_hours::BusinessHours;
+
+// Los campos que no vienen conservan su valor
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateLocationRequest {
+  pub name: Option<String>,
+  pub address: Option<Address>,
+  pub timezone: Option<String>,
+  pub currency: Option<String>,
+  pub business_hours: Option<Vec<BusinessHours>>,
+}
@@ -8,4 +8,21 @@ pub struct LoginResponse {
   pub access_token: String,
   pub refresh_token: String,
   pub access_token_expires_at: DateTime<Utc>,
+}
+
+// Primer paso de un login con 2FA: credenciales correctas, falta el segundo factor
+#[derive(Debug, Serialize)]
+pub struct MfaChallengeResponse {
+  pub mfa_required: bool,
+  pub mfa_token: String,
+  pub mfa_token_expires_at: DateTime<Utc>,
+  // La política exige 2FA pero el usuario todavía no lo configuró
+  pub enrollment_required: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum LoginOutcome {
+  Authenticated(LoginResponse),
+  MfaRequired(MfaChallengeResponse),
 }
\ No newline at end of file
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct MfaEnrollmentResponse {
+  pub secret: String,
+  pub provisioning_uri: String,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::user_types::UserType;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MfaPolicyRequest {
+  // Tipos que se suman a los de la plataforma; None deja solo los de la plataforma
+  pub required_user_types: Option<Vec<UserType>>,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::user_types::UserType;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MfaPolicyResponse {
+  // Política efectiva del restaurante
+  pub required_user_types: Vec<UserType>,
+  // true si el restaurante no añade tipos a la política de la plataforma
+  pub platform_default: bool,
+}
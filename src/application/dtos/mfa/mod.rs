@@ -0,0 +1,9 @@
+pub mod mfa_code_request;
+pub mod mfa_challenge_request;
+pub mod verify_mfa_request;
+pub mod mfa_enrollment_response;
+pub mod recovery_codes_response;
+pub mod verify_mfa_response;
+
+pub mod mfa_policy_request;
+pub mod mfa_policy_response;
\ No newline at end of file
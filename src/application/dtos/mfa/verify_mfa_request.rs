@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+// Se envía `code` (TOTP) o `recovery_code`, no ambos
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerifyMfaRequest {
+  pub mfa_token: String,
+  pub code: Option<String>,
+  pub recovery_code: Option<String>,
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+use crate::application::dtos::login::login_response::LoginResponse;
+
+#[derive(Debug, Serialize)]
+pub struct VerifyMfaResponse {
+  #[serde(flatten)]
+  pub login: LoginResponse,
+  // Solo viene cuando este paso completó el enrolamiento exigido por la política
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub recovery_codes: Option<Vec<String>>,
+}
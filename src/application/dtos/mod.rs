@@ -1,6 +1,17 @@
 pub mod login;
 pub mod register;
+pub mod refresh;
+pub mod password;
+pub mod mfa;
+pub mod pos;
+pub mod api_keys;
 pub mod roles;
 pub mod permissions;
 pub mod profile;
-pub mod users;
\ No newline at end of file
+pub mod users;
+pub mod sessions;
+pub mod audit;
+pub mod oidc;
+pub mod restaurants;
+pub mod locations;
+pub mod subscriptions;
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod oidc_authorization_response;
+pub mod oidc_callback_request;
\ No newline at end of file
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+// El cliente redirige al usuario a `authorization_url`; el proveedor vuelve al callback con `state`
+#[derive(Debug, Serialize)]
+pub struct OidcAuthorizationResponse {
+  pub authorization_url: String,
+  pub state: String,
+  pub expires_at: DateTime<Utc>,
+}
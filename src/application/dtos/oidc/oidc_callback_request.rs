@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+// Parámetros con los que el proveedor redirige al callback; si el usuario cancela llega `error`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OidcCallbackRequest {
+  pub state: String,
+  pub code: Option<String>,
+  pub error: Option<String>,
+  pub error_description: Option<String>,
+}
@@ -0,0 +1,2 @@
+pub mod forgot_password_request;
+pub mod reset_password_request;
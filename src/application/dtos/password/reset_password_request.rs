@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResetPasswordRequest {
+  pub token: String,
+  pub password: String,
+}
@@ -0,0 +1,6 @@
+pub mod register_device_request;
+pub mod register_device_response;
+pub mod pos_device_response;
+pub mod set_pin_request;
+pub mod pin_login_request;
+pub mod pin_login_response;
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PinLoginRequest {
+  pub device_id: String,
+  pub device_secret: String,
+  pub user_id: String,
+  pub pin: String,
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+// Sin refresh token: al vencer, o al cambiar de empleado, se vuelve a pedir el PIN
+#[derive(Debug, Serialize)]
+pub struct PinLoginResponse {
+  pub user_id: String,
+  pub email: String,
+  pub device_id: String,
+  pub access_token: String,
+  pub access_token_expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PosDeviceResponse {
+  pub id: String,
+  pub name: String,
+  pub is_active: bool,
+  pub current_user: Option<String>,
+  pub last_used_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+}
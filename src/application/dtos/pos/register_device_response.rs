@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+// `device_secret` solo se muestra aquí; el terminal debe guardarlo para el login por PIN
+#[derive(Debug, Serialize)]
+pub struct RegisterDeviceResponse {
+  pub device_id: String,
+  pub device_secret: String,
+  pub name: String,
+}
@@ -0,0 +1,2 @@
+pub mod refresh_request;
+pub mod refresh_response;
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct RefreshResponse {
+  pub user_id: String,
+  pub access_token: String,
+  pub refresh_token: String,
+  pub access_token_expires_at: DateTime<Utc>,
+  pub refresh_token_expires_at: DateTime<Utc>,
+}
\ No newline at end of file
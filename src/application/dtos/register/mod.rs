@@ -1,2 +1,4 @@
 pub mod register_request;
 pub mod register_response;
+pub mod verify_email_request;
+pub mod resend_verification_request;
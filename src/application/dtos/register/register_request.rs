@@ -6,6 +6,6 @@ use crate::domain::value_objects::user_types::UserType;
 pub struct RegisterRequest {
   pub email: String,
   pub password: String,
+  // Fuera de `customer` hace falta el bearer token de quien da de alta al usuario
   pub user_type: UserType,
-  pub created_by: Option<String>,
 }
\ No newline at end of file
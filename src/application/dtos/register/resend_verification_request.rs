@@ -0,0 +1,6 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResendVerificationRequest {
+  pub email: String,
+}
\ No newline at end of file
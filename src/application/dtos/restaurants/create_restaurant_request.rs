@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateRestaurantRequest {
+  pub name: String,
+  // Subdominio y valor del header `X-Tenant`, ej. "la-pizzeria"
+  pub slug: String,
+  // Base de datos propia en lugar de la compartida, para las cadenas que lo exigen por contrato
+  #[serde(default)]
+  pub dedicated_database: bool,
+  // Código del plan; por defecto `starter`
+  #[serde(default)]
+  pub plan: Option<String>,
+}
@@ -0,0 +1,4 @@
+pub mod create_restaurant_request;
+pub mod restaurant_response;
+pub mod signup_request;
+pub mod signup_response;
\ No newline at end of file
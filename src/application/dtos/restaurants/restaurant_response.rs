@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::restaurant::Restaurant;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RestaurantResponse {
+  pub id: String,
+  pub name: String,
+  pub slug: String,
+  pub dedicated_database: bool,
+  pub is_active: bool,
+  pub inherit_role_permissions: bool,
+  pub created_at: DateTime<Utc>,
+}
+
+impl From<Restaurant> for RestaurantResponse {
+  fn from(restaurant: Restaurant) -> Self {
+    Self {
+      id: restaurant.id.map(|id| id.id.to_string()).unwrap_or_default(),
+      name: restaurant.name,
+      slug: restaurant.slug,
+      dedicated_database: restaurant.database.is_some(),
+      is_active: restaurant.is_active,
+      inherit_role_permissions: restaurant.inherit_role_permissions,
+      created_at: restaurant.created_at,
+    }
+  }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::dtos::locations::create_location_request::CreateLocationRequest;
+
+// Alta de un restaurante por su cuenta: el restaurante, su primera sucursal y el dueño
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignupRequest {
+  pub restaurant_name: String,
+  pub slug: String,
+  // Credenciales del dueño; `email` va en la raíz para el límite por email del rate limiter
+  pub email: String,
+  pub password: String,
+  pub location: CreateLocationRequest,
+  // Código del plan a probar; por defecto `starter`
+  #[serde(default)]
+  pub plan: Option<String>,
+}
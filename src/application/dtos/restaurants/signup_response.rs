@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+use crate::application::dtos::locations::location_response::LocationResponse;
+use crate::application::dtos::login::login_response::LoginOutcome;
+use crate::application::dtos::restaurants::restaurant_response::RestaurantResponse;
+
+#[derive(Debug, Serialize)]
+pub struct SignupResponse {
+  pub restaurant: RestaurantResponse,
+  pub location: LocationResponse,
+  // Sesión del dueño, o el reto de 2FA si la política lo exige a los administradores
+  #[serde(flatten)]
+  pub session: LoginOutcome,
+}
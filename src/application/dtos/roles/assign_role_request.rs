@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssignRoleRequest {
+  pub role_id: String,
+  // Solo en esta sucursal; sin ella el rol vale en todo el restaurante
+  pub location_id: Option<String>,
+}
+
+// `?location_id=...` al quitar un rol asignado en una sucursal
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleScopeQuery {
+  pub location_id: Option<String>,
+}
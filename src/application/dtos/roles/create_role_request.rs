@@ -3,6 +3,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateRoleRequest {
   pub name: String, 
-  pub description: String,
-  pub hierarchy_level: i32,
+  // Sin plantilla la descripción y el nivel son obligatorios
+  pub description: Option<String>,
+  pub hierarchy_level: Option<i32>,
+  // Plantilla de la que se copian la descripción, el nivel y los permisos, ej. "waiter"
+  pub template: Option<String>,
+  // Permisos en formato "recurso:acción", además de los de la plantilla
+  #[serde(default)]
+  pub permissions: Vec<String>,
 }
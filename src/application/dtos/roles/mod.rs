@@ -1,3 +1,7 @@
 pub mod create_role_request;
 pub mod role_response;
-pub mod update_role_request;
\ No newline at end of file
+pub mod update_role_request;
+pub mod assign_role_request;
+pub mod role_template_response;
+pub mod role_inheritance_request;
+pub mod role_tree_response;
\ No newline at end of file
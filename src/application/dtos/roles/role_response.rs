@@ -9,4 +9,7 @@ pub struct RoleResponse {
   pub hierarchy_level: i32,
   pub is_active: bool,
   pub created_at: DateTime<Utc>,
+  // Solo al crearlo, en formato "recurso:acción"
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub permissions: Vec<String>,
 }
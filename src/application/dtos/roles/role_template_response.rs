@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::services::role_templates::RoleTemplate;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleTemplateResponse {
+  pub name: String,
+  pub description: String,
+  pub hierarchy_level: i32,
+  pub permissions: Vec<String>,
+}
+
+impl From<RoleTemplate> for RoleTemplateResponse {
+  fn from(template: RoleTemplate) -> Self {
+    Self {
+      name: template.name.to_string(),
+      description: template.description.to_string(),
+      hierarchy_level: template.hierarchy_level,
+      permissions: template.permissions.iter().map(|permission| permission.to_string()).collect(),
+    }
+  }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+// Roles del usuario y los que quedan por debajo, agrupados por nivel de mayor a menor
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleTreeResponse {
+  // None si el usuario no tiene roles
+  pub hierarchy_level: Option<i32>,
+  pub inheritance: bool,
+  // Permisos con los que cuenta la sesión, herencia incluida ("recurso:acción")
+  pub effective_permissions: Vec<String>,
+  pub levels: Vec<RoleTreeLevel>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleTreeLevel {
+  pub hierarchy_level: i32,
+  pub roles: Vec<RoleTreeNode>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleTreeNode {
+  pub id: String,
+  pub name: String,
+  pub description: String,
+  // Asignado al usuario, en todo el restaurante o en la sucursal de la sesión
+  pub assigned: bool,
+  pub permissions: Vec<String>,
+  // Solo con la herencia activada
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub inherited_permissions: Vec<String>,
+}
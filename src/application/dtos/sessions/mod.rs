@@ -0,0 +1 @@
+pub mod session_response;
\ No newline at end of file
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::token::RefreshToken;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionResponse {
+  pub id: String,
+  pub device_name: Option<String>,
+  pub ip_address: Option<String>,
+  pub user_agent: Option<String>,
+  pub started_at: DateTime<Utc>,
+  pub last_used_at: DateTime<Utc>,
+  pub expires_at: DateTime<Utc>,
+  // Marca la sesión desde la que se hace la consulta
+  pub current: bool,
+}
+
+impl SessionResponse {
+  pub fn from_token(token: RefreshToken, current_session: Option<&str>) -> Self {
+    Self {
+      current: current_session == Some(token.session_id.as_str()),
+      id: token.session_id,
+      device_name: token.device_name,
+      ip_address: token.ip_address,
+      user_agent: token.user_agent,
+      started_at: token.session_started_at,
+      last_used_at: token.last_used_at,
+      expires_at: token.expires_at,
+    }
+  }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::subscription::SubscriptionStatus;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChangePlanRequest {
+  pub plan: String,
+  // Por defecto activa: el cambio lo hace la plataforma tras el cobro
+  #[serde(default = "active")]
+  pub status: SubscriptionStatus,
+}
+
+fn active() -> SubscriptionStatus {
+  SubscriptionStatus::Active
+}
@@ -0,0 +1,3 @@
+pub mod plan_response;
+pub mod subscription_response;
+pub mod change_plan_request;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::plan::{Feature, Plan, PlanLimits};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlanResponse {
+  pub code: String,
+  pub name: String,
+  pub limits: PlanLimits,
+  pub features: Vec<Feature>,
+  pub trial_days: u32,
+}
+
+impl From<Plan> for PlanResponse {
+  fn from(plan: Plan) -> Self {
+    Self {
+      code: plan.code,
+      name: plan.name,
+      limits: plan.limits,
+      features: plan.features,
+      trial_days: plan.trial_days,
+    }
+  }
+}
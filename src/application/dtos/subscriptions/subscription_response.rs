@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::application::dtos::subscriptions::plan_response::PlanResponse;
+use crate::domain::entities::plan::{Plan, PlanLimit};
+use crate::domain::entities::subscription::{Subscription, SubscriptionStatus};
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionResponse {
+  pub plan: PlanResponse,
+  pub status: SubscriptionStatus,
+  pub trial_ends_at: Option<DateTime<Utc>>,
+  pub current_period_start: DateTime<Utc>,
+  pub current_period_end: DateTime<Utc>,
+  pub usage: Vec<UsageResponse>,
+  pub usage_refreshed_at: Option<DateTime<Utc>>,
+}
+
+// Uso de un límite frente al tope del plan; `limit` en None si no tiene tope
+#[derive(Debug, Serialize)]
+pub struct UsageResponse {
+  pub name: PlanLimit,
+  pub used: u64,
+  pub limit: Option<u64>,
+}
+
+impl SubscriptionResponse {
+  pub fn new(subscription: Subscription, plan: Plan) -> Self {
+    let usage = PlanLimit::ALL.iter()
+      .map(|limit| UsageResponse { name: *limit, used: subscription.usage.get(*limit), limit: plan.limit(*limit) })
+      .collect();
+
+    Self {
+      plan: plan.into(),
+      status: subscription.status,
+      trial_ends_at: subscription.trial_ends_at,
+      current_period_start: subscription.current_period_start,
+      current_period_end: subscription.current_period_end,
+      usage,
+      usage_refreshed_at: subscription.usage_refreshed_at,
+    }
+  }
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+// Sin refresh token: al vencer el access token la suplantación termina
+#[derive(Debug, Serialize)]
+pub struct ImpersonationResponse {
+  pub user_id: String,
+  pub email: String,
+  pub impersonator_id: String,
+  pub access_token: String,
+  pub access_token_expires_at: DateTime<Utc>,
+}
@@ -1,3 +1,6 @@
 pub mod change_password_request;
 pub mod verification_status_user_request;
-pub mod user_response;
\ No newline at end of file
+pub mod user_response;
+pub mod impersonation_response;
+pub mod switch_location_request;
+pub mod switch_location_response;
\ No newline at end of file
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+// Sin `location_id` la sesión deja de trabajar en una sucursal
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SwitchLocationRequest {
+  pub location_id: Option<String>,
+}
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+// El refresh token de la sesión no cambia; las renovaciones conservan la sucursal
+#[derive(Debug, Serialize)]
+pub struct SwitchLocationResponse {
+  pub location_id: Option<String>,
+  pub access_token: String,
+  pub access_token_expires_at: DateTime<Utc>,
+}
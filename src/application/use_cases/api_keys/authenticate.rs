@@ -0,0 +1,33 @@
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::repositories::api_key_repository::ApiKeyRepository;
+use crate::domain::error::Error;
+
+pub struct AuthenticateApiKeyUseCase<K> where K: ApiKeyRepository {
+  api_key_repository: K,
+}
+
+impl<K> AuthenticateApiKeyUseCase<K> where K: ApiKeyRepository {
+  pub fn new(api_key_repository: K) -> Self {
+    Self { api_key_repository }
+  }
+
+  pub async fn execute(&self, key: &str) -> Result<ApiKey, Error> {
+    let api_key = self.api_key_repository
+      .find_by_key(key)
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if api_key.is_expired() {
+      return Err(Error::TokenExpired);
+    }
+
+    let id = api_key.id.clone().ok_or(Error::InvalidToken)?.id.to_string();
+
+    // Un fallo al registrar el uso no debe rechazar la petición
+    if let Err(err) = self.api_key_repository.touch_last_used(id).await {
+      log::warn!("Failed to record API key usage: {}", err);
+    }
+
+    Ok(api_key)
+  }
+}
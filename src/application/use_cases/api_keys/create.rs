@@ -0,0 +1,72 @@
+use std::str::FromStr;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::entities::api_key::{ApiKey, ApiKeyScope};
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::api_key_repository::ApiKeyRepository;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::application::dtos::api_keys::{
+  create_api_key_request::CreateApiKeyRequest,
+  create_api_key_response::CreateApiKeyResponse,
+};
+
+const KEY_PREFIX: &str = "rk_";
+const VISIBLE_PREFIX_LENGTH: usize = 11;
+
+pub struct CreateApiKeyUseCase<K> where K: ApiKeyRepository {
+  api_key_repository: K,
+}
+
+impl<K> CreateApiKeyUseCase<K> where K: ApiKeyRepository {
+  pub fn new(api_key_repository: K) -> Self {
+    Self { api_key_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, request: CreateApiKeyRequest) -> Result<CreateApiKeyResponse, Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+    auth_user.require_full_session()?;
+
+    if request.name.trim().is_empty() {
+      return Err(Error::ValidationError("API key name is required".to_string()));
+    }
+
+    if request.scopes.is_empty() {
+      return Err(Error::ValidationError("At least one scope is required".to_string()));
+    }
+
+    if matches!(request.expires_at, Some(expires_at) if expires_at <= Utc::now()) {
+      return Err(Error::ValidationError("Expiration must be in the future".to_string()));
+    }
+
+    let mut scopes: Vec<ApiKeyScope> = Vec::new();
+
+    for scope in &request.scopes {
+      let required = RequiredPermission::from_str(scope)?;
+
+      // Nadie puede entregar a un equipo un permiso que no tiene
+      auth_user.require(&required)?;
+
+      let scope = ApiKeyScope::from(required);
+      if !scopes.contains(&scope) {
+        scopes.push(scope);
+      }
+    }
+
+    let key = format!("{}{}{}", KEY_PREFIX, Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+    let api_key = ApiKey::new(
+      request.name.trim().to_string(),
+      key[..VISIBLE_PREFIX_LENGTH].to_string(),
+      scopes,
+      auth_user.user.id.clone().ok_or(Error::UserNotFound)?,
+      request.expires_at,
+    );
+
+    let api_key = self.api_key_repository.create(&api_key, &key).await?;
+
+    Ok(CreateApiKeyResponse { key, api_key: api_key.into() })
+  }
+}
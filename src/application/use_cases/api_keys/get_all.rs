@@ -0,0 +1,24 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::api_key_repository::ApiKeyRepository;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::application::dtos::api_keys::api_key_response::ApiKeyResponse;
+
+pub struct GetAllApiKeysUseCase<K> where K: ApiKeyRepository {
+  api_key_repository: K,
+}
+
+impl<K> GetAllApiKeysUseCase<K> where K: ApiKeyRepository {
+  pub fn new(api_key_repository: K) -> Self {
+    Self { api_key_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<Vec<ApiKeyResponse>, Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+
+    let api_keys = self.api_key_repository.find_all().await?;
+
+    Ok(api_keys.into_iter().map(ApiKeyResponse::from).collect())
+  }
+}
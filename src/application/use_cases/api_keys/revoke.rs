@@ -0,0 +1,28 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::api_key_repository::ApiKeyRepository;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+
+pub struct RevokeApiKeyUseCase<K> where K: ApiKeyRepository {
+  api_key_repository: K,
+}
+
+impl<K> RevokeApiKeyUseCase<K> where K: ApiKeyRepository {
+  pub fn new(api_key_repository: K) -> Self {
+    Self { api_key_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, id: &str) -> Result<(), Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+
+    self.api_key_repository
+      .find_by_id(id.to_string())
+      .await?
+      .ok_or(Error::InvalidInput("API key not found".to_string()))?;
+
+    self.api_key_repository.revoke(id.to_string()).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditFilter, AuditOutcome};
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+
+use crate::application::dtos::audit::{
+  audit_query_request::AuditQueryRequest,
+  audit_event_response::{AuditEventResponse, AuditPageResponse},
+};
+
+const DEFAULT_PER_PAGE: u64 = 50;
+const MAX_PER_PAGE: u64 = 200;
+
+pub struct GetAuditEventsUseCase<A> where A: AuditRepository {
+  audit_repository: A,
+}
+
+impl<A> GetAuditEventsUseCase<A> where A: AuditRepository {
+  pub fn new(audit_repository: A) -> Self {
+    Self { audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, query: AuditQueryRequest) -> Result<AuditPageResponse, Error> {
+    // El registro muestra la actividad de todos los usuarios: no basta con users:manage
+    if auth_user.user.user_type != UserType::SuperAdmin {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    auth_user.require_full_session()?;
+
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE);
+
+    if page == 0 {
+      return Err(Error::InvalidInput("page must be greater than 0".to_string()));
+    }
+
+    if per_page == 0 || per_page > MAX_PER_PAGE {
+      return Err(Error::InvalidInput(format!("per_page must be between 1 and {}", MAX_PER_PAGE)));
+    }
+
+    let filter = AuditFilter {
+      action: query.action.as_deref().map(AuditAction::from_str).transpose().map_err(Error::InvalidInput)?,
+      outcome: query.outcome.as_deref().map(AuditOutcome::from_str).transpose().map_err(Error::InvalidInput)?,
+      actor_id: query.actor_id,
+      impersonator_id: query.impersonator_id,
+      target_type: query.target_type,
+      target_id: query.target_id,
+      ip_address: query.ip_address,
+      from: query.from,
+      to: query.to,
+    };
+
+    let (events, total) = self.audit_repository
+      .find_all(&filter, (page - 1) * per_page, per_page)
+      .await?;
+
+    Ok(AuditPageResponse {
+      items: events.into_iter().map(AuditEventResponse::from).collect(),
+      page,
+      per_page,
+      total,
+    })
+  }
+}
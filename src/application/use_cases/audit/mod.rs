@@ -0,0 +1 @@
+pub mod get_all;
\ No newline at end of file
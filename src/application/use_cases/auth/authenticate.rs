@@ -0,0 +1,162 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::Permission;
+use crate::domain::entities::role::Role;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  role_repository::RoleRepository,
+  permission_repository::PermissionRepository,
+  revoked_token_repository::RevokedTokenRepository,
+  restaurant_repository::RestaurantRepository,
+};
+use crate::domain::error::Error;
+use chrono::DateTime;
+use crate::domain::services::pos::PosScope;
+use crate::domain::services::role_hierarchy::RoleHierarchy;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::services::token::{TokenScope, TokenService};
+
+pub struct AuthenticateUseCase<U, R, P, K, T>
+where
+  U: UserRepository,
+  R: RoleRepository,
+  P: PermissionRepository,
+  K: RevokedTokenRepository,
+  T: RestaurantRepository,
+{
+  user_repository: U,
+  role_repository: R,
+  permission_repository: P,
+  revoked_token_repository: K,
+  restaurant_repository: T,
+  token_service: TokenService,
+}
+
+impl<U, R, P, K, T> AuthenticateUseCase<U, R, P, K, T>
+where
+  U: UserRepository,
+  R: RoleRepository,
+  P: PermissionRepository,
+  K: RevokedTokenRepository,
+  T: RestaurantRepository,
+{
+  pub fn new(
+    user_repository: U,
+    role_repository: R,
+    permission_repository: P,
+    revoked_token_repository: K,
+    restaurant_repository: T,
+    token_service: TokenService
+  ) -> Self {
+    Self { user_repository, role_repository, permission_repository, revoked_token_repository, restaurant_repository, token_service }
+  }
+
+  pub async fn execute(&self, token: &str) -> Result<AuthenticatedUser, Error> {
+    let claims = self.token_service.verify_access_token(token)?;
+
+    if self.token_service.is_token_expired(&claims) {
+      return Err(Error::TokenExpired);
+    }
+
+    // Logout, cambio de contraseña o baja: el token sigue firmado y vigente pero ya no se acepta
+    let issued_at = DateTime::from_timestamp(claims.iat, 0).ok_or(Error::InvalidToken)?;
+    let revoked = self.revoked_token_repository
+      .find_matching(claims.jti.clone(), claims.sub.clone(), issued_at)
+      .await?;
+
+    if revoked.is_some() {
+      return Err(Error::InvalidToken);
+    }
+
+    // Si al super admin le cierran las sesiones (baja, cambio de contraseña) también caen sus suplantaciones
+    if let Some(actor) = &claims.act {
+      let revoked = self.revoked_token_repository
+        .find_matching(None, actor.sub.clone(), issued_at)
+        .await?;
+
+      if revoked.is_some() {
+        return Err(Error::InvalidToken);
+      }
+    }
+
+    let user = self.user_repository
+      .find_by_id(claims.sub.clone())
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if !user.can_sign_in() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    // Permisos efectivos: users_roles -> roles_permissions
+    let roles = self.role_repository.find_user_roles(claims.sub.clone()).await?;
+    let mut permissions = self.role_permissions(&roles).await?;
+
+    // Los roles de la sucursal de la sesión se guardan aparte: solo valen sobre esa sucursal
+    let location_roles = match &claims.lid {
+      Some(location_id) => self.role_repository
+        .find_user_location_roles(claims.sub.clone(), location_id.clone())
+        .await?,
+      None => Vec::new(),
+    };
+    let mut location_permissions = self.role_permissions(&location_roles).await?;
+
+    // Los clientes no heredan permisos del personal
+    if user.user_type != UserType::Customer && self.inherits_permissions(claims.tid.as_deref()).await? {
+      let restaurant_roles = self.role_repository.find_all().await?;
+
+      self.inherit_permissions(&roles, &restaurant_roles, &mut permissions).await?;
+      self.inherit_permissions(&location_roles, &restaurant_roles, &mut location_permissions).await?;
+    }
+
+    // Una sesión abierta con PIN nunca supera el alcance POS, aunque los roles hayan cambiado después
+    if claims.scope == Some(TokenScope::Pos) {
+      permissions = PosScope::restrict(permissions);
+      location_permissions = PosScope::restrict(location_permissions);
+    }
+
+    Ok(AuthenticatedUser::new(claims, user, roles, permissions).with_location_access(location_roles, location_permissions))
+  }
+
+  // La herencia de permisos la decide cada restaurante; la plataforma no la usa
+  async fn inherits_permissions(&self, restaurant_id: Option<&str>) -> Result<bool, Error> {
+    let Some(restaurant_id) = restaurant_id else {
+      return Ok(false);
+    };
+
+    let restaurant = self.restaurant_repository.find_by_id(restaurant_id.to_string()).await?;
+
+    Ok(restaurant.is_some_and(|restaurant| restaurant.inherit_role_permissions))
+  }
+
+  async fn inherit_permissions(&self, roles: &[Role], restaurant_roles: &[Role], permissions: &mut Vec<Permission>) -> Result<(), Error> {
+    let Some(level) = RoleHierarchy::inheritance_level(roles) else {
+      return Ok(());
+    };
+
+    let inherited: Vec<Role> = RoleHierarchy::inherited(level, restaurant_roles).into_iter().cloned().collect();
+
+    for permission in self.role_permissions(&inherited).await? {
+      if !permissions.iter().any(|p| p.id == permission.id) {
+        permissions.push(permission);
+      }
+    }
+
+    Ok(())
+  }
+
+  async fn role_permissions(&self, roles: &[Role]) -> Result<Vec<Permission>, Error> {
+    let mut permissions: Vec<Permission> = Vec::new();
+
+    for role in roles {
+      let role_id = role.id.clone().ok_or(Error::RoleNotFound)?.id.to_string();
+
+      for permission in self.permission_repository.find_role_permissions(role_id).await? {
+        if !permissions.iter().any(|p| p.id == permission.id) {
+          permissions.push(permission);
+        }
+      }
+    }
+
+    Ok(permissions)
+  }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::mailer::{EmailMessage, Mailer};
+use crate::domain::services::token::TokenService;
+use crate::application::dtos::password::forgot_password_request::ForgotPasswordRequest;
+
+pub struct ForgotPasswordUseCase<U, O>
+where
+  U: UserRepository,
+  O: OneTimeTokenRepository,
+{
+  user_repository: U,
+  one_time_token_repository: O,
+  token_service: TokenService,
+  mailer: Arc<dyn Mailer>,
+  app_url: String,
+}
+
+impl<U, O> ForgotPasswordUseCase<U, O>
+where
+  U: UserRepository,
+  O: OneTimeTokenRepository,
+{
+  pub fn new(user_repository: U, one_time_token_repository: O, token_service: TokenService, mailer: Arc<dyn Mailer>, app_url: String) -> Self {
+    Self { user_repository, one_time_token_repository, token_service, mailer, app_url }
+  }
+
+  pub async fn execute(&self, request: ForgotPasswordRequest) -> Result<(), Error> {
+    // No se revela si el correo existe: la respuesta es la misma y el tiempo parecido
+    let user = match self.user_repository.find_by_email(&request.email).await? {
+      Some(user) if user.is_active => user,
+      _ => {
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        return Ok(());
+      }
+    };
+
+    let user_id = user.id.clone().ok_or(Error::UserNotFound)?.id.to_string();
+
+    // Solo el último enlace emitido es válido
+    self.one_time_token_repository
+      .invalidate_for_user(user_id, TokenPurpose::PasswordReset)
+      .await?;
+
+    let (token, record) = self.token_service.generate_one_time_token(&user, TokenPurpose::PasswordReset)?;
+    self.one_time_token_repository.create(&record).await?;
+
+    let link = format!("{}/reset-password?token={}", self.app_url.trim_end_matches('/'), token);
+    let message = EmailMessage::new(
+      user.email.clone(),
+      "Restablece tu contraseña".to_string(),
+      format!(
+        "Hola,\n\nRecibimos una solicitud para restablecer tu contraseña. Abre el siguiente enlace para elegir una nueva:\n\n{}\n\nEl enlace vence el {}. Si no fuiste tú, ignora este correo.\n",
+        link,
+        record.expires_at.format("%Y-%m-%d %H:%M UTC")
+      ),
+    );
+
+    // Un fallo de envío no se expone al cliente, revelaría que la cuenta existe
+    if let Err(err) = self.mailer.send(&message).await {
+      log::error!("Failed to send password reset email to {}: {}", user.email, err);
+    }
+
+    Ok(())
+  }
+}
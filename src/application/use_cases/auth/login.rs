@@ -1,50 +1,162 @@
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
 use crate::domain::error::Error;
 use crate::domain::repositories::user_repository::UserRepository;
 use crate::domain::repositories::token_repository::TokenRepository;
+use crate::domain::repositories::one_time_token_repository::OneTimeTokenRepository;
+use crate::domain::repositories::mfa_repository::MfaRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::services::lockout::LockoutPolicy;
+use crate::domain::services::mfa_policy::MfaPolicy;
+use crate::domain::services::password_policy::PasswordPolicy;
 use crate::domain::services::token::TokenService;
-use crate::application::dtos::login::{login_request::LoginRequest, login_response::LoginResponse};
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::application::dtos::login::{
+  login_request::LoginRequest,
+  login_response::{LoginResponse, LoginOutcome, MfaChallengeResponse},
+};
 
-pub struct LoginUseCase<U, T>
+pub struct LoginUseCase<U, T, O, M, A>
 where
   U: UserRepository,
   T: TokenRepository,
+  O: OneTimeTokenRepository,
+  M: MfaRepository,
+  A: AuditRepository,
 {
   user_repository: U,
   token_repository: T,
+  one_time_token_repository: O,
+  mfa_repository: M,
+  audit_repository: A,
   token_service: TokenService,
+  lockout_policy: LockoutPolicy,
+  mfa_policy: MfaPolicy,
+  password_policy: PasswordPolicy,
 }
 
-impl<U, T> LoginUseCase<U, T>
+impl<U, T, O, M, A> LoginUseCase<U, T, O, M, A>
 where
   U: UserRepository,
   T: TokenRepository,
+  O: OneTimeTokenRepository,
+  M: MfaRepository,
+  A: AuditRepository,
 {
-  pub fn new(user_repository: U, token_repository: T, token_service: TokenService) -> Self {
-    Self { user_repository, token_repository, token_service }
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    user_repository: U,
+    token_repository: T,
+    one_time_token_repository: O,
+    mfa_repository: M,
+    audit_repository: A,
+    token_service: TokenService,
+    lockout_policy: LockoutPolicy,
+    mfa_policy: MfaPolicy,
+    password_policy: PasswordPolicy,
+  ) -> Self {
+    Self {
+      user_repository,
+      token_repository,
+      one_time_token_repository,
+      mfa_repository,
+      audit_repository,
+      token_service,
+      lockout_policy,
+      mfa_policy,
+      password_policy,
+    }
   }
 
-  pub async fn execute(&self, request: LoginRequest) -> Result<LoginResponse, Error> {
-    let user = self.user_repository
-      .authenticate(&request.email, &request.password)
+  pub async fn execute(&self, request: LoginRequest, client: &ClientInfo) -> Result<LoginOutcome, Error> {
+    let mut event = AuditEvent::new(AuditAction::Login).client(client);
+    let result = self.login(request, client, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn login(&self, request: LoginRequest, client: &ClientInfo, event: &mut AuditEvent) -> Result<LoginOutcome, Error> {
+    let existing_user = self.user_repository
+      .find_by_email(&request.email)
       .await?
       .ok_or(Error::InvalidCredentials)?;
+
+    let user_id = existing_user.id.clone().ok_or(Error::InvalidCredentials)?.id.to_string();
+
+    // Mientras no se valide la contraseña no se sabe quién es el actor, solo la cuenta afectada
+    event.set_target("users", &user_id);
+
+    // Mientras la cuenta esté bloqueada ni siquiera se verifica la contraseña
+    if self.lockout_policy.is_locked(&existing_user) {
+      return Err(Error::AccountLocked(existing_user.locked_until.unwrap()));
+    }
+
+    let user = match self.user_repository.authenticate(&request.email, &request.password).await? {
+      Some(user) => user,
+      None => return Err(self.register_failed_attempt(user_id, existing_user.failed_login_attempts).await?),
+    };
+
+    event.actor_id = user.id.clone();
+
+    if !user.can_sign_in() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    // Con la contraseña caducada no se emite sesión: el usuario debe restablecerla por correo
+    if self.password_policy.is_expired(&user) {
+      return Err(Error::PasswordExpired);
+    }
+
+    // Con 2FA la sesión no se emite todavía: se entrega un token corto para el segundo paso
+    let credential = self.mfa_repository.find_by_user(user_id.clone()).await?;
+
+    if self.mfa_policy.applies_to(&user.user_type, credential.as_ref()) {
+      let (mfa_token, record) = self.token_service.generate_one_time_token(&user, TokenPurpose::MfaPending)?;
+      self.one_time_token_repository.create(&record).await?;
+
+      return Ok(LoginOutcome::MfaRequired(MfaChallengeResponse {
+        mfa_required: true,
+        mfa_token,
+        mfa_token_expires_at: record.expires_at,
+        enrollment_required: !credential.is_some_and(|credential| credential.enabled),
+      }));
+    }
+
+    self.user_repository.record_successful_login(user_id.clone()).await?;
     
     // Generar tokens
-    let token_pair = self.token_service.generate_token_pair(&user)?;
+    let token_pair = self.token_service.generate_token_pair(&user, client)?;
 
     // Guardar refresh token
     self.token_repository
       .create_refresh_token(&token_pair.refresh_token)
       .await?;
 
-    Ok(LoginResponse {
-      user_id: user.id.clone().unwrap().id.to_string(),
+    Ok(LoginOutcome::Authenticated(LoginResponse {
+      user_id,
       email: user.email,
-      access_token: token_pair.access_token,
+      access_token: token_pair.access_token.token,
       refresh_token: token_pair.refresh_token.token,
-      access_token_expires_at: token_pair.refresh_token.expires_at,
-    })
+      access_token_expires_at: token_pair.access_token.expires_at,
+    }))
   }
-}
 
+  // Devuelve el error que debe recibir el cliente tras un intento fallido
+  async fn register_failed_attempt(&self, user_id: String, previous_attempts: i32) -> Result<Error, Error> {
+    let attempts = previous_attempts + 1;
 
+    self.user_repository.update_failed_login_attempts(user_id.clone(), attempts).await?;
+
+    match self.lockout_policy.locked_until(attempts) {
+      Some(locked_until) => {
+        log::warn!("User {} locked until {} after {} failed login attempts", user_id, locked_until, attempts);
+
+        self.user_repository.lock_account(user_id, locked_until).await?;
+        Ok(Error::AccountLocked(locked_until))
+      },
+      None => Ok(Error::InvalidCredentials),
+    }
+  }
+}
@@ -1,30 +1,48 @@
-use std::str::FromStr;
-use surrealdb::sql::Thing;
-
-use crate::domain::repositories::token_repository::TokenRepository;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::{
+  token_repository::TokenRepository,
+  revoked_token_repository::RevokedTokenRepository,
+};
 use crate::domain::error::Error;
 use crate::domain::services::token::TokenService;
 
-pub struct LogoutUseCase<T> where T: TokenRepository {
+pub struct LogoutUseCase<T, K>
+where
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+{
   token_repository: T,
+  revoked_token_repository: K,
   token_service: TokenService,
 }
 
-impl<T> LogoutUseCase<T> where T: TokenRepository {
-  pub fn new(token_repository: T, token_service: TokenService) -> Self {
-    Self { token_repository, token_service }
+impl<T, K> LogoutUseCase<T, K>
+where
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+{
+  pub fn new(token_repository: T, revoked_token_repository: K, token_service: TokenService) -> Self {
+    Self { token_repository, revoked_token_repository, token_service }
   }
 
-  pub async fn execute(&self, token: &str) -> Result<(), Error> {
-    let claims = self.token_service.verify_access_token(token)?;
-
-    if self.token_service.is_token_expired(&claims) {
-      return Err(Error::TokenExpired);
+  // Cierra solo la sesión del token; los tokens sin sesión (anteriores a las sesiones) cierran todas.
+  // Una suplantación no tiene sesión propia y no debe cerrar las del usuario suplantado.
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<(), Error> {
+    match auth_user.session_id() {
+      _ if auth_user.is_impersonation() => {}
+      Some(session_id) => {
+        if !self.token_repository.revoke_session(auth_user.user_id(), session_id).await? {
+          return Err(Error::InvalidToken);
+        }
+      }
+      None => self.token_repository.invalidate_refresh_token(auth_user.user_id()).await?,
     }
 
-    let user_id = Thing::from_str(claims.sub.as_str()).unwrap();
+    // El access token con el que se hizo logout deja de valer en el acto
+    if let Some(entry) = self.token_service.revocation_for_token(&auth_user.claims) {
+      self.revoked_token_repository.revoke(&entry).await?;
+    }
 
-    self.token_repository.invalidate_refresh_token(user_id.to_string()).await?;
     Ok(())
   }
-}
\ No newline at end of file
+}
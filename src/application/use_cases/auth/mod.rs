@@ -1,3 +1,11 @@
 pub mod register;
 pub mod login;
-pub mod logout;
\ No newline at end of file
+pub mod logout;
+pub mod authenticate;
+pub mod refresh;
+pub mod send_verification;
+pub mod verify_email;
+pub mod resend_verification;
+pub mod forgot_password;
+pub mod reset_password;
+pub mod pin_login;
\ No newline at end of file
@@ -0,0 +1,100 @@
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  pos_device_repository::PosDeviceRepository,
+  staff_pin_repository::StaffPinRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::lockout::LockoutPolicy;
+use crate::domain::services::pos::PosScope;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::user_types::UserType;
+use crate::application::dtos::pos::{
+  pin_login_request::PinLoginRequest,
+  pin_login_response::PinLoginResponse,
+};
+
+// Login por PIN en un terminal registrado. Cambiar de empleado es otro login sobre el mismo
+// terminal: no hace falta cerrar la sesión anterior, que vence sola
+pub struct PinLoginUseCase<U, D, S>
+where
+  U: UserRepository,
+  D: PosDeviceRepository,
+  S: StaffPinRepository,
+{
+  user_repository: U,
+  pos_device_repository: D,
+  staff_pin_repository: S,
+  token_service: TokenService,
+  lockout_policy: LockoutPolicy,
+}
+
+impl<U, D, S> PinLoginUseCase<U, D, S>
+where
+  U: UserRepository,
+  D: PosDeviceRepository,
+  S: StaffPinRepository,
+{
+  pub fn new(user_repository: U, pos_device_repository: D, staff_pin_repository: S, token_service: TokenService, lockout_policy: LockoutPolicy) -> Self {
+    Self { user_repository, pos_device_repository, staff_pin_repository, token_service, lockout_policy }
+  }
+
+  pub async fn execute(&self, request: PinLoginRequest) -> Result<PinLoginResponse, Error> {
+    PosScope::validate_pin(&request.pin).map_err(|_| Error::InvalidCredentials)?;
+
+    self.pos_device_repository
+      .authenticate(request.device_id.clone(), &request.device_secret)
+      .await?
+      .ok_or(Error::InvalidCredentials)?;
+
+    let user = self.user_repository
+      .find_by_id(request.user_id.clone())
+      .await?
+      .filter(|user| user.can_sign_in() && user.user_type != UserType::Customer)
+      .ok_or(Error::InvalidCredentials)?;
+
+    let staff_pin = self.staff_pin_repository
+      .find_by_user(request.user_id.clone())
+      .await?
+      .ok_or(Error::InvalidCredentials)?;
+
+    if self.lockout_policy.is_locked_until(staff_pin.locked_until) {
+      return Err(Error::AccountLocked(staff_pin.locked_until.unwrap()));
+    }
+
+    if !self.staff_pin_repository.verify(request.user_id.clone(), &request.pin).await? {
+      return Err(self.register_failed_attempt(request.user_id, staff_pin.failed_attempts).await?);
+    }
+
+    if staff_pin.failed_attempts > 0 {
+      self.staff_pin_repository.update_failed_attempts(request.user_id.clone(), 0).await?;
+    }
+
+    self.pos_device_repository.set_current_user(request.device_id.clone(), request.user_id.clone()).await?;
+
+    let access_token = self.token_service.generate_pos_access_token(&user, request.device_id.clone())?;
+
+    Ok(PinLoginResponse {
+      user_id: request.user_id,
+      email: user.email,
+      device_id: request.device_id,
+      access_token: access_token.token,
+      access_token_expires_at: access_token.expires_at,
+    })
+  }
+
+  async fn register_failed_attempt(&self, user_id: String, previous_attempts: i32) -> Result<Error, Error> {
+    let attempts = previous_attempts + 1;
+
+    self.staff_pin_repository.update_failed_attempts(user_id.clone(), attempts).await?;
+
+    match self.lockout_policy.locked_until(attempts) {
+      Some(locked_until) => {
+        log::warn!("Staff PIN of user {} locked until {} after {} failed attempts", user_id, locked_until, attempts);
+
+        self.staff_pin_repository.lock(user_id, locked_until).await?;
+        Ok(Error::AccountLocked(locked_until))
+      },
+      None => Ok(Error::InvalidCredentials),
+    }
+  }
+}
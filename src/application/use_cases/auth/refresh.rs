@@ -0,0 +1,83 @@
+use crate::domain::error::Error;
+use crate::domain::repositories::user_repository::UserRepository;
+use crate::domain::repositories::token_repository::TokenRepository;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::application::dtos::refresh::{refresh_request::RefreshRequest, refresh_response::RefreshResponse};
+
+pub struct RefreshTokenUseCase<U, T>
+where
+  U: UserRepository,
+  T: TokenRepository,
+{
+  user_repository: U,
+  token_repository: T,
+  token_service: TokenService,
+}
+
+impl<U, T> RefreshTokenUseCase<U, T>
+where
+  U: UserRepository,
+  T: TokenRepository,
+{
+  pub fn new(user_repository: U, token_repository: T, token_service: TokenService) -> Self {
+    Self { user_repository, token_repository, token_service }
+  }
+
+  pub async fn execute(&self, request: RefreshRequest, client: &ClientInfo) -> Result<RefreshResponse, Error> {
+    let refresh_token = self.token_repository
+      .find_refresh_token(&request.refresh_token)
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    let user_id = refresh_token.user_id.id.to_string();
+
+    // Un refresh token ya usado que vuelve a llegar se trata como robo: se invalida toda la familia del usuario
+    if refresh_token.used {
+      return self.reject_reuse(user_id).await;
+    }
+
+    if refresh_token.is_expired() {
+      return Err(Error::TokenExpired);
+    }
+
+    // El usuario se busca en el tenant de la petición antes de gastar el token: con un `X-Tenant`
+    // equivocado el cliente puede reintentar sin que parezca una reutilización
+    let user = self.user_repository
+      .find_by_id(user_id.clone())
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if !user.can_sign_in() {
+      self.token_repository.invalidate_all_user_tokens(user_id).await?;
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    // Dos peticiones a la vez con el mismo token: solo la primera lo marca
+    if !self.token_repository.mark_refresh_token_used(&refresh_token.token).await? {
+      return self.reject_reuse(user_id).await;
+    }
+
+    // Rotación: el refresh token usado se reemplaza por uno nuevo de la misma sesión
+    let token_pair = self.token_service.rotate_token_pair(&user, &refresh_token, client)?;
+
+    self.token_repository
+      .create_refresh_token(&token_pair.refresh_token)
+      .await?;
+
+    Ok(RefreshResponse {
+      user_id,
+      access_token: token_pair.access_token.token,
+      refresh_token: token_pair.refresh_token.token,
+      access_token_expires_at: token_pair.access_token.expires_at,
+      refresh_token_expires_at: token_pair.refresh_token.expires_at,
+    })
+  }
+
+  async fn reject_reuse(&self, user_id: String) -> Result<RefreshResponse, Error> {
+    log::warn!("Refresh token reuse detected for user {}, invalidating all sessions", user_id);
+
+    self.token_repository.invalidate_all_user_tokens(user_id).await?;
+    Err(Error::InvalidToken)
+  }
+}
@@ -1,60 +1,132 @@
-use std::str::FromStr;
-use surrealdb::sql::Thing;
-
 use crate::domain::repositories::{
   user_repository::UserRepository,
+  role_repository::RoleRepository,
   token_repository::TokenRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+  audit_repository::AuditRepository,
 };
 use crate::domain::entities::user::User;
+use crate::domain::entities::role::Role;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::entitlements::Entitlements;
+use crate::domain::entities::plan::PlanLimit;
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::services::role_hierarchy::RoleHierarchy;
 use crate::domain::error::Error;
-use crate::domain::value_objects::{user_types::UserType, user_status::UserStatus};
+use crate::domain::value_objects::{user_types::UserType, user_status::UserStatus, client_info::ClientInfo};
 use crate::application::dtos::register::{register_request::RegisterRequest, register_response::RegisterResponse, register_response::ProfileStatus};
 use crate::domain::services::token::TokenService;
+use crate::domain::services::password_policy::PasswordPolicy;
+use crate::application::use_cases::auth::send_verification::SendVerificationEmailUseCase;
 
-pub struct RegisterUseCase<U, T>
+// Los clientes se registran a sí mismos; el personal lo da de alta un usuario autenticado con
+// users:manage, que solo puede crear tipos de usuario por debajo de su nivel
+pub struct RegisterUseCase<U, R, T, O, A>
 where
   U: UserRepository,
+  R: RoleRepository,
   T: TokenRepository,
+  O: OneTimeTokenRepository,
+  A: AuditRepository,
 {
   user_repository: U,
+  role_repository: R,
   token_repository: T,
+  audit_repository: A,
   token_service: TokenService,
+  password_policy: PasswordPolicy,
+  send_verification: SendVerificationEmailUseCase<O>,
 }
 
-impl<U, T> RegisterUseCase<U, T>
+impl<U, R, T, O, A> RegisterUseCase<U, R, T, O, A>
 where
   U: UserRepository,
+  R: RoleRepository,
   T: TokenRepository,
+  O: OneTimeTokenRepository,
+  A: AuditRepository,
 {
-  pub fn new(user_repository: U, token_repository: T, token_service: TokenService) -> Self {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    user_repository: U,
+    role_repository: R,
+    token_repository: T,
+    audit_repository: A,
+    token_service: TokenService,
+    password_policy: PasswordPolicy,
+    send_verification: SendVerificationEmailUseCase<O>,
+  ) -> Self {
     Self {
       user_repository,
+      role_repository,
       token_repository,
+      audit_repository,
       token_service,
+      password_policy,
+      send_verification,
     }
   }
 
   pub async fn execute(
     &self,
     request: RegisterRequest,
+    creator: Option<&AuthenticatedUser>,
+    client: &ClientInfo,
+    entitlements: &Entitlements,
+  ) -> Result<RegisterResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::Register).client(client);
+
+    if let Some(creator) = creator {
+      event = event.by(creator);
+    }
+
+    let result = self.register(request, creator, client, entitlements, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn register(
+    &self,
+    request: RegisterRequest,
+    creator: Option<&AuthenticatedUser>,
+    client: &ClientInfo,
+    entitlements: &Entitlements,
+    event: &mut AuditEvent,
   ) -> Result<RegisterResponse, Error> {
     let user_type = request.user_type.clone();
 
-    if let Some(_) = self.user_repository.find_by_email(&request.email).await? {
+    self.password_policy.validate(&request.password)?;
+
+    if self.user_repository.find_by_email(&request.email).await?.is_some() {
       tokio::time::sleep(std::time::Duration::from_millis(500)).await;
       return Err(Error::RegistrationFailed);
     }
 
+    let role = find_user_type_role(&self.role_repository, &user_type).await?;
+
     // Validar permisos según el tipo de usuario
     match user_type {
       UserType::Employee | UserType::Admin | UserType::SuperAdmin => {
-        let creator_id = request.created_by
-          .ok_or(Error::UnauthorizedOperation)?;
-        
-        self.validate_admin_permissions(&creator_id).await?;
+        let creator = creator.ok_or(Error::UnauthorizedOperation)?;
+
+        creator.require(&RequiredPermission::new(Resource::Users, Action::Manage))?;
+
+        if let Some(role) = &role {
+          creator.require_outranks(role.hierarchy_level)?;
+        }
+
+        // El plan limita el personal; los clientes no cuentan
+        let staff = self.user_repository.find_all().await?.iter()
+          .filter(|user| user.is_active && user.user_type != UserType::Customer)
+          .count() as u64;
+        entitlements.require_capacity(PlanLimit::Staff, staff)?;
       }
       UserType::Customer => {
-        if request.created_by.is_some() {
+        if creator.is_some() {
           return Err(Error::InvalidOperation);
         }
       },
@@ -75,14 +147,31 @@ where
         Err(_) => return Err(Error::RegistrationFailed)
       };
 
-    let user_with_roles_and_permissions = self.user_repository.find_by_id(user.id.clone().unwrap().id.to_string()).await?.unwrap();
-    let roles = user_with_roles_and_permissions.roles.as_ref().unwrap().clone();
-    
-    // Assign roles to the user
-    self.user_repository.assign_roles(user.id.clone().unwrap().id.to_string(), roles).await?;
+    let created_id = user.id.clone().unwrap();
+
+    event.set_target("users", &created_id.id.to_string());
+
+    // Un cliente que se registra a sí mismo es su propio actor
+    if event.actor_id.is_none() {
+      event.actor_id = Some(created_id.clone());
+    }
+
+    event.record_changes(None, Some(&serde_json::json!({
+      "email": user.email,
+      "user_type": user.user_type,
+      "status": user.status,
+    })));
+
+    if let Some(role) = &role {
+      let assigned_by = creator.map(|creator| creator.user_id()).unwrap_or(created_id.id.to_string());
+
+      assign_user_type_role(&self.role_repository, role, created_id.id.to_string(), assigned_by).await?;
+    }
+
+    let user_with_roles_and_permissions = self.user_repository.find_by_id(created_id.id.to_string()).await?.ok_or(Error::UserNotFound)?;
 
     // Create refresh token
-    let new_refresh_token = self.token_service.generate_refresh_token(&user_with_roles_and_permissions)?;
+    let new_refresh_token = self.token_service.generate_refresh_token(&user_with_roles_and_permissions, client)?;
 
     let refresh_token = match self.token_repository.create_refresh_token(&new_refresh_token).await {
       Ok(token) => token,
@@ -91,6 +180,11 @@ where
       }
     };
 
+    // Si el correo falla el usuario puede pedir el reenvío, el registro no se revierte
+    if let Err(err) = self.send_verification.execute(&user_with_roles_and_permissions).await {
+      log::error!("Failed to send verification email to {}: {}", user.email, err);
+    }
+
     let profile_status = match request.user_type {
       UserType::Customer => ProfileStatus::PendingCompletion,
       UserType::Employee | UserType::Admin | UserType::SuperAdmin => ProfileStatus::PendingApproval,
@@ -102,25 +196,19 @@ where
       profile_status,
     })
   }
+}
 
-  async fn validate_admin_permissions(&self, creator_id: &str) -> Result<(), Error> {
-    let creator_id = Thing::from_str(creator_id).unwrap();
-    
-    let creator = self.user_repository
-      .find_by_id(creator_id.to_string())
-      .await?
-      .ok_or(Error::UnauthorizedOperation)?;
-
-    if !creator.is_active {
-      return Err(Error::UnauthorizedOperation);
-    }
-
-    if creator.user_type != UserType::Admin && creator.user_type != UserType::SuperAdmin {
-      return Err(Error::UnauthorizedOperation);
-    }
-
-    Ok(())
+// Rol de la plataforma que le corresponde al usuario por su tipo; el personal recibe los suyos después.
+// Lo comparten el registro y el login externo.
+pub async fn find_user_type_role<R: RoleRepository>(role_repository: &R, user_type: &UserType) -> Result<Option<Role>, Error> {
+  match RoleHierarchy::user_type_role(user_type) {
+    Some(name) => Ok(Some(role_repository.find_by_name(name).await?.ok_or(Error::RoleNotFound)?)),
+    None => Ok(None),
   }
 }
 
-
+pub async fn assign_user_type_role<R: RoleRepository>(role_repository: &R, role: &Role, user_id: String, assigned_by: String) -> Result<(), Error> {
+  role_repository
+    .assign_role_to_user(user_id, role.id.clone().ok_or(Error::RoleNotFound)?.id.to_string(), None, assigned_by)
+    .await
+}
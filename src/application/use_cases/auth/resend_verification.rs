@@ -0,0 +1,55 @@
+use chrono::Utc;
+use std::time::Duration;
+
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+};
+use crate::domain::error::Error;
+use crate::application::use_cases::auth::send_verification::SendVerificationEmailUseCase;
+
+pub struct ResendVerificationUseCase<U, O>
+where
+  U: UserRepository,
+  O: OneTimeTokenRepository,
+{
+  user_repository: U,
+  one_time_token_repository: O,
+  send_verification: SendVerificationEmailUseCase<O>,
+  cooldown: Duration,
+}
+
+impl<U, O> ResendVerificationUseCase<U, O>
+where
+  U: UserRepository,
+  O: OneTimeTokenRepository,
+{
+  pub fn new(user_repository: U, one_time_token_repository: O, send_verification: SendVerificationEmailUseCase<O>, cooldown: Duration) -> Self {
+    Self { user_repository, one_time_token_repository, send_verification, cooldown }
+  }
+
+  pub async fn execute(&self, email: &str) -> Result<(), Error> {
+    // No se revela si el correo existe o si ya fue verificado
+    let user = match self.user_repository.find_by_email(email).await? {
+      Some(user) if !user.is_verified => user,
+      _ => return Ok(()),
+    };
+
+    let user_id = user.id.clone().ok_or(Error::UserNotFound)?.id.to_string();
+
+    let latest = self.one_time_token_repository
+      .find_latest_for_user(user_id, TokenPurpose::EmailVerification)
+      .await?;
+
+    if let Some(latest) = latest {
+      let elapsed = (Utc::now() - latest.created_at).to_std().unwrap_or_default();
+
+      if elapsed < self.cooldown {
+        return Err(Error::TooManyRequests((self.cooldown - elapsed).as_secs().max(1)));
+      }
+    }
+
+    self.send_verification.execute(&user).await
+  }
+}
@@ -0,0 +1,90 @@
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  token_repository::TokenRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+  revoked_token_repository::RevokedTokenRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::token::TokenService;
+use crate::domain::services::password_policy::PasswordPolicy;
+use crate::application::dtos::password::reset_password_request::ResetPasswordRequest;
+
+pub struct ResetPasswordUseCase<U, T, O, K>
+where
+  U: UserRepository,
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  K: RevokedTokenRepository,
+{
+  user_repository: U,
+  token_repository: T,
+  one_time_token_repository: O,
+  revoked_token_repository: K,
+  token_service: TokenService,
+  password_policy: PasswordPolicy,
+}
+
+impl<U, T, O, K> ResetPasswordUseCase<U, T, O, K>
+where
+  U: UserRepository,
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  K: RevokedTokenRepository,
+{
+  pub fn new(
+    user_repository: U,
+    token_repository: T,
+    one_time_token_repository: O,
+    revoked_token_repository: K,
+    token_service: TokenService,
+    password_policy: PasswordPolicy,
+  ) -> Self {
+    Self { user_repository, token_repository, one_time_token_repository, revoked_token_repository, token_service, password_policy }
+  }
+
+  pub async fn execute(&self, request: ResetPasswordRequest) -> Result<(), Error> {
+    let claims = self.token_service.verify_one_time_token(&request.token, TokenPurpose::PasswordReset)?;
+
+    let record = self.one_time_token_repository
+      .find_by_jti(&claims.jti)
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if record.is_expired() {
+      return Err(Error::TokenExpired);
+    }
+
+    if record.user_id.id.to_string() != claims.sub {
+      return Err(Error::InvalidToken);
+    }
+
+    let user = self.user_repository
+      .find_by_id(claims.sub.clone())
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if !user.is_active {
+      return Err(Error::InvalidToken);
+    }
+
+    // Antes de consumir el token: si la contraseña no vale, el enlace debe seguir sirviendo para reintentar
+    self.password_policy
+      .validate_change(&self.user_repository, &claims.sub, &request.password)
+      .await?;
+
+    if !self.one_time_token_repository.consume(&claims.jti).await? {
+      return Err(Error::InvalidToken);
+    }
+
+    self.user_repository.change_password(claims.sub.clone(), &request.password, self.password_policy.history_size()).await?;
+
+    // Las sesiones abiertas con la contraseña anterior dejan de poder refrescarse y sus access tokens de valer
+    self.token_repository.invalidate_all_user_tokens(claims.sub.clone()).await?;
+    self.revoked_token_repository
+      .revoke(&self.token_service.revocation_for_user(&claims.sub))
+      .await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::domain::entities::user::User;
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::repositories::one_time_token_repository::OneTimeTokenRepository;
+use crate::domain::error::Error;
+use crate::domain::services::mailer::{EmailMessage, Mailer};
+use crate::domain::services::token::TokenService;
+
+// Emite un token de verificación nuevo (invalidando los anteriores) y lo envía por correo
+pub struct SendVerificationEmailUseCase<O> where O: OneTimeTokenRepository {
+  one_time_token_repository: O,
+  token_service: TokenService,
+  mailer: Arc<dyn Mailer>,
+  app_url: String,
+}
+
+impl<O> SendVerificationEmailUseCase<O> where O: OneTimeTokenRepository {
+  pub fn new(one_time_token_repository: O, token_service: TokenService, mailer: Arc<dyn Mailer>, app_url: String) -> Self {
+    Self { one_time_token_repository, token_service, mailer, app_url }
+  }
+
+  pub async fn execute(&self, user: &User) -> Result<(), Error> {
+    let user_id = user.id.clone().ok_or(Error::UserNotFound)?.id.to_string();
+
+    self.one_time_token_repository
+      .invalidate_for_user(user_id, TokenPurpose::EmailVerification)
+      .await?;
+
+    let (token, record) = self.token_service.generate_one_time_token(user, TokenPurpose::EmailVerification)?;
+    self.one_time_token_repository.create(&record).await?;
+
+    let link = format!("{}/api/v1/auth/verify?token={}", self.app_url.trim_end_matches('/'), token);
+    let message = EmailMessage::new(
+      user.email.clone(),
+      "Verifica tu correo electrónico".to_string(),
+      format!(
+        "Hola,\n\nPara activar tu cuenta abre el siguiente enlace:\n\n{}\n\nEl enlace vence el {}.\n",
+        link,
+        record.expires_at.format("%Y-%m-%d %H:%M UTC")
+      ),
+    );
+
+    self.mailer.send(&message).await
+  }
+}
@@ -0,0 +1,58 @@
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::token::TokenService;
+
+pub struct VerifyEmailUseCase<U, O>
+where
+  U: UserRepository,
+  O: OneTimeTokenRepository,
+{
+  user_repository: U,
+  one_time_token_repository: O,
+  token_service: TokenService,
+}
+
+impl<U, O> VerifyEmailUseCase<U, O>
+where
+  U: UserRepository,
+  O: OneTimeTokenRepository,
+{
+  pub fn new(user_repository: U, one_time_token_repository: O, token_service: TokenService) -> Self {
+    Self { user_repository, one_time_token_repository, token_service }
+  }
+
+  pub async fn execute(&self, token: &str) -> Result<(), Error> {
+    let claims = self.token_service.verify_one_time_token(token, TokenPurpose::EmailVerification)?;
+
+    let record = self.one_time_token_repository
+      .find_by_jti(&claims.jti)
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if record.is_expired() {
+      return Err(Error::TokenExpired);
+    }
+
+    if record.user_id.id.to_string() != claims.sub {
+      return Err(Error::InvalidToken);
+    }
+
+    // Antes de consumir el token: si el usuario no está en este tenant el enlace no se gasta
+    self.user_repository
+      .find_by_id(claims.sub.clone())
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if !self.one_time_token_repository.consume(&claims.jti).await? {
+      return Err(Error::InvalidToken);
+    }
+
+    self.user_repository.set_verification_status(claims.sub, true).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,47 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  location_repository::LocationRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::error::Error;
+
+// Alta de un empleado en una sucursal; puede hacerla quien gestiona el personal de esa sucursal
+pub struct AssignLocationUserUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  location_repository: L,
+  audit_repository: A,
+}
+
+impl<L, A> AssignLocationUserUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  pub fn new(location_repository: L, audit_repository: A) -> Self {
+    Self { location_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, location_id: &str, user_id: &str) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::AssignLocation).by(auth_user).client(client);
+    event.set_target("users", user_id);
+    event.record_changes(None, Some(&serde_json::json!({ "location_id": location_id })));
+
+    let result = self.assign(auth_user, location_id, user_id).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn assign(&self, auth_user: &AuthenticatedUser, location_id: &str, user_id: &str) -> Result<(), Error> {
+    auth_user.require_at_location(&RequiredPermission::new(Resource::Employees, Action::Manage), location_id)?;
+
+    self.location_repository
+      .find_by_id(location_id.to_string())
+      .await?
+      .ok_or(Error::LocationNotFound)?;
+
+    self.location_repository
+      .assign_user(user_id.to_string(), location_id.to_string(), auth_user.user_id())
+      .await
+  }
+}
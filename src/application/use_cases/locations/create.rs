@@ -0,0 +1,67 @@
+use crate::domain::entities::location::Location;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::entitlements::Entitlements;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::entities::plan::PlanLimit;
+use crate::domain::repositories::{
+  location_repository::LocationRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::error::Error;
+
+use crate::application::dtos::locations::{
+  create_location_request::CreateLocationRequest,
+  location_response::LocationResponse,
+};
+
+pub struct CreateLocationUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  location_repository: L,
+  audit_repository: A,
+}
+
+impl<L, A> CreateLocationUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  pub fn new(location_repository: L, audit_repository: A) -> Self {
+    Self { location_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, entitlements: &Entitlements, request: &CreateLocationRequest) -> Result<LocationResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::CreateLocation).by(auth_user).client(client);
+    let result = self.create(auth_user, entitlements, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn create(&self, auth_user: &AuthenticatedUser, entitlements: &Entitlements, request: &CreateLocationRequest, event: &mut AuditEvent) -> Result<LocationResponse, Error> {
+    // Abrir sucursales es cosa de todo el restaurante, no de una de ellas
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+
+    let location = Location::new(
+      request.name.clone(),
+      request.address.clone(),
+      request.timezone.clone(),
+      request.currency.clone(),
+      request.business_hours.clone(),
+    );
+    location.validate()?;
+
+    if self.location_repository.find_by_name(&location.name).await?.is_some() {
+      return Err(Error::LocationAlreadyExists(location.name));
+    }
+
+    // Solo cuentan las sucursales abiertas, igual que en el recuento de uso
+    let locations = self.location_repository.find_all().await?.len() as u64;
+    entitlements.require_capacity(PlanLimit::Locations, locations)?;
+
+    let location = self.location_repository.create(&location).await?;
+
+    event.set_target("locations", &location.id.clone().ok_or(Error::CreationFailed)?.id.to_string());
+    event.record_changes(None, Some(&location));
+
+    Ok(location.into())
+  }
+}
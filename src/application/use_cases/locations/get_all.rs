@@ -0,0 +1,24 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::location_repository::LocationRepository;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::error::Error;
+use crate::application::dtos::locations::location_response::LocationResponse;
+
+pub struct GetAllLocationsUseCase<L> where L: LocationRepository {
+  location_repository: L,
+}
+
+impl<L> GetAllLocationsUseCase<L> where L: LocationRepository {
+  pub fn new(location_repository: L) -> Self {
+    Self { location_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<Vec<LocationResponse>, Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Read))?;
+
+    let locations = self.location_repository.find_all().await?;
+
+    Ok(locations.into_iter().map(LocationResponse::from).collect())
+  }
+}
@@ -0,0 +1,27 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::location_repository::LocationRepository;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::error::Error;
+use crate::application::dtos::locations::location_response::LocationResponse;
+
+pub struct GetLocationByIdUseCase<L> where L: LocationRepository {
+  location_repository: L,
+}
+
+impl<L> GetLocationByIdUseCase<L> where L: LocationRepository {
+  pub fn new(location_repository: L) -> Self {
+    Self { location_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, id: &str) -> Result<LocationResponse, Error> {
+    auth_user.require_at_location(&RequiredPermission::new(Resource::Settings, Action::Read), id)?;
+
+    let location = self.location_repository
+      .find_by_id(id.to_string())
+      .await?
+      .ok_or(Error::LocationNotFound)?;
+
+    Ok(location.into())
+  }
+}
@@ -0,0 +1,21 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::location_repository::LocationRepository;
+use crate::domain::error::Error;
+use crate::application::dtos::locations::location_response::LocationResponse;
+
+// Sucursales en las que puede trabajar el usuario, para elegir una al iniciar la sesión
+pub struct GetMyLocationsUseCase<L> where L: LocationRepository {
+  location_repository: L,
+}
+
+impl<L> GetMyLocationsUseCase<L> where L: LocationRepository {
+  pub fn new(location_repository: L) -> Self {
+    Self { location_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<Vec<LocationResponse>, Error> {
+    let locations = self.location_repository.find_user_locations(auth_user.user_id()).await?;
+
+    Ok(locations.into_iter().map(LocationResponse::from).collect())
+  }
+}
@@ -0,0 +1,8 @@
+pub mod create;
+pub mod get_all;
+pub mod get_by_id;
+pub mod get_mine;
+pub mod update;
+pub mod remove;
+pub mod assign_user;
+pub mod unassign_user;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  location_repository::LocationRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::error::Error;
+
+pub struct RemoveLocationUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  location_repository: L,
+  audit_repository: A,
+}
+
+impl<L, A> RemoveLocationUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  pub fn new(location_repository: L, audit_repository: A) -> Self {
+    Self { location_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, id: &str) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::DeleteLocation).by(auth_user).client(client);
+    event.set_target("locations", id);
+
+    let result = self.remove(auth_user, id, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn remove(&self, auth_user: &AuthenticatedUser, id: &str, event: &mut AuditEvent) -> Result<(), Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+
+    let location = self.location_repository
+      .find_by_id(id.to_string())
+      .await?
+      .ok_or(Error::LocationNotFound)?;
+
+    self.location_repository.delete(id.to_string()).await?;
+    event.record_changes(Some(&location), None);
+
+    Ok(())
+  }
+}
@@ -0,0 +1,42 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  location_repository::LocationRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::error::Error;
+
+// Saca a un empleado de una sucursal junto con los roles que tenía en ella
+pub struct UnassignLocationUserUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  location_repository: L,
+  audit_repository: A,
+}
+
+impl<L, A> UnassignLocationUserUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  pub fn new(location_repository: L, audit_repository: A) -> Self {
+    Self { location_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, location_id: &str, user_id: &str) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::UnassignLocation).by(auth_user).client(client);
+    event.set_target("users", user_id);
+    event.record_changes(Some(&serde_json::json!({ "location_id": location_id })), None);
+
+    let result = self.unassign(auth_user, location_id, user_id).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn unassign(&self, auth_user: &AuthenticatedUser, location_id: &str, user_id: &str) -> Result<(), Error> {
+    auth_user.require_at_location(&RequiredPermission::new(Resource::Employees, Action::Manage), location_id)?;
+
+    self.location_repository
+      .remove_user(user_id.to_string(), location_id.to_string())
+      .await
+  }
+}
@@ -0,0 +1,71 @@
+use crate::domain::entities::location::Location;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  location_repository::LocationRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::error::Error;
+
+use crate::application::dtos::locations::{
+  update_location_request::UpdateLocationRequest,
+  location_response::LocationResponse,
+};
+
+pub struct UpdateLocationUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  location_repository: L,
+  audit_repository: A,
+}
+
+impl<L, A> UpdateLocationUseCase<L, A> where L: LocationRepository, A: AuditRepository {
+  pub fn new(location_repository: L, audit_repository: A) -> Self {
+    Self { location_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, id: &str, request: &UpdateLocationRequest) -> Result<LocationResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::UpdateLocation).by(auth_user).client(client);
+    event.set_target("locations", id);
+
+    let result = self.update(auth_user, id, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn update(&self, auth_user: &AuthenticatedUser, id: &str, request: &UpdateLocationRequest, event: &mut AuditEvent) -> Result<LocationResponse, Error> {
+    // El encargado de una sucursal puede cambiar sus datos (ej. el horario)
+    auth_user.require_at_location(&RequiredPermission::new(Resource::Settings, Action::Manage), id)?;
+
+    let location = self.location_repository
+      .find_by_id(id.to_string())
+      .await?
+      .ok_or(Error::LocationNotFound)?;
+
+    let payload = Location::new(
+      request.name.clone().unwrap_or(location.name.clone()),
+      request.address.clone().unwrap_or(location.address.clone()),
+      request.timezone.clone().unwrap_or(location.timezone.clone()),
+      request.currency.clone().unwrap_or(location.currency.clone()),
+      request.business_hours.clone().unwrap_or(location.business_hours.clone()),
+    );
+    payload.validate()?;
+
+    if payload.name != location.name {
+      if let Some(existing) = self.location_repository.find_by_name(&payload.name).await? {
+        if existing.id != location.id {
+          return Err(Error::LocationAlreadyExists(payload.name));
+        }
+      }
+    }
+
+    let updated = self.location_repository.update(id.to_string(), &payload).await?;
+
+    event.record_changes(Some(&location), Some(&updated));
+
+    Ok(updated.into())
+  }
+}
@@ -0,0 +1,45 @@
+use crate::domain::repositories::{
+  token_repository::TokenRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+  revoked_token_repository::RevokedTokenRepository,
+  oidc_authorization_repository::OidcAuthorizationRepository,
+};
+use crate::domain::error::Error;
+
+// Borra refresh tokens, tokens de un solo uso, entradas de revocación y autorizaciones OIDC ya vencidas
+pub struct CleanupExpiredTokensUseCase<T, O, K, S>
+where
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  K: RevokedTokenRepository,
+  S: OidcAuthorizationRepository,
+{
+  token_repository: T,
+  one_time_token_repository: O,
+  revoked_token_repository: K,
+  oidc_authorization_repository: S,
+}
+
+impl<T, O, K, S> CleanupExpiredTokensUseCase<T, O, K, S>
+where
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  K: RevokedTokenRepository,
+  S: OidcAuthorizationRepository,
+{
+  pub fn new(token_repository: T, one_time_token_repository: O, revoked_token_repository: K, oidc_authorization_repository: S) -> Self {
+    Self { token_repository, one_time_token_repository, revoked_token_repository, oidc_authorization_repository }
+  }
+
+  pub async fn execute(&self) -> Result<String, Error> {
+    let refresh_tokens = self.token_repository.cleanup_expired_tokens().await?;
+    let one_time_tokens = self.one_time_token_repository.cleanup_expired().await?;
+    let revoked_tokens = self.revoked_token_repository.cleanup_expired().await?;
+    let oidc_authorizations = self.oidc_authorization_repository.cleanup_expired().await?;
+
+    Ok(format!(
+      "removed {} refresh tokens, {} one-time tokens, {} revocation entries and {} OIDC authorizations",
+      refresh_tokens, one_time_tokens, revoked_tokens, oidc_authorizations
+    ))
+  }
+}
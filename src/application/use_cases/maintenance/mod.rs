@@ -0,0 +1,3 @@
+pub mod cleanup_expired_tokens;
+pub mod release_expired_locks;
+pub mod purge_deleted_records;
\ No newline at end of file
@@ -0,0 +1,44 @@
+use chrono::Utc;
+use std::time::Duration;
+
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  profile_repository::ProfileRepository,
+  api_key_repository::ApiKeyRepository,
+};
+use crate::domain::error::Error;
+
+// Borrado definitivo de lo que lleva dado de baja más que el período de retención
+pub struct PurgeDeletedRecordsUseCase<U, P, A>
+where
+  U: UserRepository,
+  P: ProfileRepository,
+  A: ApiKeyRepository,
+{
+  user_repository: U,
+  profile_repository: P,
+  api_key_repository: A,
+  retention: Duration,
+}
+
+impl<U, P, A> PurgeDeletedRecordsUseCase<U, P, A>
+where
+  U: UserRepository,
+  P: ProfileRepository,
+  A: ApiKeyRepository,
+{
+  pub fn new(user_repository: U, profile_repository: P, api_key_repository: A, retention: Duration) -> Self {
+    Self { user_repository, profile_repository, api_key_repository, retention }
+  }
+
+  pub async fn execute(&self) -> Result<String, Error> {
+    let cutoff = Utc::now() - self.retention;
+
+    // Los usuarios arrastran su perfil, así que van antes que los perfiles sueltos
+    let users = self.user_repository.purge_deleted(cutoff).await?;
+    let profiles = self.profile_repository.purge_deleted(cutoff).await?;
+    let api_keys = self.api_key_repository.purge_revoked(cutoff).await?;
+
+    Ok(format!("purged {} users, {} profiles and {} revoked API keys", users, profiles, api_keys))
+  }
+}
@@ -0,0 +1,42 @@
+use chrono::Utc;
+
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  staff_pin_repository::StaffPinRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::lockout::LockoutPolicy;
+
+// Un bloqueo vencido no se libera enseguida: se espera un período completo de bloqueo máximo sin
+// fallos nuevos antes de reiniciar el contador, si no el backoff exponencial nunca escalaría
+pub struct ReleaseExpiredLocksUseCase<U, S>
+where
+  U: UserRepository,
+  S: StaffPinRepository,
+{
+  user_repository: U,
+  staff_pin_repository: S,
+  lockout_policy: LockoutPolicy,
+  pin_lockout_policy: LockoutPolicy,
+}
+
+impl<U, S> ReleaseExpiredLocksUseCase<U, S>
+where
+  U: UserRepository,
+  S: StaffPinRepository,
+{
+  pub fn new(user_repository: U, staff_pin_repository: S, lockout_policy: LockoutPolicy, pin_lockout_policy: LockoutPolicy) -> Self {
+    Self { user_repository, staff_pin_repository, lockout_policy, pin_lockout_policy }
+  }
+
+  pub async fn execute(&self) -> Result<String, Error> {
+    let users = self.user_repository
+      .release_expired_locks(Utc::now() - self.lockout_policy.max_lockout_duration)
+      .await?;
+    let pins = self.staff_pin_repository
+      .release_expired_locks(Utc::now() - self.pin_lockout_policy.max_lockout_duration)
+      .await?;
+
+    Ok(format!("released {} account locks and {} PIN locks", users, pins))
+  }
+}
@@ -0,0 +1,45 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::mfa_repository::MfaRepository;
+use crate::domain::error::Error;
+use crate::domain::services::mfa_policy::MfaPolicy;
+use crate::domain::services::totp::TotpService;
+use crate::application::dtos::mfa::mfa_code_request::MfaCodeRequest;
+
+pub struct DisableMfaUseCase<M> where M: MfaRepository {
+  mfa_repository: M,
+  totp_service: TotpService,
+  mfa_policy: MfaPolicy,
+}
+
+impl<M> DisableMfaUseCase<M> where M: MfaRepository {
+  pub fn new(mfa_repository: M, totp_service: TotpService, mfa_policy: MfaPolicy) -> Self {
+    Self { mfa_repository, totp_service, mfa_policy }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, request: &MfaCodeRequest) -> Result<(), Error> {
+    auth_user.require_full_session()?;
+
+    // Si la política lo exige el usuario no puede desactivarlo
+    if self.mfa_policy.is_required_for(&auth_user.user.user_type) {
+      return Err(Error::MfaRequired);
+    }
+
+    let user_id = auth_user.user_id();
+
+    let credential = self.mfa_repository
+      .find_by_user(user_id.clone())
+      .await?
+      .filter(|credential| credential.enabled)
+      .ok_or(Error::MfaNotEnrolled)?;
+
+    let step = self.totp_service
+      .verify(&credential.secret, &request.code, credential.last_used_step)?
+      .ok_or(Error::InvalidMfaCode)?;
+
+    if !self.mfa_repository.record_used_step(user_id.clone(), step).await? {
+      return Err(Error::InvalidMfaCode);
+    }
+
+    self.mfa_repository.delete(user_id).await
+  }
+}
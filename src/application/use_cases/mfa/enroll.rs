@@ -0,0 +1,86 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::mfa::TotpCredential;
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::entities::user::User;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+  mfa_repository::MfaRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::token::TokenService;
+use crate::domain::services::totp::TotpService;
+use crate::application::dtos::mfa::mfa_enrollment_response::MfaEnrollmentResponse;
+
+// Genera un secreto pendiente; se activa cuando el usuario confirma el primer código
+pub struct EnrollMfaUseCase<U, O, M>
+where
+  U: UserRepository,
+  O: OneTimeTokenRepository,
+  M: MfaRepository,
+{
+  user_repository: U,
+  one_time_token_repository: O,
+  mfa_repository: M,
+  token_service: TokenService,
+  totp_service: TotpService,
+}
+
+impl<U, O, M> EnrollMfaUseCase<U, O, M>
+where
+  U: UserRepository,
+  O: OneTimeTokenRepository,
+  M: MfaRepository,
+{
+  pub fn new(user_repository: U, one_time_token_repository: O, mfa_repository: M, token_service: TokenService, totp_service: TotpService) -> Self {
+    Self { user_repository, one_time_token_repository, mfa_repository, token_service, totp_service }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<MfaEnrollmentResponse, Error> {
+    auth_user.require_full_session()?;
+
+    self.enroll(&auth_user.user).await
+  }
+
+  // Enrolamiento durante el login, cuando la política lo exige y el usuario aún no tiene sesión
+  pub async fn execute_with_challenge(&self, mfa_token: &str) -> Result<MfaEnrollmentResponse, Error> {
+    let claims = self.token_service.verify_one_time_token(mfa_token, TokenPurpose::MfaPending)?;
+
+    let record = self.one_time_token_repository
+      .find_by_jti(&claims.jti)
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if !record.is_usable() || record.user_id.id.to_string() != claims.sub {
+      return Err(Error::InvalidToken);
+    }
+
+    let user = self.user_repository
+      .find_by_id(claims.sub)
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if !user.can_sign_in() {
+      return Err(Error::InvalidToken);
+    }
+
+    self.enroll(&user).await
+  }
+
+  async fn enroll(&self, user: &User) -> Result<MfaEnrollmentResponse, Error> {
+    let user_id = user.id.clone().ok_or(Error::UserNotFound)?;
+
+    let existing = self.mfa_repository.find_by_user(user_id.id.to_string()).await?;
+
+    if existing.is_some_and(|credential| credential.enabled) {
+      return Err(Error::MfaAlreadyEnabled);
+    }
+
+    let secret = self.totp_service.generate_secret();
+    let provisioning_uri = self.totp_service.provisioning_uri(&secret, &user.email)?;
+
+    self.mfa_repository.save_pending(&TotpCredential::new(user_id, secret.clone())).await?;
+
+    Ok(MfaEnrollmentResponse { secret, provisioning_uri })
+  }
+}
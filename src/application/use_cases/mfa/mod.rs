@@ -0,0 +1,7 @@
+pub mod enroll;
+pub mod confirm;
+pub mod verify;
+pub mod regenerate_recovery_codes;
+pub mod disable;
+pub mod resolve_policy;
+pub mod set_policy;
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::mfa_repository::MfaRepository;
+use crate::domain::error::Error;
+use crate::domain::services::totp::TotpService;
+use crate::application::dtos::mfa::{
+  mfa_code_request::MfaCodeRequest,
+  recovery_codes_response::RecoveryCodesResponse,
+};
+
+// Los códigos anteriores dejan de servir
+pub struct RegenerateRecoveryCodesUseCase<M> where M: MfaRepository {
+  mfa_repository: M,
+  totp_service: TotpService,
+}
+
+impl<M> RegenerateRecoveryCodesUseCase<M> where M: MfaRepository {
+  pub fn new(mfa_repository: M, totp_service: TotpService) -> Self {
+    Self { mfa_repository, totp_service }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, request: &MfaCodeRequest) -> Result<RecoveryCodesResponse, Error> {
+    auth_user.require_full_session()?;
+
+    let user_id = auth_user.user_id();
+
+    let credential = self.mfa_repository
+      .find_by_user(user_id.clone())
+      .await?
+      .filter(|credential| credential.enabled)
+      .ok_or(Error::MfaNotEnrolled)?;
+
+    let step = self.totp_service
+      .verify(&credential.secret, &request.code, credential.last_used_step)?
+      .ok_or(Error::InvalidMfaCode)?;
+
+    if !self.mfa_repository.record_used_step(user_id.clone(), step).await? {
+      return Err(Error::InvalidMfaCode);
+    }
+
+    let recovery_codes = self.totp_service.generate_recovery_codes();
+    self.mfa_repository.replace_recovery_codes(user_id, recovery_codes.clone()).await?;
+
+    Ok(RecoveryCodesResponse { recovery_codes })
+  }
+}
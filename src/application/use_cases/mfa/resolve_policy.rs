@@ -0,0 +1,29 @@
+use crate::domain::repositories::restaurant_repository::RestaurantRepository;
+use crate::domain::services::mfa_policy::MfaPolicy;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::domain::error::Error;
+
+// Política de 2FA del tenant: la del restaurante o, si no define una, la de la plataforma
+pub struct ResolveMfaPolicyUseCase<R> where R: RestaurantRepository {
+  restaurant_repository: R,
+  default_policy: MfaPolicy,
+}
+
+impl<R> ResolveMfaPolicyUseCase<R> where R: RestaurantRepository {
+  pub fn new(restaurant_repository: R, default_policy: MfaPolicy) -> Self {
+    Self { restaurant_repository, default_policy }
+  }
+
+  pub async fn execute(&self, tenant: &TenantContext) -> Result<MfaPolicy, Error> {
+    let Some(restaurant) = tenant.record() else {
+      return Ok(self.default_policy.clone());
+    };
+
+    let restaurant = self.restaurant_repository
+      .find_by_id(restaurant.id.to_string())
+      .await?
+      .ok_or(Error::RestaurantNotFound)?;
+
+    Ok(self.default_policy.for_restaurant(&restaurant))
+  }
+}
@@ -0,0 +1,101 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::restaurant_repository::RestaurantRepository;
+use crate::domain::repositories::role_repository::RoleRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::services::mfa_policy::MfaPolicy;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::{client_info::ClientInfo, user_types::UserType};
+use crate::application::dtos::mfa::{mfa_policy_request::MfaPolicyRequest, mfa_policy_response::MfaPolicyResponse};
+use crate::domain::error::Error;
+
+use crate::application::use_cases::auth::register::find_user_type_role;
+
+// Cada restaurante puede exigir 2FA a más tipos de usuario que la configuración, nunca a menos. Solo
+// se cambia la exigencia de los tipos que quedan por debajo de quien lo hace.
+pub struct SetMfaPolicyUseCase<R, T, A> where R: RestaurantRepository, T: RoleRepository, A: AuditRepository {
+  restaurant_repository: R,
+  role_repository: T,
+  audit_repository: A,
+  default_policy: MfaPolicy,
+}
+
+impl<R, T, A> SetMfaPolicyUseCase<R, T, A> where R: RestaurantRepository, T: RoleRepository, A: AuditRepository {
+  pub fn new(restaurant_repository: R, role_repository: T, audit_repository: A, default_policy: MfaPolicy) -> Self {
+    Self { restaurant_repository, role_repository, audit_repository, default_policy }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, request: &MfaPolicyRequest) -> Result<MfaPolicyResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::SetMfaPolicy).by(auth_user).client(client);
+    let result = self.set(auth_user, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn set(&self, auth_user: &AuthenticatedUser, request: &MfaPolicyRequest, event: &mut AuditEvent) -> Result<MfaPolicyResponse, Error> {
+    auth_user.require_full_session()?;
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+
+    let restaurant_id = auth_user.tenant().record().ok_or(Error::InvalidOperation)?.id.to_string();
+
+    // Los super admins son de la plataforma, no de un restaurante
+    if request.required_user_types.as_ref().is_some_and(|types| types.contains(&UserType::SuperAdmin)) {
+      return Err(Error::ValidationError("super_admin is not a restaurant user type".to_string()));
+    }
+
+    event.set_target("restaurants", &restaurant_id);
+
+    let before = self.restaurant_repository
+      .find_by_id(restaurant_id.clone())
+      .await?
+      .ok_or(Error::RestaurantNotFound)?;
+
+    let required_user_types = request.required_user_types.as_ref().map(|types| {
+      let mut unique: Vec<UserType> = Vec::new();
+
+      for user_type in types {
+        if !unique.contains(user_type) {
+          unique.push(user_type.clone());
+        }
+      }
+
+      unique
+    });
+
+    let current = self.default_policy.for_restaurant(&before);
+    let requested = self.default_policy.adding(required_user_types.as_deref());
+
+    for user_type in current.required_user_types.iter().chain(&requested.required_user_types) {
+      if current.is_required_for(user_type) != requested.is_required_for(user_type) {
+        self.require_outranks_user_type(auth_user, user_type).await?;
+      }
+    }
+
+    let restaurant = self.restaurant_repository.set_mfa_policy(restaurant_id, required_user_types).await?;
+
+    event.record_changes(
+      Some(&serde_json::json!({ "mfa_required_user_types": before.mfa_required_user_types })),
+      Some(&serde_json::json!({ "mfa_required_user_types": restaurant.mfa_required_user_types })),
+    );
+
+    Ok(MfaPolicyResponse {
+      required_user_types: self.default_policy.for_restaurant(&restaurant).required_user_types,
+      platform_default: restaurant.mfa_required_user_types.is_none(),
+    })
+  }
+
+  // Nadie cambia la exigencia de su propio tipo de usuario ni la de uno cuyo rol le supera
+  async fn require_outranks_user_type(&self, auth_user: &AuthenticatedUser, user_type: &UserType) -> Result<(), Error> {
+    if &auth_user.user.user_type == user_type {
+      return Err(Error::RoleHierarchyViolation);
+    }
+
+    match find_user_type_role(&self.role_repository, user_type).await? {
+      Some(role) => auth_user.require_outranks(role.hierarchy_level),
+      None => Ok(()),
+    }
+  }
+}
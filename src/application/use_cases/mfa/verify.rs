@@ -0,0 +1,152 @@
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  token_repository::TokenRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+  mfa_repository::MfaRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::lockout::LockoutPolicy;
+use crate::domain::services::token::TokenService;
+use crate::domain::services::totp::TotpService;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::application::dtos::login::login_response::LoginResponse;
+use crate::application::dtos::mfa::{
+  verify_mfa_request::VerifyMfaRequest,
+  verify_mfa_response::VerifyMfaResponse,
+};
+
+// Segundo paso del login: canjea el token "mfa pending" y un código TOTP o de recuperación por la sesión
+pub struct VerifyMfaUseCase<U, T, O, M>
+where
+  U: UserRepository,
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  M: MfaRepository,
+{
+  user_repository: U,
+  token_repository: T,
+  one_time_token_repository: O,
+  mfa_repository: M,
+  token_service: TokenService,
+  totp_service: TotpService,
+  lockout_policy: LockoutPolicy,
+}
+
+impl<U, T, O, M> VerifyMfaUseCase<U, T, O, M>
+where
+  U: UserRepository,
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  M: MfaRepository,
+{
+  pub fn new(
+    user_repository: U,
+    token_repository: T,
+    one_time_token_repository: O,
+    mfa_repository: M,
+    token_service: TokenService,
+    totp_service: TotpService,
+    lockout_policy: LockoutPolicy,
+  ) -> Self {
+    Self { user_repository, token_repository, one_time_token_repository, mfa_repository, token_service, totp_service, lockout_policy }
+  }
+
+  pub async fn execute(&self, request: VerifyMfaRequest, client: &ClientInfo) -> Result<VerifyMfaResponse, Error> {
+    let claims = self.token_service.verify_one_time_token(&request.mfa_token, TokenPurpose::MfaPending)?;
+
+    let record = self.one_time_token_repository
+      .find_by_jti(&claims.jti)
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if !record.is_usable() || record.user_id.id.to_string() != claims.sub {
+      return Err(Error::InvalidToken);
+    }
+
+    let user_id = claims.sub;
+
+    let user = self.user_repository
+      .find_by_id(user_id.clone())
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if !user.can_sign_in() {
+      return Err(Error::InvalidToken);
+    }
+
+    // Los códigos fallidos cuentan como intentos de login, así no se puede probar a fuerza bruta
+    if self.lockout_policy.is_locked(&user) {
+      return Err(Error::AccountLocked(user.locked_until.unwrap()));
+    }
+
+    let credential = self.mfa_repository
+      .find_by_user(user_id.clone())
+      .await?
+      .ok_or(Error::MfaNotEnrolled)?;
+
+    let is_valid = match (&request.code, &request.recovery_code) {
+      (Some(code), _) => match self.totp_service.verify(&credential.secret, code, credential.last_used_step)? {
+        Some(step) => self.mfa_repository.record_used_step(user_id.clone(), step).await?,
+        None => false,
+      },
+      (None, Some(recovery_code)) if credential.enabled => {
+        let recovery_code = TotpService::normalize_recovery_code(recovery_code);
+        self.mfa_repository.consume_recovery_code(user_id.clone(), &recovery_code).await?
+      },
+      _ => return Err(Error::InvalidInput("code or recovery_code is required".to_string())),
+    };
+
+    if !is_valid {
+      return Err(self.register_failed_attempt(user_id, user.failed_login_attempts).await?);
+    }
+
+    // Enrolamiento exigido por la política: el primer código válido lo activa
+    let recovery_codes = if credential.enabled {
+      None
+    } else {
+      let recovery_codes = self.totp_service.generate_recovery_codes();
+      self.mfa_repository.enable(user_id.clone(), recovery_codes.clone()).await?;
+      Some(recovery_codes)
+    };
+
+    if !self.one_time_token_repository.consume(&claims.jti).await? {
+      return Err(Error::InvalidToken);
+    }
+
+    self.user_repository.record_successful_login(user_id.clone()).await?;
+
+    let token_pair = self.token_service.generate_token_pair(&user, client)?;
+
+    self.token_repository
+      .create_refresh_token(&token_pair.refresh_token)
+      .await?;
+
+    Ok(VerifyMfaResponse {
+      login: LoginResponse {
+        user_id,
+        email: user.email,
+        access_token: token_pair.access_token.token,
+        refresh_token: token_pair.refresh_token.token,
+        access_token_expires_at: token_pair.access_token.expires_at,
+      },
+      recovery_codes,
+    })
+  }
+
+  async fn register_failed_attempt(&self, user_id: String, previous_attempts: i32) -> Result<Error, Error> {
+    let attempts = previous_attempts + 1;
+
+    self.user_repository.update_failed_login_attempts(user_id.clone(), attempts).await?;
+
+    match self.lockout_policy.locked_until(attempts) {
+      Some(locked_until) => {
+        log::warn!("User {} locked until {} after {} failed two-factor attempts", user_id, locked_until, attempts);
+
+        self.user_repository.lock_account(user_id, locked_until).await?;
+        Ok(Error::AccountLocked(locked_until))
+      },
+      None => Ok(Error::InvalidMfaCode),
+    }
+  }
+}
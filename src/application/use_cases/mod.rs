@@ -2,4 +2,14 @@ pub mod auth;
 pub mod roles;
 pub mod permissions;
 pub mod profile;
-pub mod users;
\ No newline at end of file
+pub mod users;
+pub mod mfa;
+pub mod pos;
+pub mod api_keys;
+pub mod sessions;
+pub mod maintenance;
+pub mod audit;
+pub mod oidc;
+pub mod restaurants;
+pub mod locations;
+pub mod subscriptions;
\ No newline at end of file
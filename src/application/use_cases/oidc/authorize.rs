@@ -0,0 +1,37 @@
+use chrono::Utc;
+use std::sync::Arc;
+
+use crate::domain::entities::oidc_authorization::OidcAuthorization;
+use crate::domain::repositories::oidc_authorization_repository::OidcAuthorizationRepository;
+use crate::domain::services::oidc::{self, OidcConfig, OidcProvider, Pkce};
+use crate::domain::error::Error;
+use crate::application::dtos::oidc::oidc_authorization_response::OidcAuthorizationResponse;
+
+// Primer paso del login externo: guarda state, nonce y verificador PKCE y arma la URL del proveedor
+pub struct StartOidcLoginUseCase<S> where S: OidcAuthorizationRepository {
+  authorization_repository: S,
+  provider: Arc<dyn OidcProvider>,
+  config: OidcConfig,
+}
+
+impl<S> StartOidcLoginUseCase<S> where S: OidcAuthorizationRepository {
+  pub fn new(authorization_repository: S, provider: Arc<dyn OidcProvider>, config: OidcConfig) -> Self {
+    Self { authorization_repository, provider, config }
+  }
+
+  pub async fn execute(&self) -> Result<OidcAuthorizationResponse, Error> {
+    let metadata = self.provider.metadata().await?;
+    let pkce = Pkce::generate()?;
+    let state = oidc::random_token()?;
+    let nonce = oidc::random_token()?;
+    let expires_at = Utc::now() + self.config.authorization_duration;
+
+    let authorization_url = oidc::authorization_url(&self.config, &metadata, &state, &nonce, &pkce.challenge)?;
+
+    self.authorization_repository
+      .create(&OidcAuthorization::new(state.clone(), nonce, pkce.verifier, expires_at))
+      .await?;
+
+    Ok(OidcAuthorizationResponse { authorization_url, state, expires_at })
+  }
+}
@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use crate::domain::entities::user::User;
+use crate::domain::entities::external_identity::ExternalIdentity;
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  role_repository::RoleRepository,
+  token_repository::TokenRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+  mfa_repository::MfaRepository,
+  external_identity_repository::ExternalIdentityRepository,
+  oidc_authorization_repository::OidcAuthorizationRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::lockout::LockoutPolicy;
+use crate::domain::services::mfa_policy::MfaPolicy;
+use crate::domain::services::oidc::{self, IdTokenClaims, OidcConfig, OidcProvider};
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::{client_info::ClientInfo, user_status::UserStatus, user_types::UserType};
+use crate::domain::error::Error;
+use crate::application::dtos::login::login_response::{LoginOutcome, LoginResponse, MfaChallengeResponse};
+use crate::application::dtos::oidc::oidc_callback_request::OidcCallbackRequest;
+use crate::application::use_cases::auth::register::{assign_user_type_role, find_user_type_role};
+
+// Segundo paso del login externo: canjea el código, valida el ID token y resuelve el usuario.
+// La identidad se busca por (issuer, sub); si no existe se enlaza a la cuenta con el mismo email
+// verificado o se crea un cliente nuevo. Solo los clientes pueden entrar así.
+pub struct CompleteOidcLoginUseCase<U, R, T, O, M, I, S, A>
+where
+  U: UserRepository,
+  R: RoleRepository,
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  M: MfaRepository,
+  I: ExternalIdentityRepository,
+  S: OidcAuthorizationRepository,
+  A: AuditRepository,
+{
+  user_repository: U,
+  role_repository: R,
+  token_repository: T,
+  one_time_token_repository: O,
+  mfa_repository: M,
+  identity_repository: I,
+  authorization_repository: S,
+  audit_repository: A,
+  provider: Arc<dyn OidcProvider>,
+  config: OidcConfig,
+  token_service: TokenService,
+  lockout_policy: LockoutPolicy,
+  mfa_policy: MfaPolicy,
+}
+
+impl<U, R, T, O, M, I, S, A> CompleteOidcLoginUseCase<U, R, T, O, M, I, S, A>
+where
+  U: UserRepository,
+  R: RoleRepository,
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  M: MfaRepository,
+  I: ExternalIdentityRepository,
+  S: OidcAuthorizationRepository,
+  A: AuditRepository,
+{
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    user_repository: U,
+    role_repository: R,
+    token_repository: T,
+    one_time_token_repository: O,
+    mfa_repository: M,
+    identity_repository: I,
+    authorization_repository: S,
+    audit_repository: A,
+    provider: Arc<dyn OidcProvider>,
+    config: OidcConfig,
+    token_service: TokenService,
+    lockout_policy: LockoutPolicy,
+    mfa_policy: MfaPolicy,
+  ) -> Self {
+    Self {
+      user_repository,
+      role_repository,
+      token_repository,
+      one_time_token_repository,
+      mfa_repository,
+      identity_repository,
+      authorization_repository,
+      audit_repository,
+      provider,
+      config,
+      token_service,
+      lockout_policy,
+      mfa_policy,
+    }
+  }
+
+  pub async fn execute(&self, request: OidcCallbackRequest, client: &ClientInfo) -> Result<LoginOutcome, Error> {
+    let mut event = AuditEvent::new(AuditAction::Login).client(client);
+    let result = self.login(request, client, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn login(&self, request: OidcCallbackRequest, client: &ClientInfo, event: &mut AuditEvent) -> Result<LoginOutcome, Error> {
+    // El state se consume aunque el proveedor devuelva un error: no se puede reintentar con él
+    let authorization = self.authorization_repository
+      .consume(&request.state)
+      .await?
+      .ok_or(Error::InvalidToken)?;
+
+    if let Some(error) = request.error {
+      let description = request.error_description.unwrap_or_default();
+      return Err(Error::IdentityProviderError(format!("{} {}", error, description).trim().to_string()));
+    }
+
+    let code = request.code.ok_or(Error::InvalidInput("code is required".to_string()))?;
+
+    let metadata = self.provider.metadata().await?;
+    let id_token = self.provider.exchange_code(&metadata, &code, &authorization.code_verifier).await?;
+    let jwks = self.provider.jwks(&metadata).await?;
+    let claims = oidc::validate_id_token(&id_token, &jwks, &self.config, &authorization.nonce)?;
+
+    let user = self.resolve_user(&claims, event).await?;
+    let user_id = user.id.clone().ok_or(Error::UserNotFound)?.id.to_string();
+
+    event.set_target("users", &user_id);
+    event.actor_id = user.id.clone();
+
+    if !user.can_sign_in() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    // El personal entra con contraseña (y 2FA); una cuenta externa no puede saltárselo
+    if user.user_type != UserType::Customer {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    if self.lockout_policy.is_locked(&user) {
+      return Err(Error::AccountLocked(user.locked_until.unwrap()));
+    }
+
+    let credential = self.mfa_repository.find_by_user(user_id.clone()).await?;
+
+    if self.mfa_policy.applies_to(&user.user_type, credential.as_ref()) {
+      let (mfa_token, record) = self.token_service.generate_one_time_token(&user, TokenPurpose::MfaPending)?;
+      self.one_time_token_repository.create(&record).await?;
+
+      return Ok(LoginOutcome::MfaRequired(MfaChallengeResponse {
+        mfa_required: true,
+        mfa_token,
+        mfa_token_expires_at: record.expires_at,
+        enrollment_required: !credential.is_some_and(|credential| credential.enabled),
+      }));
+    }
+
+    self.user_repository.record_successful_login(user_id.clone()).await?;
+
+    let token_pair = self.token_service.generate_token_pair(&user, client)?;
+
+    self.token_repository
+      .create_refresh_token(&token_pair.refresh_token)
+      .await?;
+
+    Ok(LoginOutcome::Authenticated(LoginResponse {
+      user_id,
+      email: user.email,
+      access_token: token_pair.access_token.token,
+      refresh_token: token_pair.refresh_token.token,
+      access_token_expires_at: token_pair.access_token.expires_at,
+    }))
+  }
+
+  async fn resolve_user(&self, claims: &IdTokenClaims, event: &mut AuditEvent) -> Result<User, Error> {
+    if let Some(identity) = self.identity_repository.find_by_subject(&claims.iss, &claims.sub).await? {
+      self.identity_repository.record_login(&claims.iss, &claims.sub, claims.email.clone()).await?;
+
+      return self.user_repository
+        .find_by_id(identity.user_id.id.to_string())
+        .await?
+        .ok_or(Error::UserNotFound);
+    }
+
+    // Sin email verificado cualquiera podría apropiarse de una cuenta ajena
+    let email = claims.verified_email().ok_or(Error::UnverifiedExternalEmail)?;
+
+    let (user, created) = match self.user_repository.find_by_email(&email).await? {
+      Some(user) => (user, false),
+      None => (self.create_customer(&email).await?, true),
+    };
+
+    // Se enlaza solo a clientes; el resto de comprobaciones las hace `login` como en cada acceso
+    if user.user_type != UserType::Customer {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    let user_id = user.id.clone().ok_or(Error::UserNotFound)?;
+
+    self.identity_repository
+      .create(&ExternalIdentity::new(user_id, claims.iss.clone(), claims.sub.clone(), Some(email)))
+      .await?;
+
+    event.record_changes(None, Some(&serde_json::json!({
+      "external_identity": { "issuer": claims.iss, "subject": claims.sub },
+      "user_created": created,
+    })));
+
+    Ok(user)
+  }
+
+  // El email ya lo verificó el proveedor; la contraseña es aleatoria y se puede fijar con "olvidé mi contraseña".
+  // Recibe el mismo rol que un cliente que se registra con contraseña.
+  async fn create_customer(&self, email: &str) -> Result<User, Error> {
+    let role = find_user_type_role(&self.role_repository, &UserType::Customer).await?;
+
+    let new_user = User::new(email.to_string(), oidc::random_token()?, UserStatus::PendingVerification, UserType::Customer);
+    let user = self.user_repository.create(&new_user).await?;
+    let user_id = user.id.clone().ok_or(Error::UserCreationError("missing id".to_string()))?.id.to_string();
+
+    self.user_repository.set_verification_status(user_id.clone(), true).await?;
+
+    if let Some(role) = &role {
+      assign_user_type_role(&self.role_repository, role, user_id.clone(), user_id.clone()).await?;
+    }
+
+    self.user_repository
+      .find_by_id(user_id)
+      .await?
+      .ok_or(Error::UserNotFound)
+  }
+}
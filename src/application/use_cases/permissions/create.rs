@@ -1,22 +1,39 @@
 use std::str::FromStr;
 
 use crate::domain::entities::permission::Permission;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::application::dtos::permissions::create_permission_request::CreatePermissionRequest;
 use crate::application::dtos::permissions::permission_response::PermissionResponse;
 use crate::domain::error::Error;
 use crate::domain::entities::permission::{Resource, Action};
 
-pub struct CreatePermissionUseCase<T> where T: PermissionRepository {
+pub struct CreatePermissionUseCase<T, A> where T: PermissionRepository, A: AuditRepository {
   permission_repository: T,
+  audit_repository: A,
 }
 
-impl<T> CreatePermissionUseCase<T> where T: PermissionRepository {
-  pub fn new(permission_repository: T) -> Self {
-    Self { permission_repository }
+impl<T, A> CreatePermissionUseCase<T, A> where T: PermissionRepository, A: AuditRepository {
+  pub fn new(permission_repository: T, audit_repository: A) -> Self {
+    Self { permission_repository, audit_repository }
   }
 
-  pub async fn execute(&self, request: &CreatePermissionRequest) -> Result<PermissionResponse, Error> {
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, request: &CreatePermissionRequest) -> Result<PermissionResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::CreatePermission).by(auth_user).client(client);
+    let result = self.create(auth_user, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn create(&self, auth_user: &AuthenticatedUser, request: &CreatePermissionRequest, event: &mut AuditEvent) -> Result<PermissionResponse, Error> {
+    // El catálogo de permisos lo comparten todos los restaurantes
+    auth_user.require_platform()?;
+
     let resource = Resource::from_str(&request.resource).map_err(|_| Error::InvalidResource)?;
     let action = Action::from_str(&request.action).map_err(|_| Error::InvalidAction)?;
 
@@ -29,6 +46,9 @@ impl<T> CreatePermissionUseCase<T> where T: PermissionRepository {
 
     let permission = self.permission_repository.create(&new_permission).await?;
 
+    event.set_target("permissions", &permission.id.clone().unwrap().id.to_string());
+    event.record_changes(None, Some(&permission));
+
     Ok(PermissionResponse { 
       id: permission.id.clone().unwrap().id.to_string(),
       name: permission.name.clone(),
@@ -39,4 +59,4 @@ impl<T> CreatePermissionUseCase<T> where T: PermissionRepository {
       created_at: permission.created_at
     })
   }
-}
\ No newline at end of file
+}
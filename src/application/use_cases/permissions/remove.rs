@@ -1,24 +1,43 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::domain::error::Error;
 
-pub struct RemovePermissionUseCase<T> where T: PermissionRepository {
+pub struct RemovePermissionUseCase<T, A> where T: PermissionRepository, A: AuditRepository {
   permission_repository: T,
+  audit_repository: A,
 }
 
-impl<T> RemovePermissionUseCase<T> where T: PermissionRepository {
-  pub fn new(permission_repository: T) -> Self {
-    Self { permission_repository }
+impl<T, A> RemovePermissionUseCase<T, A> where T: PermissionRepository, A: AuditRepository {
+  pub fn new(permission_repository: T, audit_repository: A) -> Self {
+    Self { permission_repository, audit_repository }
   }
 
-  pub async fn execute(&self, id: &str) -> Result<(), Error> {
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, id: &str) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::DeletePermission).by(auth_user).client(client);
+    event.set_target("permissions", id);
+
+    let result = self.remove(auth_user, id, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn remove(&self, auth_user: &AuthenticatedUser, id: &str, event: &mut AuditEvent) -> Result<(), Error> {
+    auth_user.require_platform()?;
+
     let permission = self.permission_repository.find_by_id(id.to_string()).await?;
 
     match permission {
-      None => return Err(Error::PermissionNotFound),
+      None => Err(Error::PermissionNotFound),
       Some(permission) => {
         self.permission_repository.delete(permission.id.clone().unwrap().id.to_string()).await?;
+        event.record_changes(Some(&permission), None);
         Ok(())
       }
     }
   }
-}
\ No newline at end of file
+}
@@ -1,26 +1,44 @@
 use std::str::FromStr;
 
 use crate::domain::entities::permission::Permission;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::domain::error::Error;
 use crate::domain::entities::permission::{Resource, Action};
 use crate::application::dtos::permissions::update_permission_request::UpdatePermissionRequest;
 use crate::application::dtos::permissions::permission_response::PermissionResponse;
 
-pub struct UpdatePermissionUseCase<T> where T: PermissionRepository {
+pub struct UpdatePermissionUseCase<T, A> where T: PermissionRepository, A: AuditRepository {
   permission_repository: T,
+  audit_repository: A,
 }
 
-impl<T> UpdatePermissionUseCase<T> where T: PermissionRepository {
-  pub fn new(permission_repository: T) -> Self {
-    Self { permission_repository }
+impl<T, A> UpdatePermissionUseCase<T, A> where T: PermissionRepository, A: AuditRepository {
+  pub fn new(permission_repository: T, audit_repository: A) -> Self {
+    Self { permission_repository, audit_repository }
   }
 
-  pub async fn execute(&self, id: &str, request: &UpdatePermissionRequest) -> Result<PermissionResponse, Error> {
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, id: &str, request: &UpdatePermissionRequest) -> Result<PermissionResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::UpdatePermission).by(auth_user).client(client);
+    event.set_target("permissions", id);
+
+    let result = self.update(auth_user, id, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn update(&self, auth_user: &AuthenticatedUser, id: &str, request: &UpdatePermissionRequest, event: &mut AuditEvent) -> Result<PermissionResponse, Error> {
+    auth_user.require_platform()?;
+
     let permission = self.permission_repository.find_by_id(id.to_string()).await?;
 
     match permission {
-      None => return Err(Error::PermissionNotFound),
+      None => Err(Error::PermissionNotFound),
       Some(permission) => {
         let resource = Resource::from_str(&request.resource.clone().unwrap()).map_err(|_| Error::InvalidResource)?;
         let action = Action::from_str(&request.action.clone().unwrap()).map_err(|_| Error::InvalidAction)?;
@@ -32,6 +50,8 @@ impl<T> UpdatePermissionUseCase<T> where T: PermissionRepository {
           action
         );
         let updated_permission = self.permission_repository.update(permission.id.clone().unwrap().id.to_string(), &payload).await?;
+
+        event.record_changes(Some(&permission), Some(&updated_permission));
         
         Ok(PermissionResponse {
           id: updated_permission.id.clone().unwrap().id.to_string(),
@@ -0,0 +1,31 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::pos_device_repository::PosDeviceRepository;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::application::dtos::pos::pos_device_response::PosDeviceResponse;
+
+pub struct GetDevicesUseCase<D> where D: PosDeviceRepository {
+  pos_device_repository: D,
+}
+
+impl<D> GetDevicesUseCase<D> where D: PosDeviceRepository {
+  pub fn new(pos_device_repository: D) -> Self {
+    Self { pos_device_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<Vec<PosDeviceResponse>, Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+
+    let devices = self.pos_device_repository.find_all().await?;
+
+    Ok(devices.iter().map(|device| PosDeviceResponse {
+      id: device.id.clone().unwrap().id.to_string(),
+      name: device.name.clone(),
+      is_active: device.is_active,
+      current_user: device.current_user.as_ref().map(|user| user.id.to_string()),
+      last_used_at: device.last_used_at,
+      created_at: device.created_at,
+    }).collect())
+  }
+}
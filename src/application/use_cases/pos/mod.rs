@@ -0,0 +1,5 @@
+pub mod register_device;
+pub mod get_devices;
+pub mod revoke_device;
+pub mod set_pin;
+pub mod remove_pin;
\ No newline at end of file
@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::pos_device_repository::PosDeviceRepository;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::application::dtos::pos::{
+  register_device_request::RegisterDeviceRequest,
+  register_device_response::RegisterDeviceResponse,
+};
+
+pub struct RegisterDeviceUseCase<D> where D: PosDeviceRepository {
+  pos_device_repository: D,
+}
+
+impl<D> RegisterDeviceUseCase<D> where D: PosDeviceRepository {
+  pub fn new(pos_device_repository: D) -> Self {
+    Self { pos_device_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, request: &RegisterDeviceRequest) -> Result<RegisterDeviceResponse, Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+    auth_user.require_full_session()?;
+
+    if request.name.trim().is_empty() {
+      return Err(Error::ValidationError("Device name is required".to_string()));
+    }
+
+    let device_secret = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+    let device = self.pos_device_repository
+      .create(request.name.trim(), &device_secret, auth_user.user_id())
+      .await?;
+
+    Ok(RegisterDeviceResponse {
+      device_id: device.id.ok_or(Error::CreationFailed)?.id.to_string(),
+      device_secret,
+      name: device.name,
+    })
+  }
+}
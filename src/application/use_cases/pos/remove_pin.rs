@@ -0,0 +1,24 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::staff_pin_repository::StaffPinRepository;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+
+// Un encargado borra el PIN olvidado o bloqueado; el empleado define uno nuevo después
+pub struct RemovePinUseCase<S> where S: StaffPinRepository {
+  staff_pin_repository: S,
+}
+
+impl<S> RemovePinUseCase<S> where S: StaffPinRepository {
+  pub fn new(staff_pin_repository: S) -> Self {
+    Self { staff_pin_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, user_id: &str) -> Result<(), Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Employees, Action::Manage))?;
+
+    self.staff_pin_repository.delete(user_id.to_string()).await?;
+
+    Ok(())
+  }
+}
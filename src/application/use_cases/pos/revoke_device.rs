@@ -0,0 +1,29 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::pos_device_repository::PosDeviceRepository;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+
+// Un terminal revocado ya no puede abrir sesiones; las abiertas vencen con su token corto
+pub struct RevokeDeviceUseCase<D> where D: PosDeviceRepository {
+  pos_device_repository: D,
+}
+
+impl<D> RevokeDeviceUseCase<D> where D: PosDeviceRepository {
+  pub fn new(pos_device_repository: D) -> Self {
+    Self { pos_device_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, device_id: &str) -> Result<(), Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+
+    self.pos_device_repository
+      .find_by_id(device_id.to_string())
+      .await?
+      .ok_or(Error::InvalidInput("POS device not found".to_string()))?;
+
+    self.pos_device_repository.deactivate(device_id.to_string()).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,31 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::staff_pin_repository::StaffPinRepository;
+use crate::domain::error::Error;
+use crate::domain::services::pos::PosScope;
+use crate::domain::value_objects::user_types::UserType;
+use crate::application::dtos::pos::set_pin_request::SetPinRequest;
+
+// Cada empleado define su propio PIN desde una sesión normal (no desde el terminal)
+pub struct SetPinUseCase<S> where S: StaffPinRepository {
+  staff_pin_repository: S,
+}
+
+impl<S> SetPinUseCase<S> where S: StaffPinRepository {
+  pub fn new(staff_pin_repository: S) -> Self {
+    Self { staff_pin_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, request: &SetPinRequest) -> Result<(), Error> {
+    auth_user.require_full_session()?;
+
+    if auth_user.user.user_type == UserType::Customer {
+      return Err(Error::UnauthorizedOperation);
+    }
+
+    PosScope::validate_pin(&request.pin)?;
+
+    self.staff_pin_repository.set_pin(auth_user.user_id(), &request.pin).await?;
+
+    Ok(())
+  }
+}
@@ -1,33 +1,21 @@
-use std::str::FromStr;
-
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
 use crate::domain::entities::profile::Profile;
 use crate::domain::repositories::profile_repository::ProfileRepository;
 use crate::application::dtos::profile::create_profile_request::CreateProfileRequest;
 use crate::application::dtos::profile::profile_response::ProfileResponse;
 use crate::domain::error::Error;
 
-use surrealdb::sql::Thing;
-
-use crate::domain::services::token::TokenService;
-
 pub struct CreateProfileUseCase<T> where T: ProfileRepository {
   profile_repository: T,
-  token_service: TokenService,
 }
 
 impl<T> CreateProfileUseCase<T> where T: ProfileRepository {
-  pub fn new(profile_repository: T, token_service: TokenService) -> Self {
-    Self { profile_repository, token_service }
+  pub fn new(profile_repository: T) -> Self {
+    Self { profile_repository }
   }
 
-  pub async fn execute(&self, token: &str, request: &CreateProfileRequest) -> Result<ProfileResponse, Error> {
-    let claims = self.token_service.verify_access_token(token)?;
-
-    if self.token_service.is_token_expired(&claims) {
-      return Err(Error::TokenExpired);
-    }
-    
-    let user_id = Thing::from_str(claims.sub.as_str()).unwrap();
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, request: &CreateProfileRequest) -> Result<ProfileResponse, Error> {
+    let user_id = auth_user.user.id.clone().ok_or(Error::UserNotFound)?;
 
     let new_profile = Profile::new(
       user_id,
@@ -38,7 +26,7 @@ impl<T> CreateProfileUseCase<T> where T: ProfileRepository {
       request.position.clone(),
       request.avatar.clone(),
       request.emergency_contact.clone(),
-      request.birth_date.clone(),
+      request.birth_date,
     );
     
     let profile = self.profile_repository.create(&new_profile).await?;
@@ -1,6 +1,4 @@
-use std::str::FromStr;
-use surrealdb::sql::Thing;
-
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
 use crate::domain::entities::profile::Profile;
 use crate::domain::repositories::profile_repository::ProfileRepository;
 use crate::domain::error::Error;
@@ -8,44 +6,31 @@ use crate::domain::error::Error;
 use crate::application::dtos::profile::update_profile_request::UpdateProfileRequest;
 use crate::application::dtos::profile::profile_response::ProfileResponse;
 
-use crate::domain::services::token::TokenService;
-
 pub struct UpdateProfileUseCase<T> where T: ProfileRepository {
   profile_repository: T,
-  token_service: TokenService,
 }
 
 impl<T> UpdateProfileUseCase<T> where T: ProfileRepository {
-  pub fn new(profile_repository: T, token_service: TokenService) -> Self {
-    Self { profile_repository, token_service }
+  pub fn new(profile_repository: T) -> Self {
+    Self { profile_repository }
   }
 
-  pub async fn execute(&self, token: &str, request: &UpdateProfileRequest) -> Result<ProfileResponse, Error> {
-
-    let claims = self.token_service.verify_access_token(token)?;
-
-    if self.token_service.is_token_expired(&claims) {
-      return Err(Error::TokenExpired);
-    }
-
-    let user_id = Thing::from_str(claims.sub.as_str()).unwrap();
-
-    let profile = self.profile_repository.find_by_user_id(user_id.to_string()).await?;
-
-        match profile {
-          None => return Err(Error::ProfileNotFound),
-          Some(profile) => {
-          
-            let payload = Profile::new(
-              profile.user_id,
-              request.first_name.clone().unwrap_or(profile.first_name.clone()),
-              request.last_name.clone().unwrap_or(profile.last_name.clone()),
-              request.phone.clone().unwrap_or(profile.phone.clone()),
-              request.address.clone().unwrap_or(profile.address.clone()),
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, request: &UpdateProfileRequest) -> Result<ProfileResponse, Error> {
+    let profile = self.profile_repository.find_by_user_id(auth_user.user_id()).await?;
+
+    match profile {
+      None => Err(Error::ProfileNotFound),
+      Some(profile) => {
+        let payload = Profile::new(
+          profile.user_id,
+          request.first_name.clone().unwrap_or(profile.first_name.clone()),
+          request.last_name.clone().unwrap_or(profile.last_name.clone()),
+          request.phone.clone().unwrap_or(profile.phone.clone()),
+          request.address.clone().unwrap_or(profile.address.clone()),
           request.position.clone(),
           request.avatar.clone(),
           request.emergency_contact.clone(),
-          request.birth_date.clone().unwrap_or(profile.birth_date.clone()),
+          request.birth_date.unwrap_or(profile.birth_date),
         );
         
         let updated_profile = self.profile_repository.update(profile.id.clone().unwrap().id.to_string(), &payload).await?;
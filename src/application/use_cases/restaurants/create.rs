@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::entities::plan::DEFAULT_PLAN;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::repositories::{
+  restaurant_repository::RestaurantRepository,
+  subscription_repository::SubscriptionRepository,
+  plan_repository::PlanRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::tenant_database::TenantDatabaseProvisioner;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+
+use crate::application::dtos::restaurants::{
+  create_restaurant_request::CreateRestaurantRequest,
+  restaurant_response::RestaurantResponse,
+};
+use crate::application::use_cases::subscriptions::start::StartSubscriptionUseCase;
+
+const MIN_SLUG_LENGTH: usize = 3;
+const MAX_SLUG_LENGTH: usize = 63;
+// Subdominios que usa la propia plataforma
+const RESERVED_SLUGS: [&str; 5] = ["www", "api", "app", "admin", "mock-oidc"];
+
+pub struct CreateRestaurantUseCase<R, A, S, P>
+where
+  R: RestaurantRepository,
+  A: AuditRepository,
+  S: SubscriptionRepository,
+  P: PlanRepository,
+{
+  restaurant_repository: R,
+  audit_repository: A,
+  provisioner: Arc<dyn TenantDatabaseProvisioner>,
+  start_subscription: StartSubscriptionUseCase<S, P>,
+}
+
+impl<R, A, S, P> CreateRestaurantUseCase<R, A, S, P>
+where
+  R: RestaurantRepository,
+  A: AuditRepository,
+  S: SubscriptionRepository,
+  P: PlanRepository,
+{
+  pub fn new(
+    restaurant_repository: R,
+    audit_repository: A,
+    provisioner: Arc<dyn TenantDatabaseProvisioner>,
+    start_subscription: StartSubscriptionUseCase<S, P>,
+  ) -> Self {
+    Self { restaurant_repository, audit_repository, provisioner, start_subscription }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, request: &CreateRestaurantRequest) -> Result<RestaurantResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::CreateRestaurant).by(auth_user).client(client);
+    let result = self.create(auth_user, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn create(&self, auth_user: &AuthenticatedUser, request: &CreateRestaurantRequest, event: &mut AuditEvent) -> Result<RestaurantResponse, Error> {
+    // Los tenants los da de alta la plataforma, no un restaurante
+    if auth_user.user.user_type != UserType::SuperAdmin || !auth_user.tenant().is_platform() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    auth_user.require_full_session()?;
+
+    let name = request.name.trim();
+    let slug = request.slug.trim().to_lowercase();
+
+    if name.is_empty() {
+      return Err(Error::ValidationError("Restaurant name is required".to_string()));
+    }
+
+    validate_slug(&slug)?;
+
+    if self.restaurant_repository.find_by_slug(&slug).await?.is_some() {
+      return Err(Error::RestaurantAlreadyExists(slug));
+    }
+
+    let plan = self.start_subscription.find_plan(request.plan.as_deref().unwrap_or(DEFAULT_PLAN)).await?;
+
+    let restaurant = self.restaurant_repository
+      .create(&Restaurant::new(name.to_string(), slug, request.dedicated_database))
+      .await?;
+    let restaurant_id = restaurant.id.clone().ok_or(Error::CreationFailed)?.id.to_string();
+
+    event.set_target("restaurants", &restaurant_id);
+
+    if let Err(err) = self.start_subscription.execute(&restaurant, &plan).await {
+      self.restaurant_repository.delete(restaurant_id).await?;
+      return Err(err);
+    }
+
+    // Sin base no hay restaurante: se deshace el alta para poder reintentarla con el mismo slug
+    if restaurant.database.is_some() {
+      if let Err(err) = self.provisioner.provision(&restaurant).await {
+        log::error!("Provisioning failed for restaurant {}: {}", restaurant.slug, err);
+        self.restaurant_repository.delete(restaurant_id).await?;
+        return Err(err);
+      }
+    }
+
+    event.record_changes(None, Some(&restaurant));
+
+    Ok(restaurant.into())
+  }
+}
+
+// Debe servir como etiqueta DNS: minúsculas, dígitos y guiones que no van en los extremos
+pub fn validate_slug(slug: &str) -> Result<(), Error> {
+  let valid = (MIN_SLUG_LENGTH..=MAX_SLUG_LENGTH).contains(&slug.len())
+    && slug.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    && !slug.starts_with('-')
+    && !slug.ends_with('-');
+
+  if !valid {
+    return Err(Error::ValidationError(format!(
+      "Slug must be {}-{} lowercase letters, digits or inner hyphens",
+      MIN_SLUG_LENGTH, MAX_SLUG_LENGTH
+    )));
+  }
+
+  if RESERVED_SLUGS.contains(&slug) {
+    return Err(Error::ValidationError(format!("Slug '{}' is reserved", slug)));
+  }
+
+  Ok(())
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::repositories::{
+  restaurant_repository::RestaurantRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::tenant_database::TenantDatabaseProvisioner;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+
+// Baja definitiva de un restaurante con base dedicada: se borra la base y el restaurante.
+// Los que comparten la base de la plataforma no se pueden dar de baja así.
+pub struct DeprovisionRestaurantUseCase<R, A> where R: RestaurantRepository, A: AuditRepository {
+  restaurant_repository: R,
+  audit_repository: A,
+  provisioner: Arc<dyn TenantDatabaseProvisioner>,
+}
+
+impl<R, A> DeprovisionRestaurantUseCase<R, A> where R: RestaurantRepository, A: AuditRepository {
+  pub fn new(restaurant_repository: R, audit_repository: A, provisioner: Arc<dyn TenantDatabaseProvisioner>) -> Self {
+    Self { restaurant_repository, audit_repository, provisioner }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, id: &str) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::DeprovisionRestaurant).by(auth_user).client(client);
+    event.set_target("restaurants", id);
+
+    let result = self.deprovision(auth_user, id, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn deprovision(&self, auth_user: &AuthenticatedUser, id: &str, event: &mut AuditEvent) -> Result<(), Error> {
+    if auth_user.user.user_type != UserType::SuperAdmin || !auth_user.tenant().is_platform() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    auth_user.require_full_session()?;
+
+    let restaurant = self.restaurant_repository
+      .find_by_id(id.to_string())
+      .await?
+      .ok_or(Error::RestaurantNotFound)?;
+
+    if restaurant.database.is_none() {
+      return Err(Error::TenantNotDedicated);
+    }
+
+    self.provisioner.deprovision(&restaurant).await?;
+    self.restaurant_repository.delete(id.to_string()).await?;
+
+    event.record_changes(Some(&restaurant), None);
+
+    Ok(())
+  }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::repositories::{
+  restaurant_repository::RestaurantRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::tenant_database::TenantDatabaseProvisioner;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+
+// Volcado de la base dedicada de un restaurante, ej. para entregárselo al terminar el contrato
+pub struct ExportRestaurantUseCase<R, A> where R: RestaurantRepository, A: AuditRepository {
+  restaurant_repository: R,
+  audit_repository: A,
+  provisioner: Arc<dyn TenantDatabaseProvisioner>,
+}
+
+impl<R, A> ExportRestaurantUseCase<R, A> where R: RestaurantRepository, A: AuditRepository {
+  pub fn new(restaurant_repository: R, audit_repository: A, provisioner: Arc<dyn TenantDatabaseProvisioner>) -> Self {
+    Self { restaurant_repository, audit_repository, provisioner }
+  }
+
+  // Devuelve el slug, para nombrar el fichero, y el volcado
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, id: &str) -> Result<(String, Vec<u8>), Error> {
+    let mut event = AuditEvent::new(AuditAction::ExportRestaurant).by(auth_user).client(client);
+    event.set_target("restaurants", id);
+
+    let result = self.export(auth_user, id).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn export(&self, auth_user: &AuthenticatedUser, id: &str) -> Result<(String, Vec<u8>), Error> {
+    if auth_user.user.user_type != UserType::SuperAdmin || !auth_user.tenant().is_platform() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    auth_user.require_full_session()?;
+
+    let restaurant = self.restaurant_repository
+      .find_by_id(id.to_string())
+      .await?
+      .ok_or(Error::RestaurantNotFound)?;
+
+    let dump = self.provisioner.export(&restaurant).await?;
+
+    Ok((restaurant.slug, dump))
+  }
+}
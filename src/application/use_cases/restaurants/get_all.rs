@@ -0,0 +1,25 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::restaurant_repository::RestaurantRepository;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+use crate::application::dtos::restaurants::restaurant_response::RestaurantResponse;
+
+pub struct GetAllRestaurantsUseCase<R> where R: RestaurantRepository {
+  restaurant_repository: R,
+}
+
+impl<R> GetAllRestaurantsUseCase<R> where R: RestaurantRepository {
+  pub fn new(restaurant_repository: R) -> Self {
+    Self { restaurant_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<Vec<RestaurantResponse>, Error> {
+    if auth_user.user.user_type != UserType::SuperAdmin || !auth_user.tenant().is_platform() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    let restaurants = self.restaurant_repository.find_all().await?;
+
+    Ok(restaurants.into_iter().map(RestaurantResponse::from).collect())
+  }
+}
@@ -0,0 +1,6 @@
+pub mod create;
+pub mod get_all;
+pub mod resolve_tenant;
+pub mod deprovision;
+pub mod export;
+pub mod signup;
\ No newline at end of file
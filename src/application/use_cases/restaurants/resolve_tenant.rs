@@ -0,0 +1,30 @@
+use crate::domain::repositories::restaurant_repository::RestaurantRepository;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::domain::error::Error;
+
+// Convierte lo que trae la petición (slug del header o subdominio, o el `tid` del token)
+// en el tenant sobre el que se trabaja. Un restaurante dado de baja no atiende peticiones.
+pub struct ResolveTenantUseCase<R> where R: RestaurantRepository {
+  restaurant_repository: R,
+}
+
+impl<R> ResolveTenantUseCase<R> where R: RestaurantRepository {
+  pub fn new(restaurant_repository: R) -> Self {
+    Self { restaurant_repository }
+  }
+
+  pub async fn execute(&self, slug: Option<&str>, restaurant_id: Option<&str>) -> Result<TenantContext, Error> {
+    let restaurant = match (slug, restaurant_id) {
+      (Some(slug), _) => self.restaurant_repository.find_by_slug(&slug.to_lowercase()).await?,
+      (None, Some(restaurant_id)) => self.restaurant_repository.find_by_id(restaurant_id.to_string()).await?,
+      (None, None) => return Ok(TenantContext::platform()),
+    }
+    .ok_or(Error::RestaurantNotFound)?;
+
+    if !restaurant.is_active {
+      return Err(Error::RestaurantInactive);
+    }
+
+    Ok(TenantContext::from(&restaurant))
+  }
+}
@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::entities::plan::DEFAULT_PLAN;
+use crate::domain::entities::location::Location;
+use crate::domain::entities::user::User;
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::repositories::{
+  restaurant_repository::RestaurantRepository,
+  token_repository::TokenRepository,
+  one_time_token_repository::OneTimeTokenRepository,
+  subscription_repository::SubscriptionRepository,
+  plan_repository::PlanRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::mfa_policy::MfaPolicy;
+use crate::domain::services::password_policy::PasswordPolicy;
+use crate::domain::services::role_templates::{default_role_templates, OWNER_ROLE};
+use crate::domain::services::tenant_onboarding::TenantOnboarding;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::{client_info::ClientInfo, user_status::UserStatus, user_types::UserType};
+use crate::domain::error::Error;
+
+use crate::application::dtos::login::login_response::{LoginOutcome, LoginResponse, MfaChallengeResponse};
+use crate::application::dtos::restaurants::{
+  signup_request::SignupRequest,
+  signup_response::SignupResponse,
+};
+use crate::application::use_cases::auth::send_verification::SendVerificationEmailUseCase;
+use crate::application::use_cases::restaurants::create::validate_slug;
+use crate::application::use_cases::subscriptions::start::StartSubscriptionUseCase;
+
+// Rol de la plataforma que distingue al administrador del restaurante de su personal
+const ADMIN_ROLE: &str = "admin";
+
+// Alta de un restaurante por su cuenta, sin pasar por un super admin. Siempre usa la base
+// compartida: las bases dedicadas son cosa de contrato y las da de alta la plataforma.
+// Empieza con la prueba del plan elegido; los planes sin prueba se contratan con la plataforma.
+pub struct SignupRestaurantUseCase<R, T, O, A, S, P>
+where
+  R: RestaurantRepository,
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  A: AuditRepository,
+  S: SubscriptionRepository,
+  P: PlanRepository,
+{
+  restaurant_repository: R,
+  token_repository: T,
+  one_time_token_repository: O,
+  audit_repository: A,
+  onboarding: Arc<dyn TenantOnboarding>,
+  token_service: TokenService,
+  password_policy: PasswordPolicy,
+  mfa_policy: MfaPolicy,
+  send_verification: SendVerificationEmailUseCase<O>,
+  start_subscription: StartSubscriptionUseCase<S, P>,
+}
+
+impl<R, T, O, A, S, P> SignupRestaurantUseCase<R, T, O, A, S, P>
+where
+  R: RestaurantRepository,
+  T: TokenRepository,
+  O: OneTimeTokenRepository,
+  A: AuditRepository,
+  S: SubscriptionRepository,
+  P: PlanRepository,
+{
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    restaurant_repository: R,
+    token_repository: T,
+    one_time_token_repository: O,
+    audit_repository: A,
+    onboarding: Arc<dyn TenantOnboarding>,
+    token_service: TokenService,
+    password_policy: PasswordPolicy,
+    mfa_policy: MfaPolicy,
+    send_verification: SendVerificationEmailUseCase<O>,
+    start_subscription: StartSubscriptionUseCase<S, P>,
+  ) -> Self {
+    Self {
+      restaurant_repository,
+      token_repository,
+      one_time_token_repository,
+      audit_repository,
+      onboarding,
+      token_service,
+      password_policy,
+      mfa_policy,
+      send_verification,
+      start_subscription,
+    }
+  }
+
+  pub async fn execute(&self, request: &SignupRequest, client: &ClientInfo) -> Result<SignupResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::SignupRestaurant).client(client);
+    let result = self.signup(request, client, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn signup(&self, request: &SignupRequest, client: &ClientInfo, event: &mut AuditEvent) -> Result<SignupResponse, Error> {
+    let name = request.restaurant_name.trim();
+    let slug = request.slug.trim().to_lowercase();
+    let email = request.email.trim();
+
+    if name.is_empty() {
+      return Err(Error::ValidationError("Restaurant name is required".to_string()));
+    }
+
+    validate_slug(&slug)?;
+
+    if !email.contains('@') {
+      return Err(Error::ValidationError(format!("Invalid email: {}", email)));
+    }
+
+    self.password_policy.validate(&request.password)?;
+
+    let location = Location::new(
+      request.location.name.clone(),
+      request.location.address.clone(),
+      request.location.timezone.clone(),
+      request.location.currency.clone(),
+      request.location.business_hours.clone(),
+    );
+    location.validate()?;
+
+    if self.restaurant_repository.find_by_slug(&slug).await?.is_some() {
+      return Err(Error::RestaurantAlreadyExists(slug));
+    }
+
+    let plan = self.start_subscription.find_plan(request.plan.as_deref().unwrap_or(DEFAULT_PLAN)).await?;
+
+    if plan.trial_days == 0 {
+      return Err(Error::ValidationError(format!("Plan {} has no trial period", plan.code)));
+    }
+
+    let restaurant = self.restaurant_repository
+      .create(&Restaurant::new(name.to_string(), slug, false))
+      .await?;
+    let restaurant_id = restaurant.id.clone().ok_or(Error::CreationFailed)?.id.to_string();
+
+    event.set_target("restaurants", &restaurant_id);
+
+    let owner = User::new(email.to_string(), request.password.clone(), UserStatus::PendingVerification, UserType::Admin);
+
+    // Un restaurante sin dueño no sirve: se deshace el alta, suscripción incluida, para poder
+    // reintentarla con el mismo slug
+    let onboarded = match self.start_subscription.execute(&restaurant, &plan).await {
+      Ok(_) => self.onboarding
+        .onboard(&restaurant, &location, &owner, &default_role_templates(), &[ADMIN_ROLE, OWNER_ROLE])
+        .await,
+      Err(err) => Err(err),
+    };
+
+    let (owner, location) = match onboarded {
+      Ok(onboarded) => onboarded,
+      Err(err) => {
+        log::error!("Onboarding failed for restaurant {}: {}", restaurant.slug, err);
+        self.restaurant_repository.delete(restaurant_id).await?;
+        return Err(err);
+      }
+    };
+
+    event.actor_id = owner.id.clone();
+    event.record_changes(None, Some(&serde_json::json!({
+      "restaurant": restaurant.slug,
+      "location": location.name,
+      "owner": owner.email,
+      "plan": plan.code,
+    })));
+
+    // Si el correo falla el dueño puede pedir el reenvío, el alta no se revierte
+    if let Err(err) = self.send_verification.execute(&owner).await {
+      log::error!("Failed to send verification email to {}: {}", owner.email, err);
+    }
+
+    let session = self.open_session(&owner, client).await?;
+
+    Ok(SignupResponse {
+      restaurant: restaurant.into(),
+      location: location.into(),
+      session,
+    })
+  }
+
+  // Igual que en el login: si la política exige 2FA a los administradores, primero debe configurarlo
+  async fn open_session(&self, owner: &User, client: &ClientInfo) -> Result<LoginOutcome, Error> {
+    if self.mfa_policy.is_required_for(&owner.user_type) {
+      let (mfa_token, record) = self.token_service.generate_one_time_token(owner, TokenPurpose::MfaPending)?;
+      self.one_time_token_repository.create(&record).await?;
+
+      return Ok(LoginOutcome::MfaRequired(MfaChallengeResponse {
+        mfa_required: true,
+        mfa_token,
+        mfa_token_expires_at: record.expires_at,
+        enrollment_required: true,
+      }));
+    }
+
+    let token_pair = self.token_service.generate_token_pair(owner, client)?;
+
+    self.token_repository
+      .create_refresh_token(&token_pair.refresh_token)
+      .await?;
+
+    Ok(LoginOutcome::Authenticated(LoginResponse {
+      user_id: owner.id.clone().ok_or(Error::CreationFailed)?.id.to_string(),
+      email: owner.email.clone(),
+      access_token: token_pair.access_token.token,
+      refresh_token: token_pair.refresh_token.token,
+      access_token_expires_at: token_pair.access_token.expires_at,
+    }))
+  }
+}
@@ -0,0 +1,103 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::entities::role::Role;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  role_repository::RoleRepository,
+  location_repository::LocationRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::error::Error;
+
+use crate::application::dtos::roles::assign_role_request::AssignRoleRequest;
+use crate::application::use_cases::auth::register::find_user_type_role;
+
+// Asigna un rol en todo el restaurante o solo en una sucursal; el encargado de una sucursal
+// solo puede repartir roles dentro de ella. Tanto el rol como el usuario que lo recibe deben
+// quedar por debajo del nivel de quien lo asigna.
+pub struct AssignRoleUseCase<U, R, L, A> where U: UserRepository, R: RoleRepository, L: LocationRepository, A: AuditRepository {
+  user_repository: U,
+  role_repository: R,
+  location_repository: L,
+  audit_repository: A,
+}
+
+impl<U, R, L, A> AssignRoleUseCase<U, R, L, A> where U: UserRepository, R: RoleRepository, L: LocationRepository, A: AuditRepository {
+  pub fn new(user_repository: U, role_repository: R, location_repository: L, audit_repository: A) -> Self {
+    Self { user_repository, role_repository, location_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, user_id: &str, request: &AssignRoleRequest) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::AssignRole).by(auth_user).client(client);
+    event.set_target("users", user_id);
+    event.record_changes(None, Some(request));
+
+    let result = self.assign(auth_user, user_id, request).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn assign(&self, auth_user: &AuthenticatedUser, user_id: &str, request: &AssignRoleRequest) -> Result<(), Error> {
+    let required = RequiredPermission::new(Resource::Users, Action::Manage);
+
+    match &request.location_id {
+      Some(location_id) => {
+        auth_user.require_at_location(&required, location_id)?;
+
+        self.location_repository
+          .find_by_id(location_id.clone())
+          .await?
+          .ok_or(Error::LocationNotFound)?;
+
+        // Un rol de sucursal solo tiene sentido para quien trabaja en ella
+        if !self.location_repository.is_user_assigned(user_id.to_string(), location_id.clone()).await? {
+          return Err(Error::ValidationError("User is not assigned to the location".to_string()));
+        }
+      },
+      None => auth_user.require(&required)?,
+    }
+
+    let location_id = request.location_id.as_deref();
+
+    let role = self.role_repository
+      .find_by_id(request.role_id.clone())
+      .await?
+      .ok_or(Error::RoleNotFound)?;
+
+    auth_user.require_outranks_at(role.hierarchy_level, location_id)?;
+    auth_user.require_outranks_user(&user_roles(&self.user_repository, &self.role_repository, user_id, location_id).await?, location_id)?;
+
+    self.role_repository
+      .assign_role_to_user(user_id.to_string(), request.role_id.clone(), request.location_id.clone(), auth_user.user_id())
+      .await
+  }
+}
+
+// Roles de todo el restaurante más, si se actúa en una sucursal, los que tiene en ella. El rol de
+// su tipo de usuario cuenta siempre, tenga o no la asignación, para que un admin o un super admin
+// sin roles no quede por debajo de nadie.
+pub async fn user_roles<U, R>(user_repository: &U, role_repository: &R, user_id: &str, location_id: Option<&str>) -> Result<Vec<Role>, Error>
+where
+  U: UserRepository,
+  R: RoleRepository,
+{
+  let user = user_repository
+    .find_by_id(user_id.to_string())
+    .await?
+    .ok_or(Error::UserNotFound)?;
+
+  let mut roles = role_repository.find_user_roles(user_id.to_string()).await?;
+
+  if let Some(location_id) = location_id {
+    roles.extend(role_repository.find_user_location_roles(user_id.to_string(), location_id.to_string()).await?);
+  }
+
+  roles.extend(find_user_type_role(role_repository, &user.user_type).await?);
+
+  Ok(roles)
+}
@@ -1,30 +1,95 @@
+use std::str::FromStr;
+
 use crate::domain::entities::role::Role;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
 use crate::domain::repositories::role_repository::RoleRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::services::role_templates::find_role_template;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::application::dtos::roles::create_role_request::CreateRoleRequest;
 use crate::application::dtos::roles::role_response::RoleResponse;
 use crate::domain::error::Error;
 
-pub struct CreateRoleUseCase<T> where T: RoleRepository {
+// Rol propio del restaurante, desde cero o copiando una plantilla. Solo se crean roles por
+// debajo del propio nivel y con permisos que uno mismo tiene.
+pub struct CreateRoleUseCase<T, A> where T: RoleRepository, A: AuditRepository {
   role_repository: T,
+  audit_repository: A,
 }
 
-impl<T> CreateRoleUseCase<T> where T: RoleRepository {
-  pub fn new(role_repository: T) -> Self {
-    Self { role_repository }
+impl<T, A> CreateRoleUseCase<T, A> where T: RoleRepository, A: AuditRepository {
+  pub fn new(role_repository: T, audit_repository: A) -> Self {
+    Self { role_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, request: &CreateRoleRequest) -> Result<RoleResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::CreateRole).by(auth_user).client(client);
+    let result = self.create(auth_user, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
   }
 
-  pub async fn execute(&self, request: &CreateRoleRequest) -> Result<RoleResponse, Error> {
-    let new_role = Role::new(request.name.clone(), request.description.clone(), request.hierarchy_level.clone());
+  async fn create(&self, auth_user: &AuthenticatedUser, request: &CreateRoleRequest, event: &mut AuditEvent) -> Result<RoleResponse, Error> {
+    let template = match request.template.as_deref() {
+      Some(name) => Some(find_role_template(name)
+        .ok_or(Error::ValidationError(format!("Unknown role template: {}", name)))?),
+      None => None,
+    };
+
+    let description = request.description.clone()
+      .or(template.as_ref().map(|template| template.description.to_string()))
+      .ok_or(Error::ValidationError("Role description is required".to_string()))?;
+    let hierarchy_level = request.hierarchy_level
+      .or(template.as_ref().map(|template| template.hierarchy_level))
+      .ok_or(Error::ValidationError("Role hierarchy level is required".to_string()))?;
+
+    let new_role = Role::new(request.name.trim().to_string(), description, hierarchy_level);
+    new_role.validate()?;
+
+    auth_user.require_outranks(new_role.hierarchy_level)?;
 
-    let role = self.role_repository.create(&new_role).await?;
+    let mut permissions = template.map(|template| template.permissions).unwrap_or_default();
 
-    Ok(RoleResponse { 
-      id: role.id.clone().unwrap().id.to_string(),
+    for permission in &request.permissions {
+      let permission = RequiredPermission::from_str(permission)?;
+
+      if !permissions.contains(&permission) {
+        permissions.push(permission);
+      }
+    }
+
+    // Nadie puede entregar un permiso que no tiene; los super admins de la plataforma los tienen todos
+    if !auth_user.is_platform_admin() {
+      for permission in &permissions {
+        auth_user.require(permission)?;
+      }
+    }
+
+    if self.role_repository.find_by_name(&new_role.name).await?.is_some() {
+      return Err(Error::RoleAlreadyExists(new_role.name));
+    }
+
+    let role = self.role_repository.create(&new_role, &permissions).await?;
+
+    event.set_target("roles", &role.id.clone().ok_or(Error::CreationFailed)?.id.to_string());
+    event.record_changes(None, Some(&serde_json::json!({
+      "role": role,
+      "template": request.template,
+      "permissions": permissions.iter().map(|permission| permission.to_string()).collect::<Vec<_>>(),
+    })));
+
+    Ok(RoleResponse {
+      id: role.id.clone().ok_or(Error::CreationFailed)?.id.to_string(),
       name: role.name.clone(),
       description: role.description.clone(),
-      hierarchy_level: role.hierarchy_level.clone(),
+      hierarchy_level: role.hierarchy_level,
       is_active: role.is_active,
-      created_at: role.created_at
+      created_at: role.created_at,
+      permissions: permissions.iter().map(|permission| permission.to_string()).collect(),
     })
   }
-}
\ No newline at end of file
+}
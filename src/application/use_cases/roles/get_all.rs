@@ -21,6 +21,7 @@ impl<T> GetAllRolesUseCase<T> where T: RoleRepository {
       hierarchy_level: role.hierarchy_level,
       is_active: role.is_active,
       created_at: role.created_at,
+      permissions: Vec::new(),
     }).collect())
   }
 }
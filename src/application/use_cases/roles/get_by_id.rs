@@ -28,6 +28,7 @@ impl<T> GetRoleByIdUseCase<T> where T: RoleRepository {
       hierarchy_level: role.hierarchy_level,
       is_active: role.is_active,
       created_at: role.created_at,
+      permissions: Vec::new(),
     })
   }
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::domain::services::role_templates::default_role_templates;
+use crate::application::dtos::roles::role_template_response::RoleTemplateResponse;
+
+// Plantillas de las que un restaurante puede copiar sus roles
+pub struct GetRoleTemplatesUseCase;
+
+impl GetRoleTemplatesUseCase {
+  pub fn execute(&self) -> Vec<RoleTemplateResponse> {
+    default_role_templates().into_iter().map(RoleTemplateResponse::from).collect()
+  }
+}
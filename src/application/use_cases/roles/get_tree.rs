@@ -0,0 +1,114 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::Permission;
+use crate::domain::entities::role::Role;
+use crate::domain::repositories::{
+  role_repository::RoleRepository,
+  permission_repository::PermissionRepository,
+  restaurant_repository::RestaurantRepository,
+};
+use crate::domain::services::role_hierarchy::RoleHierarchy;
+use crate::application::dtos::roles::role_tree_response::{RoleTreeLevel, RoleTreeNode, RoleTreeResponse};
+use crate::domain::error::Error;
+
+// Árbol de roles efectivo del usuario: sus roles y los del restaurante que quedan por debajo,
+// que son los que puede gestionar
+pub struct GetRoleTreeUseCase<T, P, R> where T: RoleRepository, P: PermissionRepository, R: RestaurantRepository {
+  role_repository: T,
+  permission_repository: P,
+  restaurant_repository: R,
+}
+
+impl<T, P, R> GetRoleTreeUseCase<T, P, R> where T: RoleRepository, P: PermissionRepository, R: RestaurantRepository {
+  pub fn new(role_repository: T, permission_repository: P, restaurant_repository: R) -> Self {
+    Self { role_repository, permission_repository, restaurant_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<RoleTreeResponse, Error> {
+    let inheritance = match auth_user.tenant().record() {
+      Some(restaurant) => self.restaurant_repository
+        .find_by_id(restaurant.id.to_string())
+        .await?
+        .is_some_and(|restaurant| restaurant.inherit_role_permissions),
+      None => false,
+    };
+
+    let assigned: Vec<&Role> = auth_user.roles.iter().chain(&auth_user.location_roles).collect();
+    let hierarchy_level = RoleHierarchy::level(assigned.iter().copied());
+
+    let effective_permissions = permission_names(auth_user.permissions.iter().chain(&auth_user.location_permissions));
+
+    let Some(level) = hierarchy_level else {
+      return Ok(RoleTreeResponse { hierarchy_level, inheritance, effective_permissions, levels: Vec::new() });
+    };
+
+    let tenant_roles = self.role_repository.find_all().await?;
+    let below = RoleHierarchy::inherited(level, &tenant_roles);
+
+    let mut roles: Vec<&Role> = assigned.clone();
+    roles.extend(below.iter().filter(|role| !assigned.iter().any(|own| own.id == role.id)));
+
+    let mut permissions = HashMap::new();
+
+    for role in &roles {
+      let id = role_id(role)?;
+      let role_permissions = self.permission_repository.find_role_permissions(id.clone()).await?;
+      permissions.insert(id, permission_names(&role_permissions));
+    }
+
+    let mut levels: BTreeMap<i32, Vec<RoleTreeNode>> = BTreeMap::new();
+
+    for role in roles {
+      let id = role_id(role)?;
+
+      // Igual que en la autenticación: solo heredan los roles del restaurante
+      let inherited_permissions = match inheritance && role.restaurant_id.is_some() {
+        true => {
+          let mut inherited: Vec<String> = RoleHierarchy::inherited(role.hierarchy_level, &tenant_roles)
+            .iter()
+            .filter_map(|lower| role_id(lower).ok().and_then(|id| permissions.get(&id)))
+            .flatten()
+            .filter(|permission| !permissions[&id].contains(permission))
+            .cloned()
+            .collect();
+          inherited.sort();
+          inherited.dedup();
+          inherited
+        }
+        false => Vec::new(),
+      };
+
+      levels.entry(role.hierarchy_level).or_default().push(RoleTreeNode {
+        assigned: assigned.iter().any(|own| own.id == role.id),
+        permissions: permissions[&id].clone(),
+        id,
+        name: role.name.clone(),
+        description: role.description.clone(),
+        inherited_permissions,
+      });
+    }
+
+    Ok(RoleTreeResponse {
+      hierarchy_level,
+      inheritance,
+      effective_permissions,
+      levels: levels.into_iter()
+        .map(|(hierarchy_level, roles)| RoleTreeLevel { hierarchy_level, roles })
+        .collect(),
+    })
+  }
+}
+
+fn role_id(role: &Role) -> Result<String, Error> {
+  Ok(role.id.clone().ok_or(Error::RoleNotFound)?.id.to_string())
+}
+
+fn permission_names<'a>(permissions: impl IntoIterator<Item = &'a Permission>) -> Vec<String> {
+  let mut names: Vec<String> = permissions.into_iter()
+    .map(|permission| format!("{}:{}", permission.resource, permission.action))
+    .collect();
+  names.sort();
+  names.dedup();
+  names
+}
@@ -2,4 +2,9 @@ pub mod create;
 pub mod update;
 pub mod remove;
 pub mod get_by_id;
-pub mod get_all;
\ No newline at end of file
+pub mod get_all;
+pub mod assign;
+pub mod unassign;
+pub mod get_templates;
+pub mod set_inheritance;
+pub mod get_tree;
\ No newline at end of file
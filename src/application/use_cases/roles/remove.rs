@@ -1,16 +1,32 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
 use crate::domain::repositories::role_repository::RoleRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::domain::error::Error;
 
-pub struct RemoveRoleUseCase<T> where T: RoleRepository {
+pub struct RemoveRoleUseCase<T, A> where T: RoleRepository, A: AuditRepository {
   role_repository: T,
+  audit_repository: A,
 }
 
-impl<T> RemoveRoleUseCase<T> where T: RoleRepository {
-  pub fn new(role_repository: T) -> Self {
-    Self { role_repository }
+impl<T, A> RemoveRoleUseCase<T, A> where T: RoleRepository, A: AuditRepository {
+  pub fn new(role_repository: T, audit_repository: A) -> Self {
+    Self { role_repository, audit_repository }
   }
 
-  pub async fn execute(&self, id: &str) -> Result<(), Error> {
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, id: &str) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::DeleteRole).by(auth_user).client(client);
+    event.set_target("roles", id);
+
+    let result = self.remove(auth_user, id, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn remove(&self, auth_user: &AuthenticatedUser, id: &str, event: &mut AuditEvent) -> Result<(), Error> {
     let role = self.role_repository.find_by_id(id.to_string()).await?;
 
     if role.is_none() {
@@ -19,7 +35,11 @@ impl<T> RemoveRoleUseCase<T> where T: RoleRepository {
     
     let role = role.unwrap();
 
+    auth_user.require_outranks(role.hierarchy_level)?;
+
     self.role_repository.delete(role.id.clone().unwrap().id.to_string()).await?;
+    event.record_changes(Some(&role), None);
+
     Ok(())
   }
-}
\ No newline at end of file
+}
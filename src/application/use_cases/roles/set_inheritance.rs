@@ -0,0 +1,54 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::restaurant_repository::RestaurantRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::application::dtos::restaurants::restaurant_response::RestaurantResponse;
+use crate::domain::error::Error;
+
+// Activa o desactiva la herencia de permisos entre los roles del restaurante. Los roles de la
+// plataforma nunca heredan: la opción es por restaurante.
+pub struct SetRoleInheritanceUseCase<R, A> where R: RestaurantRepository, A: AuditRepository {
+  restaurant_repository: R,
+  audit_repository: A,
+}
+
+impl<R, A> SetRoleInheritanceUseCase<R, A> where R: RestaurantRepository, A: AuditRepository {
+  pub fn new(restaurant_repository: R, audit_repository: A) -> Self {
+    Self { restaurant_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, enabled: bool) -> Result<RestaurantResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::SetRoleInheritance).by(auth_user).client(client);
+    let result = self.set(auth_user, enabled, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn set(&self, auth_user: &AuthenticatedUser, enabled: bool, event: &mut AuditEvent) -> Result<RestaurantResponse, Error> {
+    let restaurant_id = auth_user.tenant().record().ok_or(Error::InvalidOperation)?.id.to_string();
+
+    // Cambia los permisos efectivos de todos los roles: es configuración del restaurante
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Manage))?;
+
+    event.set_target("restaurants", &restaurant_id);
+
+    let before = self.restaurant_repository
+      .find_by_id(restaurant_id.clone())
+      .await?
+      .ok_or(Error::RestaurantNotFound)?;
+
+    let restaurant = self.restaurant_repository.set_role_inheritance(restaurant_id, enabled).await?;
+
+    event.record_changes(
+      Some(&serde_json::json!({ "inherit_role_permissions": before.inherit_role_permissions })),
+      Some(&serde_json::json!({ "inherit_role_permissions": restaurant.inherit_role_permissions })),
+    );
+
+    Ok(restaurant.into())
+  }
+}
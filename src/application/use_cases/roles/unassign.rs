@@ -0,0 +1,59 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  role_repository::RoleRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::error::Error;
+
+use crate::application::use_cases::roles::assign::user_roles;
+
+// Mismas reglas que al asignar: el rol y el usuario deben quedar por debajo del propio nivel
+pub struct UnassignRoleUseCase<U, R, A> where U: UserRepository, R: RoleRepository, A: AuditRepository {
+  user_repository: U,
+  role_repository: R,
+  audit_repository: A,
+}
+
+impl<U, R, A> UnassignRoleUseCase<U, R, A> where U: UserRepository, R: RoleRepository, A: AuditRepository {
+  pub fn new(user_repository: U, role_repository: R, audit_repository: A) -> Self {
+    Self { user_repository, role_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, user_id: &str, role_id: &str, location_id: Option<&str>) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::UnassignRole).by(auth_user).client(client);
+    event.set_target("users", user_id);
+    event.record_changes(Some(&serde_json::json!({ "role_id": role_id, "location_id": location_id })), None);
+
+    let result = self.unassign(auth_user, user_id, role_id, location_id).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn unassign(&self, auth_user: &AuthenticatedUser, user_id: &str, role_id: &str, location_id: Option<&str>) -> Result<(), Error> {
+    let required = RequiredPermission::new(Resource::Users, Action::Manage);
+
+    match location_id {
+      Some(location_id) => auth_user.require_at_location(&required, location_id)?,
+      None => auth_user.require(&required)?,
+    }
+
+    let role = self.role_repository
+      .find_by_id(role_id.to_string())
+      .await?
+      .ok_or(Error::RoleNotFound)?;
+
+    auth_user.require_outranks_at(role.hierarchy_level, location_id)?;
+    auth_user.require_outranks_user(&user_roles(&self.user_repository, &self.role_repository, user_id, location_id).await?, location_id)?;
+
+    self.role_repository
+      .remove_role_from_user(user_id.to_string(), role_id.to_string(), location_id.map(str::to_string))
+      .await
+  }
+}
@@ -1,20 +1,36 @@
 use crate::domain::entities::role::Role;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
 use crate::domain::repositories::role_repository::RoleRepository;
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::services::audit;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::domain::error::Error;
 
 use crate::application::dtos::roles::update_role_request::UpdateRoleRequest;
 use crate::application::dtos::roles::role_response::RoleResponse;
 
-pub struct UpdateRoleUseCase<T> where T: RoleRepository {
+pub struct UpdateRoleUseCase<T, A> where T: RoleRepository, A: AuditRepository {
   role_repository: T,
+  audit_repository: A,
 }
 
-impl<T> UpdateRoleUseCase<T> where T: RoleRepository {
-  pub fn new(role_repository: T) -> Self {
-    Self { role_repository }
+impl<T, A> UpdateRoleUseCase<T, A> where T: RoleRepository, A: AuditRepository {
+  pub fn new(role_repository: T, audit_repository: A) -> Self {
+    Self { role_repository, audit_repository }
   }
 
-  pub async fn execute(&self, id: &str, request: &UpdateRoleRequest) -> Result<RoleResponse, Error> {
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, id: &str, request: &UpdateRoleRequest) -> Result<RoleResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::UpdateRole).by(auth_user).client(client);
+    event.set_target("roles", id);
+
+    let result = self.update(auth_user, id, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn update(&self, auth_user: &AuthenticatedUser, id: &str, request: &UpdateRoleRequest, event: &mut AuditEvent) -> Result<RoleResponse, Error> {
     
     let role = self.role_repository.find_by_id(id.to_string()).await?;
 
@@ -22,8 +38,27 @@ impl<T> UpdateRoleUseCase<T> where T: RoleRepository {
       return Err(Error::RoleNotFound);
     }
 
-    let payload = Role::new(request.name.clone().unwrap(), request.description.clone().unwrap(), request.hierarchy_level.unwrap());
-    let updated_role = self.role_repository.update(role.unwrap().id.clone().unwrap().id.to_string(), &payload).await?;
+    let role = role.unwrap();
+
+    // Ni se editan roles del propio nivel o superiores ni se sube uno hasta él
+    auth_user.require_outranks(role.hierarchy_level)?;
+
+    let payload = Role::new(
+      request.name.clone().unwrap_or(role.name.clone()),
+      request.description.clone().unwrap_or(role.description.clone()),
+      request.hierarchy_level.unwrap_or(role.hierarchy_level),
+    );
+    payload.validate()?;
+
+    auth_user.require_outranks(payload.hierarchy_level)?;
+
+    if payload.name != role.name && self.role_repository.find_by_name(&payload.name).await?.is_some() {
+      return Err(Error::RoleAlreadyExists(payload.name));
+    }
+
+    let updated_role = self.role_repository.update(role.id.clone().unwrap().id.to_string(), &payload).await?;
+
+    event.record_changes(Some(&role), Some(&updated_role));
 
     Ok(RoleResponse {
       id: updated_role.id.clone().unwrap().id.to_string(),
@@ -32,6 +67,7 @@ impl<T> UpdateRoleUseCase<T> where T: RoleRepository {
       hierarchy_level: updated_role.hierarchy_level,
       is_active: updated_role.is_active,
       created_at: updated_role.created_at,
+      permissions: Vec::new(),
     })
   }
 }
@@ -0,0 +1,46 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  token_repository::TokenRepository,
+  user_repository::UserRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::application::dtos::sessions::session_response::SessionResponse;
+
+// Vista de administración de las sesiones de otro usuario
+pub struct GetUserSessionsUseCase<U, T>
+where
+  U: UserRepository,
+  T: TokenRepository,
+{
+  user_repository: U,
+  token_repository: T,
+}
+
+impl<U, T> GetUserSessionsUseCase<U, T>
+where
+  U: UserRepository,
+  T: TokenRepository,
+{
+  pub fn new(user_repository: U, token_repository: T) -> Self {
+    Self { user_repository, token_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, user_id: &str) -> Result<Vec<SessionResponse>, Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Users, Action::Manage))?;
+
+    self.user_repository
+      .find_by_id(user_id.to_string())
+      .await?
+      .ok_or(Error::UserNotFound)?;
+
+    let current_session = auth_user.session_id();
+    let sessions = self.token_repository.find_active_sessions(user_id.to_string()).await?;
+
+    Ok(sessions
+      .into_iter()
+      .map(|session| SessionResponse::from_token(session, current_session.as_deref()))
+      .collect())
+  }
+}
@@ -0,0 +1,26 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::token_repository::TokenRepository;
+use crate::domain::error::Error;
+use crate::application::dtos::sessions::session_response::SessionResponse;
+
+pub struct GetMySessionsUseCase<T> where T: TokenRepository {
+  token_repository: T,
+}
+
+impl<T> GetMySessionsUseCase<T> where T: TokenRepository {
+  pub fn new(token_repository: T) -> Self {
+    Self { token_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<Vec<SessionResponse>, Error> {
+    auth_user.require_full_session()?;
+
+    let current_session = auth_user.session_id();
+    let sessions = self.token_repository.find_active_sessions(auth_user.user_id()).await?;
+
+    Ok(sessions
+      .into_iter()
+      .map(|session| SessionResponse::from_token(session, current_session.as_deref()))
+      .collect())
+  }
+}
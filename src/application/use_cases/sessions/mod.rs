@@ -0,0 +1,4 @@
+pub mod get_mine;
+pub mod get_by_user;
+pub mod revoke;
+pub mod revoke_all;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::token_repository::TokenRepository;
+use crate::domain::error::Error;
+
+// Cierra una sesión propia; el access token ya emitido vale hasta que vence
+pub struct RevokeSessionUseCase<T> where T: TokenRepository {
+  token_repository: T,
+}
+
+impl<T> RevokeSessionUseCase<T> where T: TokenRepository {
+  pub fn new(token_repository: T) -> Self {
+    Self { token_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, session_id: &str) -> Result<(), Error> {
+    auth_user.require_full_session()?;
+
+    if !self.token_repository.revoke_session(auth_user.user_id(), session_id.to_string()).await? {
+      return Err(Error::SessionNotFound);
+    }
+
+    Ok(())
+  }
+}
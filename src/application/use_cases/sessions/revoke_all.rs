@@ -0,0 +1,37 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::{
+  token_repository::TokenRepository,
+  revoked_token_repository::RevokedTokenRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::token::TokenService;
+
+// "Cerrar sesión en todos los dispositivos", incluida la actual
+pub struct RevokeAllSessionsUseCase<T, K>
+where
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+{
+  token_repository: T,
+  revoked_token_repository: K,
+  token_service: TokenService,
+}
+
+impl<T, K> RevokeAllSessionsUseCase<T, K>
+where
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+{
+  pub fn new(token_repository: T, revoked_token_repository: K, token_service: TokenService) -> Self {
+    Self { token_repository, revoked_token_repository, token_service }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<(), Error> {
+    auth_user.require_full_session()?;
+
+    self.token_repository.invalidate_all_user_tokens(auth_user.user_id()).await?;
+    self.revoked_token_repository
+      .revoke(&self.token_service.revocation_for_user(&auth_user.user_id()))
+      .await
+  }
+}
@@ -0,0 +1,90 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::entities::subscription::Subscription;
+use crate::domain::repositories::{
+  restaurant_repository::RestaurantRepository,
+  subscription_repository::SubscriptionRepository,
+  plan_repository::PlanRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+
+use crate::application::dtos::subscriptions::{
+  change_plan_request::ChangePlanRequest,
+  subscription_response::SubscriptionResponse,
+};
+
+// Cambio de plan o de estado de un restaurante; lo hace la plataforma tras el cobro o la baja
+pub struct ChangePlanUseCase<R, S, P, A>
+where
+  R: RestaurantRepository,
+  S: SubscriptionRepository,
+  P: PlanRepository,
+  A: AuditRepository,
+{
+  restaurant_repository: R,
+  subscription_repository: S,
+  plan_repository: P,
+  audit_repository: A,
+}
+
+impl<R, S, P, A> ChangePlanUseCase<R, S, P, A>
+where
+  R: RestaurantRepository,
+  S: SubscriptionRepository,
+  P: PlanRepository,
+  A: AuditRepository,
+{
+  pub fn new(restaurant_repository: R, subscription_repository: S, plan_repository: P, audit_repository: A) -> Self {
+    Self { restaurant_repository, subscription_repository, plan_repository, audit_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, restaurant_id: &str, request: &ChangePlanRequest) -> Result<SubscriptionResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::ChangeSubscription).by(auth_user).client(client);
+    event.set_target("restaurants", restaurant_id);
+
+    let result = self.change(auth_user, restaurant_id, request, &mut event).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn change(&self, auth_user: &AuthenticatedUser, restaurant_id: &str, request: &ChangePlanRequest, event: &mut AuditEvent) -> Result<SubscriptionResponse, Error> {
+    if auth_user.user.user_type != UserType::SuperAdmin || !auth_user.tenant().is_platform() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    auth_user.require_full_session()?;
+
+    let restaurant = self.restaurant_repository
+      .find_by_id(restaurant_id.to_string())
+      .await?
+      .ok_or(Error::RestaurantNotFound)?;
+
+    let plan = self.plan_repository
+      .find_by_code(request.plan.trim())
+      .await?
+      .ok_or(Error::PlanNotFound)?;
+
+    let previous = self.subscription_repository.find_by_restaurant(restaurant_id.to_string()).await?;
+
+    let mut subscription = match previous.clone() {
+      Some(subscription) => subscription,
+      None => Subscription::start(restaurant.id.clone().ok_or(Error::RestaurantNotFound)?, &plan),
+    };
+
+    subscription.change(&plan, request.status);
+
+    let subscription = self.subscription_repository.save(&subscription).await?;
+
+    event.record_changes(
+      previous.as_ref().map(|previous| serde_json::json!({ "plan": previous.plan.id.to_string(), "status": previous.status })).as_ref(),
+      Some(&serde_json::json!({ "plan": plan.code, "status": subscription.status })),
+    );
+
+    Ok(SubscriptionResponse::new(subscription, plan))
+  }
+}
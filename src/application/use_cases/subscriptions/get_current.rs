@@ -0,0 +1,39 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  subscription_repository::SubscriptionRepository,
+  plan_repository::PlanRepository,
+};
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::error::Error;
+use crate::application::dtos::subscriptions::subscription_response::SubscriptionResponse;
+
+// Plan, estado y uso del restaurante del usuario
+pub struct GetSubscriptionUseCase<S, P> where S: SubscriptionRepository, P: PlanRepository {
+  subscription_repository: S,
+  plan_repository: P,
+}
+
+impl<S, P> GetSubscriptionUseCase<S, P> where S: SubscriptionRepository, P: PlanRepository {
+  pub fn new(subscription_repository: S, plan_repository: P) -> Self {
+    Self { subscription_repository, plan_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser) -> Result<SubscriptionResponse, Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Settings, Action::Read))?;
+
+    let restaurant = auth_user.tenant().record().ok_or(Error::SubscriptionRequired)?;
+
+    let subscription = self.subscription_repository
+      .find_by_restaurant(restaurant.id.to_string())
+      .await?
+      .ok_or(Error::SubscriptionRequired)?;
+
+    let plan = self.plan_repository
+      .find_by_id(subscription.plan.id.to_string())
+      .await?
+      .ok_or(Error::PlanNotFound)?;
+
+    Ok(SubscriptionResponse::new(subscription, plan))
+  }
+}
@@ -0,0 +1,20 @@
+use crate::domain::repositories::plan_repository::PlanRepository;
+use crate::domain::error::Error;
+use crate::application::dtos::subscriptions::plan_response::PlanResponse;
+
+// Catálogo público, para la página de precios y el alta
+pub struct GetPlansUseCase<P> where P: PlanRepository {
+  plan_repository: P,
+}
+
+impl<P> GetPlansUseCase<P> where P: PlanRepository {
+  pub fn new(plan_repository: P) -> Self {
+    Self { plan_repository }
+  }
+
+  pub async fn execute(&self) -> Result<Vec<PlanResponse>, Error> {
+    let plans = self.plan_repository.find_all().await?;
+
+    Ok(plans.into_iter().map(PlanResponse::from).collect())
+  }
+}
@@ -0,0 +1,6 @@
+pub mod resolve_entitlements;
+pub mod start;
+pub mod get_plans;
+pub mod get_current;
+pub mod change_plan;
+pub mod refresh_usage;
\ No newline at end of file
@@ -0,0 +1,36 @@
+use chrono::Utc;
+
+use crate::domain::repositories::{
+  subscription_repository::SubscriptionRepository,
+  usage_repository::UsageRepository,
+};
+use crate::domain::error::Error;
+
+// Recuenta el uso de un restaurante y, si venció el período, empieza el siguiente. Devuelve
+// false si el restaurante no tiene suscripción.
+pub struct RefreshUsageUseCase<S, U> where S: SubscriptionRepository, U: UsageRepository {
+  subscription_repository: S,
+  usage_repository: U,
+}
+
+impl<S, U> RefreshUsageUseCase<S, U> where S: SubscriptionRepository, U: UsageRepository {
+  pub fn new(subscription_repository: S, usage_repository: U) -> Self {
+    Self { subscription_repository, usage_repository }
+  }
+
+  pub async fn execute(&self, restaurant_id: String) -> Result<bool, Error> {
+    let Some(mut subscription) = self.subscription_repository.find_by_restaurant(restaurant_id).await? else {
+      return Ok(false);
+    };
+
+    let now = Utc::now();
+
+    subscription.roll_period(now);
+    subscription.usage = self.usage_repository.count(subscription.current_period_start).await?;
+    subscription.usage_refreshed_at = Some(now);
+
+    self.subscription_repository.save(&subscription).await?;
+
+    Ok(true)
+  }
+}
@@ -0,0 +1,36 @@
+use crate::domain::entities::entitlements::Entitlements;
+use crate::domain::repositories::{
+  subscription_repository::SubscriptionRepository,
+  plan_repository::PlanRepository,
+};
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::domain::error::Error;
+
+// Plan y suscripción del tenant de la petición, para las comprobaciones de las rutas y los casos de uso
+pub struct ResolveEntitlementsUseCase<S, P> where S: SubscriptionRepository, P: PlanRepository {
+  subscription_repository: S,
+  plan_repository: P,
+}
+
+impl<S, P> ResolveEntitlementsUseCase<S, P> where S: SubscriptionRepository, P: PlanRepository {
+  pub fn new(subscription_repository: S, plan_repository: P) -> Self {
+    Self { subscription_repository, plan_repository }
+  }
+
+  pub async fn execute(&self, tenant: &TenantContext) -> Result<Entitlements, Error> {
+    let Some(restaurant) = tenant.record() else {
+      return Ok(Entitlements::Platform);
+    };
+
+    let Some(subscription) = self.subscription_repository.find_by_restaurant(restaurant.id.to_string()).await? else {
+      return Ok(Entitlements::Unsubscribed);
+    };
+
+    let plan = self.plan_repository
+      .find_by_id(subscription.plan.id.to_string())
+      .await?
+      .ok_or(Error::PlanNotFound)?;
+
+    Ok(Entitlements::Subscribed { subscription, plan })
+  }
+}
@@ -0,0 +1,34 @@
+use crate::domain::entities::plan::Plan;
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::entities::subscription::Subscription;
+use crate::domain::repositories::{
+  subscription_repository::SubscriptionRepository,
+  plan_repository::PlanRepository,
+};
+use crate::domain::error::Error;
+
+// Suscripción inicial de un restaurante recién creado
+pub struct StartSubscriptionUseCase<S, P> where S: SubscriptionRepository, P: PlanRepository {
+  subscription_repository: S,
+  plan_repository: P,
+}
+
+impl<S, P> StartSubscriptionUseCase<S, P> where S: SubscriptionRepository, P: PlanRepository {
+  pub fn new(subscription_repository: S, plan_repository: P) -> Self {
+    Self { subscription_repository, plan_repository }
+  }
+
+  // Se busca antes de crear el restaurante para no tener que deshacer el alta por un plan inválido
+  pub async fn find_plan(&self, code: &str) -> Result<Plan, Error> {
+    self.plan_repository
+      .find_by_code(code.trim())
+      .await?
+      .ok_or(Error::PlanNotFound)
+  }
+
+  pub async fn execute(&self, restaurant: &Restaurant, plan: &Plan) -> Result<Subscription, Error> {
+    let restaurant_id = restaurant.id.clone().ok_or(Error::RestaurantNotFound)?;
+
+    self.subscription_repository.save(&Subscription::start(restaurant_id, plan)).await
+  }
+}
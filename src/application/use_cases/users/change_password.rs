@@ -1,36 +1,68 @@
-use std::str::FromStr;
-use surrealdb::sql::Thing;
-
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
 use crate::domain::error::Error;
-use crate::domain::services::token::TokenService;
 
 use crate::application::dtos::users::change_password_request::ChangePasswordRequest;
-use crate::domain::repositories::user_repository::UserRepository;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  token_repository::TokenRepository,
+  revoked_token_repository::RevokedTokenRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::services::audit;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::services::token::TokenService;
+use crate::domain::services::password_policy::PasswordPolicy;
 
-pub struct ChangePasswordUseCase<U> where U: UserRepository, 
+pub struct ChangePasswordUseCase<U, T, K, A> where
+  U: UserRepository,
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+  A: AuditRepository,
 {
   user_repository: U,
+  token_repository: T,
+  revoked_token_repository: K,
+  audit_repository: A,
   token_service: TokenService,
+  password_policy: PasswordPolicy,
 }
 
-impl<U> ChangePasswordUseCase<U> where 
+impl<U, T, K, A> ChangePasswordUseCase<U, T, K, A> where 
   U: UserRepository,
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+  A: AuditRepository,
 {
-  pub fn new(user_repository: U, token_service: TokenService) -> Self {
-    Self { user_repository, token_service }
+  pub fn new(user_repository: U, token_repository: T, revoked_token_repository: K, audit_repository: A, token_service: TokenService, password_policy: PasswordPolicy) -> Self {
+    Self { user_repository, token_repository, revoked_token_repository, audit_repository, token_service, password_policy }
   }
 
-  pub async fn execute(&self, token: &str, request: &ChangePasswordRequest) -> Result<(), Error> {
-    let claims = self.token_service.verify_access_token(token)?;
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, request: &ChangePasswordRequest) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::ChangePassword).by(auth_user).client(client);
+    event.set_target("users", &auth_user.user_id());
 
-    if self.token_service.is_token_expired(&claims) {
-      return Err(Error::TokenExpired);
-    }
+    let result = self.change_password(auth_user, request).await;
 
-    let user_id = Thing::from_str(claims.sub.as_str()).unwrap();
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
 
-    self.user_repository.change_password(user_id.to_string(), &request.password).await?;
+  async fn change_password(&self, auth_user: &AuthenticatedUser, request: &ChangePasswordRequest) -> Result<(), Error> {
+    auth_user.require_full_session()?;
+
+    self.password_policy
+      .validate_change(&self.user_repository, &auth_user.user_id(), &request.password)
+      .await?;
+
+    self.user_repository.change_password(auth_user.user_id(), &request.password, self.password_policy.history_size()).await?;
+
+    // Todas las sesiones, incluida la actual, tienen que volver a iniciar sesión con la contraseña nueva
+    self.token_repository.invalidate_all_user_tokens(auth_user.user_id()).await?;
+    self.revoked_token_repository
+      .revoke(&self.token_service.revocation_for_user(&auth_user.user_id()))
+      .await?;
     
     Ok(())
   }
-}
\ No newline at end of file
+}
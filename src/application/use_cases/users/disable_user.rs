@@ -1,37 +1,68 @@
-use std::str::FromStr;
-use surrealdb::sql::Thing;
-
-use crate::domain::repositories::user_repository::UserRepository;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  token_repository::TokenRepository,
+  revoked_token_repository::RevokedTokenRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::services::audit;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::domain::error::Error;
 use crate::domain::services::token::TokenService;
 
-pub struct DisableUserUseCase<U> where U: UserRepository, 
+pub struct DisableUserUseCase<U, T, K, A> where
+  U: UserRepository,
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+  A: AuditRepository,
 {
   user_repository: U,
+  token_repository: T,
+  revoked_token_repository: K,
+  audit_repository: A,
   token_service: TokenService,
 }
 
-impl<U> DisableUserUseCase<U> where 
+impl<U, T, K, A> DisableUserUseCase<U, T, K, A> where 
   U: UserRepository,
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+  A: AuditRepository,
 {
-  pub fn new(user_repository: U, token_service: TokenService) -> Self {
-    Self { user_repository, token_service }
+  pub fn new(user_repository: U, token_repository: T, revoked_token_repository: K, audit_repository: A, token_service: TokenService) -> Self {
+    Self { user_repository, token_repository, revoked_token_repository, audit_repository, token_service }
   }
 
-  pub async fn execute(&self, token: &str) -> Result<(), Error> {
-    let claims = self.token_service.verify_access_token(token)?;
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::DisableUser).by(auth_user).client(client);
+    event.set_target("users", &auth_user.user_id());
 
-    if self.token_service.is_token_expired(&claims) {
-      return Err(Error::TokenExpired);
+    let result = self.disable(auth_user).await;
+
+    if result.is_ok() {
+      event.record_changes(Some(&serde_json::json!({ "is_active": true })), Some(&serde_json::json!({ "is_active": false })));
     }
 
-    let user_id = Thing::from_str(claims.sub.as_str()).unwrap();
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn disable(&self, auth_user: &AuthenticatedUser) -> Result<(), Error> {
+    auth_user.require_full_session()?;
+
+    let user_id = auth_user.user_id();
 
     //TODO: Solo los super admins pueden deshabilitar a los usuarios
 
-    self.user_repository.update_failed_login_attempts(user_id.to_string(), 0).await?;
-    self.user_repository.delete(user_id.to_string()).await?;
+    self.user_repository.update_failed_login_attempts(user_id.clone(), 0).await?;
+    self.user_repository.delete(user_id.clone()).await?;
+
+    self.token_repository.invalidate_all_user_tokens(user_id.clone()).await?;
+    self.revoked_token_repository
+      .revoke(&self.token_service.revocation_for_user(&user_id))
+      .await?;
     
     Ok(())
   }
-}
\ No newline at end of file
+}
@@ -0,0 +1,78 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+
+use crate::application::dtos::users::impersonation_response::ImpersonationResponse;
+
+pub struct ImpersonateUserUseCase<U, A> where
+  U: UserRepository,
+  A: AuditRepository,
+{
+  user_repository: U,
+  audit_repository: A,
+  token_service: TokenService,
+}
+
+impl<U, A> ImpersonateUserUseCase<U, A> where
+  U: UserRepository,
+  A: AuditRepository,
+{
+  pub fn new(user_repository: U, audit_repository: A, token_service: TokenService) -> Self {
+    Self { user_repository, audit_repository, token_service }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo, user_id: &str) -> Result<ImpersonationResponse, Error> {
+    let mut event = AuditEvent::new(AuditAction::StartImpersonation).by(auth_user).client(client);
+    event.set_target("users", user_id);
+
+    let result = self.impersonate(auth_user, user_id).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn impersonate(&self, auth_user: &AuthenticatedUser, user_id: &str) -> Result<ImpersonationResponse, Error> {
+    if auth_user.user.user_type != UserType::SuperAdmin {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    // Desde una suplantación o un terminal POS no se encadena otra
+    auth_user.require_full_session()?;
+
+    if auth_user.user_id() == user_id {
+      return Err(Error::InvalidInput("cannot impersonate yourself".to_string()));
+    }
+
+    let subject = self.user_repository
+      .find_by_id(user_id.to_string())
+      .await?
+      .ok_or(Error::UserNotFound)?;
+
+    // Un super admin tiene los mismos permisos: suplantarlo solo serviría para ocultar quién actúa
+    if subject.user_type == UserType::SuperAdmin {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    if !subject.is_active {
+      return Err(Error::UserNotFound);
+    }
+
+    let access_token = self.token_service.generate_impersonation_token(&subject, &auth_user.user)?;
+
+    Ok(ImpersonationResponse {
+      user_id: user_id.to_string(),
+      email: subject.email,
+      impersonator_id: auth_user.user_id(),
+      access_token: access_token.token,
+      access_token_expires_at: access_token.expires_at,
+    })
+  }
+}
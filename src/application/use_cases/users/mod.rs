@@ -1,4 +1,8 @@
 pub mod change_password;
 pub mod verification_user;
 pub mod disable_user;
-pub mod get_all;
\ No newline at end of file
+pub mod get_all;
+pub mod unlock_user;
+pub mod impersonate;
+pub mod stop_impersonation;
+pub mod switch_location;
\ No newline at end of file
@@ -0,0 +1,51 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::audit_event::{AuditAction, AuditEvent};
+use crate::domain::repositories::{
+  revoked_token_repository::RevokedTokenRepository,
+  audit_repository::AuditRepository,
+};
+use crate::domain::services::audit;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::error::Error;
+
+pub struct StopImpersonationUseCase<K, A> where
+  K: RevokedTokenRepository,
+  A: AuditRepository,
+{
+  revoked_token_repository: K,
+  audit_repository: A,
+  token_service: TokenService,
+}
+
+impl<K, A> StopImpersonationUseCase<K, A> where
+  K: RevokedTokenRepository,
+  A: AuditRepository,
+{
+  pub fn new(revoked_token_repository: K, audit_repository: A, token_service: TokenService) -> Self {
+    Self { revoked_token_repository, audit_repository, token_service }
+  }
+
+  // Se llama con el token de suplantación, que deja de valer en el acto
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, client: &ClientInfo) -> Result<(), Error> {
+    let mut event = AuditEvent::new(AuditAction::StopImpersonation).by(auth_user).client(client);
+    event.set_target("users", &auth_user.user_id());
+
+    let result = self.stop(auth_user).await;
+
+    audit::record(&self.audit_repository, event.outcome_of(&result)).await;
+    result
+  }
+
+  async fn stop(&self, auth_user: &AuthenticatedUser) -> Result<(), Error> {
+    if !auth_user.is_impersonation() {
+      return Err(Error::InvalidInput("token is not an impersonation token".to_string()));
+    }
+
+    let entry = self.token_service
+      .revocation_for_token(&auth_user.claims)
+      .ok_or(Error::InvalidToken)?;
+
+    self.revoked_token_repository.revoke(&entry).await
+  }
+}
@@ -0,0 +1,76 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  location_repository::LocationRepository,
+  token_repository::TokenRepository,
+  revoked_token_repository::RevokedTokenRepository,
+};
+use crate::domain::services::token::TokenService;
+use crate::domain::error::Error;
+
+use crate::application::dtos::users::{
+  switch_location_request::SwitchLocationRequest,
+  switch_location_response::SwitchLocationResponse,
+};
+
+// Cambia la sucursal en la que trabaja la sesión: nuevo access token con el claim `lid` y
+// el anterior revocado, para que no siga valiendo en la sucursal de antes
+pub struct SwitchLocationUseCase<U, L, T, K> where
+  U: UserRepository,
+  L: LocationRepository,
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+{
+  user_repository: U,
+  location_repository: L,
+  token_repository: T,
+  revoked_token_repository: K,
+  token_service: TokenService,
+}
+
+impl<U, L, T, K> SwitchLocationUseCase<U, L, T, K> where
+  U: UserRepository,
+  L: LocationRepository,
+  T: TokenRepository,
+  K: RevokedTokenRepository,
+{
+  pub fn new(user_repository: U, location_repository: L, token_repository: T, revoked_token_repository: K, token_service: TokenService) -> Self {
+    Self { user_repository, location_repository, token_repository, revoked_token_repository, token_service }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, request: &SwitchLocationRequest) -> Result<SwitchLocationResponse, Error> {
+    // Los tokens POS y de suplantación no tienen sesión que cambiar
+    let session_id = auth_user.session_id().ok_or(Error::UnauthorizedAccess)?;
+
+    if let Some(location_id) = &request.location_id {
+      if !self.location_repository.is_user_assigned(auth_user.user_id(), location_id.clone()).await? {
+        return Err(Error::LocationAccessDenied);
+      }
+    }
+
+    let updated = self.token_repository
+      .set_session_location(auth_user.user_id(), session_id.clone(), request.location_id.clone())
+      .await?;
+
+    if !updated {
+      return Err(Error::InvalidToken);
+    }
+
+    let user = self.user_repository
+      .find_by_id(auth_user.user_id())
+      .await?
+      .ok_or(Error::UserNotFound)?;
+
+    let access_token = self.token_service.generate_access_token(&user, Some(session_id), request.location_id.clone())?;
+
+    if let Some(entry) = self.token_service.revocation_for_token(&auth_user.claims) {
+      self.revoked_token_repository.revoke(&entry).await?;
+    }
+
+    Ok(SwitchLocationResponse {
+      location_id: request.location_id.clone(),
+      access_token: access_token.token,
+      access_token_expires_at: access_token.expires_at,
+    })
+  }
+}
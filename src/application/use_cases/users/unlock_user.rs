@@ -0,0 +1,36 @@
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  role_repository::RoleRepository,
+};
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+
+use crate::application::use_cases::roles::assign::user_roles;
+
+// Solo se desbloquea a quien queda por debajo en la jerarquía
+pub struct UnlockUserUseCase<U, R> where U: UserRepository, R: RoleRepository,
+{
+  user_repository: U,
+  role_repository: R,
+}
+
+impl<U, R> UnlockUserUseCase<U, R> where 
+  U: UserRepository,
+  R: RoleRepository,
+{
+  pub fn new(user_repository: U, role_repository: R) -> Self {
+    Self { user_repository, role_repository }
+  }
+
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, user_id: &str) -> Result<(), Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Users, Action::Manage))?;
+
+    auth_user.require_outranks_user(&user_roles(&self.user_repository, &self.role_repository, user_id, None).await?, None)?;
+
+    self.user_repository.unlock_account(user_id.to_string()).await?;
+    
+    Ok(())
+  }
+}
\ No newline at end of file
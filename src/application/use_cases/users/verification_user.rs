@@ -1,33 +1,33 @@
-use std::str::FromStr;
-use surrealdb::sql::Thing;
-
-use crate::domain::repositories::user_repository::UserRepository;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::repositories::{
+  user_repository::UserRepository,
+  role_repository::RoleRepository,
+};
 use crate::domain::error::Error;
-use crate::domain::services::token::TokenService;
+use crate::domain::services::permission_guard::RequiredPermission;
 
 use crate::application::dtos::users::verification_status_user_request::VerificationStatusUserRequest;
+use crate::application::use_cases::roles::assign::user_roles;
 
-pub struct VerificationUserUseCase<U> where U: UserRepository, 
+// Operación de administración: cada usuario se verifica a sí mismo con el enlace del correo
+pub struct VerificationUserUseCase<U, R> where U: UserRepository, R: RoleRepository,
 {
   user_repository: U,
-  token_service: TokenService,
+  role_repository: R,
 }
 
-impl<U> VerificationUserUseCase<U> where 
+impl<U, R> VerificationUserUseCase<U, R> where 
   U: UserRepository,
+  R: RoleRepository,
 {
-  pub fn new(user_repository: U, token_service: TokenService) -> Self {
-    Self { user_repository, token_service }
+  pub fn new(user_repository: U, role_repository: R) -> Self {
+    Self { user_repository, role_repository }
   }
 
-  pub async fn execute(&self, token: &str, request: &VerificationStatusUserRequest) -> Result<(), Error> {
-    let claims = self.token_service.verify_access_token(token)?;
-
-    if self.token_service.is_token_expired(&claims) {
-      return Err(Error::TokenExpired);
-    }
-
-    let user_id = Thing::from_str(claims.sub.as_str()).unwrap();
+  pub async fn execute(&self, auth_user: &AuthenticatedUser, user_id: &str, request: &VerificationStatusUserRequest) -> Result<(), Error> {
+    auth_user.require(&RequiredPermission::new(Resource::Users, Action::Manage))?;
+    auth_user.require_outranks_user(&user_roles(&self.user_repository, &self.role_repository, user_id, None).await?, None)?;
 
     self.user_repository.set_verification_status(user_id.to_string(), request.is_verified).await?;
     
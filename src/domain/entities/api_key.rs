@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+use crate::domain::entities::permission::{Action, Resource};
+
+// Alcance concedido a una clave, mismo formato que un permiso ("orders:read")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiKeyScope {
+  pub resource: Resource,
+  pub action: Action,
+}
+
+impl std::fmt::Display for ApiKeyScope {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}:{}", self.resource, self.action)
+  }
+}
+
+// Credencial de máquina (pantallas de cocina, impresoras, kioscos); no pertenece a un usuario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+  pub id: Option<Thing>,
+  pub name: String,
+  // Primeros caracteres de la clave, para reconocerla en los listados
+  pub prefix: String,
+  // Restaurante en el que se creó; solo autentica peticiones de ese tenant
+  #[serde(default)]
+  pub restaurant_id: Option<Thing>,
+  pub scopes: Vec<ApiKeyScope>,
+  pub created_by: Thing,
+  pub is_active: bool,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub last_used_at: Option<DateTime<Utc>>,
+  pub revoked_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+  pub fn new(
+    name: String,
+    prefix: String,
+    scopes: Vec<ApiKeyScope>,
+    created_by: Thing,
+    expires_at: Option<DateTime<Utc>>,
+  ) -> Self {
+    Self {
+      id: None,
+      name,
+      prefix,
+      restaurant_id: None,
+      scopes,
+      created_by,
+      is_active: true,
+      expires_at,
+      last_used_at: None,
+      revoked_at: None,
+      created_at: Utc::now(),
+      updated_at: Utc::now(),
+    }
+  }
+
+  pub fn is_expired(&self) -> bool {
+    matches!(self.expires_at, Some(expires_at) if expires_at <= Utc::now())
+  }
+}
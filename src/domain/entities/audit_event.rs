@@ -0,0 +1,290 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::client_info::ClientInfo;
+
+// Registro inmutable de un evento de seguridad: quién (actor) hizo qué (action) sobre qué (target),
+// desde dónde y con qué resultado. Si actuaba suplantando a otro usuario, `impersonator_id` es el super admin. `changes` guarda solo los campos modificados como { campo: { from, to } }.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+  pub id: Option<Thing>,
+  pub action: AuditAction,
+  pub outcome: AuditOutcome,
+  pub actor_id: Option<Thing>,
+  pub impersonator_id: Option<Thing>,
+  pub target_type: Option<String>,
+  pub target_id: Option<String>,
+  pub ip_address: Option<String>,
+  pub user_agent: Option<String>,
+  pub reason: Option<String>,
+  pub changes: Option<Value>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+  // El resultado se fija al final con `outcome_of`; hasta entonces el evento se da por exitoso
+  pub fn new(action: AuditAction) -> Self {
+    Self {
+      id: None,
+      action,
+      outcome: AuditOutcome::Success,
+      actor_id: None,
+      impersonator_id: None,
+      target_type: None,
+      target_id: None,
+      ip_address: None,
+      user_agent: None,
+      reason: None,
+      changes: None,
+      created_at: Utc::now(),
+    }
+  }
+
+  // Actor de una petición autenticada; durante una suplantación también anota al super admin
+  pub fn by(mut self, auth_user: &AuthenticatedUser) -> Self {
+    self.actor_id = Some(Thing::from(("users", auth_user.user_id().as_str())));
+    self.impersonator_id = auth_user.impersonator_id().map(|id| Thing::from(("users", id.as_str())));
+    self
+  }
+
+  pub fn client(mut self, client: &ClientInfo) -> Self {
+    self.ip_address = client.ip_address.clone();
+    self.user_agent = client.user_agent.clone();
+    self
+  }
+
+  // El objetivo puede no conocerse hasta que la operación avanza (p. ej. el id de un alta)
+  pub fn set_target(&mut self, target_type: &str, target_id: &str) {
+    self.target_type = Some(target_type.to_string());
+    self.target_id = Some(target_id.to_string());
+  }
+
+  // Compara las dos versiones serializadas; en altas `before` es None y en bajas lo es `after`
+  pub fn record_changes<T: Serialize>(&mut self, before: Option<&T>, after: Option<&T>) {
+    let before = before.and_then(|value| serde_json::to_value(value).ok());
+    let after = after.and_then(|value| serde_json::to_value(value).ok());
+
+    self.changes = diff(before, after);
+  }
+
+  pub fn outcome_of<T>(mut self, result: &Result<T, Error>) -> Self {
+    if let Err(err) = result {
+      self.outcome = AuditOutcome::Failure;
+      self.reason = Some(err.to_string());
+    }
+
+    self
+  }
+}
+
+// Campos que identifican el registro y no aportan nada al diff
+const IGNORED_FIELDS: [&str; 2] = ["id", "created_at"];
+
+fn diff(before: Option<Value>, after: Option<Value>) -> Option<Value> {
+  let as_object = |value: Option<Value>| match value {
+    Some(Value::Object(map)) => map,
+    _ => Map::new(),
+  };
+
+  let before = as_object(before);
+  let after = as_object(after);
+  let mut changes = Map::new();
+
+  for key in before.keys().chain(after.keys()) {
+    if IGNORED_FIELDS.contains(&key.as_str()) || changes.contains_key(key) {
+      continue;
+    }
+
+    let from = before.get(key).cloned().unwrap_or(Value::Null);
+    let to = after.get(key).cloned().unwrap_or(Value::Null);
+
+    if from != to {
+      changes.insert(key.clone(), serde_json::json!({ "from": from, "to": to }));
+    }
+  }
+
+  (!changes.is_empty()).then_some(Value::Object(changes))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditAction {
+  #[serde(rename = "auth.login")]
+  Login,
+  #[serde(rename = "auth.register")]
+  Register,
+  #[serde(rename = "user.change_password")]
+  ChangePassword,
+  #[serde(rename = "user.disable")]
+  DisableUser,
+  #[serde(rename = "user.impersonate.start")]
+  StartImpersonation,
+  #[serde(rename = "user.impersonate.stop")]
+  StopImpersonation,
+  #[serde(rename = "restaurant.create")]
+  CreateRestaurant,
+  #[serde(rename = "restaurant.signup")]
+  SignupRestaurant,
+  #[serde(rename = "subscription.change")]
+  ChangeSubscription,
+  #[serde(rename = "restaurant.deprovision")]
+  DeprovisionRestaurant,
+  #[serde(rename = "restaurant.export")]
+  ExportRestaurant,
+  #[serde(rename = "location.create")]
+  CreateLocation,
+  #[serde(rename = "location.update")]
+  UpdateLocation,
+  #[serde(rename = "location.delete")]
+  DeleteLocation,
+  #[serde(rename = "location.assign_user")]
+  AssignLocation,
+  #[serde(rename = "location.unassign_user")]
+  UnassignLocation,
+  #[serde(rename = "role.create")]
+  CreateRole,
+  #[serde(rename = "role.update")]
+  UpdateRole,
+  #[serde(rename = "role.delete")]
+  DeleteRole,
+  #[serde(rename = "role.assign")]
+  AssignRole,
+  #[serde(rename = "role.unassign")]
+  UnassignRole,
+  #[serde(rename = "role.inheritance")]
+  SetRoleInheritance,
+  #[serde(rename = "mfa.policy")]
+  SetMfaPolicy,
+  #[serde(rename = "permission.create")]
+  CreatePermission,
+  #[serde(rename = "permission.update")]
+  UpdatePermission,
+  #[serde(rename = "permission.delete")]
+  DeletePermission,
+}
+
+impl AuditAction {
+  const ALL: [AuditAction; 26] = [
+    AuditAction::Login,
+    AuditAction::Register,
+    AuditAction::ChangePassword,
+    AuditAction::DisableUser,
+    AuditAction::StartImpersonation,
+    AuditAction::StopImpersonation,
+    AuditAction::CreateRestaurant,
+    AuditAction::SignupRestaurant,
+    AuditAction::ChangeSubscription,
+    AuditAction::DeprovisionRestaurant,
+    AuditAction::ExportRestaurant,
+    AuditAction::CreateLocation,
+    AuditAction::UpdateLocation,
+    AuditAction::DeleteLocation,
+    AuditAction::AssignLocation,
+    AuditAction::UnassignLocation,
+    AuditAction::CreateRole,
+    AuditAction::UpdateRole,
+    AuditAction::DeleteRole,
+    AuditAction::AssignRole,
+    AuditAction::UnassignRole,
+    AuditAction::SetRoleInheritance,
+    AuditAction::SetMfaPolicy,
+    AuditAction::CreatePermission,
+    AuditAction::UpdatePermission,
+    AuditAction::DeletePermission,
+  ];
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      AuditAction::Login => "auth.login",
+      AuditAction::Register => "auth.register",
+      AuditAction::ChangePassword => "user.change_password",
+      AuditAction::DisableUser => "user.disable",
+      AuditAction::StartImpersonation => "user.impersonate.start",
+      AuditAction::StopImpersonation => "user.impersonate.stop",
+      AuditAction::CreateRestaurant => "restaurant.create",
+      AuditAction::SignupRestaurant => "restaurant.signup",
+      AuditAction::ChangeSubscription => "subscription.change",
+      AuditAction::DeprovisionRestaurant => "restaurant.deprovision",
+      AuditAction::ExportRestaurant => "restaurant.export",
+      AuditAction::CreateLocation => "location.create",
+      AuditAction::UpdateLocation => "location.update",
+      AuditAction::DeleteLocation => "location.delete",
+      AuditAction::AssignLocation => "location.assign_user",
+      AuditAction::UnassignLocation => "location.unassign_user",
+      AuditAction::CreateRole => "role.create",
+      AuditAction::UpdateRole => "role.update",
+      AuditAction::DeleteRole => "role.delete",
+      AuditAction::AssignRole => "role.assign",
+      AuditAction::UnassignRole => "role.unassign",
+      AuditAction::SetRoleInheritance => "role.inheritance",
+      AuditAction::SetMfaPolicy => "mfa.policy",
+      AuditAction::CreatePermission => "permission.create",
+      AuditAction::UpdatePermission => "permission.update",
+      AuditAction::DeletePermission => "permission.delete",
+    }
+  }
+}
+
+impl std::str::FromStr for AuditAction {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::ALL
+      .into_iter()
+      .find(|action| action.as_str() == s)
+      .ok_or_else(|| format!("Invalid audit action: {}", s))
+  }
+}
+
+impl std::fmt::Display for AuditAction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+  Success,
+  Failure,
+}
+
+impl std::str::FromStr for AuditOutcome {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(match s {
+      "success" => AuditOutcome::Success,
+      "failure" => AuditOutcome::Failure,
+      _ => return Err(format!("Invalid audit outcome: {}", s)),
+    })
+  }
+}
+
+impl std::fmt::Display for AuditOutcome {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let value = match self {
+      AuditOutcome::Success => "success",
+      AuditOutcome::Failure => "failure",
+    };
+
+    write!(f, "{}", value)
+  }
+}
+
+// Criterios de búsqueda del registro; los campos vacíos no filtran
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+  pub actor_id: Option<String>,
+  pub impersonator_id: Option<String>,
+  pub target_type: Option<String>,
+  pub target_id: Option<String>,
+  pub action: Option<AuditAction>,
+  pub outcome: Option<AuditOutcome>,
+  pub ip_address: Option<String>,
+  pub from: Option<DateTime<Utc>>,
+  pub to: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,153 @@
+use crate::domain::entities::user::User;
+use crate::domain::entities::role::Role;
+use crate::domain::entities::permission::Permission;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::{PermissionGuard, RequiredPermission};
+use crate::domain::services::role_hierarchy::RoleHierarchy;
+use crate::domain::services::token::{TokenClaims, TokenScope};
+use crate::domain::value_objects::{tenant::TenantContext, user_types::UserType};
+
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+  pub claims: TokenClaims,
+  pub user: User,
+  // Roles sin sucursal; con ellos se calcula su nivel en la jerarquía
+  pub roles: Vec<Role>,
+  // Permisos de los roles sin sucursal: valen en todo el restaurante
+  pub permissions: Vec<Permission>,
+  // Roles y permisos asignados en la sucursal del claim `lid`; solo valen sobre esa sucursal
+  pub location_roles: Vec<Role>,
+  pub location_permissions: Vec<Permission>,
+}
+
+impl AuthenticatedUser {
+  pub fn new(claims: TokenClaims, user: User, roles: Vec<Role>, permissions: Vec<Permission>) -> Self {
+    Self { claims, user, roles, permissions, location_roles: Vec::new(), location_permissions: Vec::new() }
+  }
+
+  pub fn with_location_access(mut self, location_roles: Vec<Role>, location_permissions: Vec<Permission>) -> Self {
+    self.location_roles = location_roles;
+    self.location_permissions = location_permissions;
+    self
+  }
+
+  // Id del usuario sin el nombre de la tabla, tal como viaja en el claim `sub`
+  pub fn user_id(&self) -> String {
+    self.claims.sub.clone()
+  }
+
+  // Sesión del access token; los tokens POS y los emitidos antes de existir las sesiones no la tienen
+  pub fn session_id(&self) -> Option<String> {
+    self.claims.sid.clone()
+  }
+
+  // Restaurante del token; con él se leen los datos propios del usuario (contraseña, sesiones, perfil)
+  pub fn tenant(&self) -> TenantContext {
+    TenantContext::from_restaurant_id(self.claims.tid.as_deref())
+  }
+
+  // Sucursal elegida en la sesión
+  pub fn location_id(&self) -> Option<String> {
+    self.claims.lid.clone()
+  }
+
+  // Cada usuario trabaja solo en su restaurante; los super admins de la plataforma, en cualquiera
+  pub fn require_tenant(&self, tenant: &TenantContext) -> Result<(), Error> {
+    let own = self.tenant();
+
+    if own == *tenant || (own.is_platform() && self.user.user_type == UserType::SuperAdmin) {
+      return Ok(());
+    }
+
+    Err(Error::TenantMismatch)
+  }
+
+  // Datos compartidos por todos los restaurantes (catálogo de permisos, alta de tenants)
+  pub fn require_platform(&self) -> Result<(), Error> {
+    if !self.tenant().is_platform() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    Ok(())
+  }
+
+  pub fn is_platform_admin(&self) -> bool {
+    self.user.user_type == UserType::SuperAdmin && self.tenant().is_platform()
+  }
+
+  // Nivel más alto entre sus roles de todo el restaurante (ver `RoleHierarchy`)
+  pub fn hierarchy_level(&self) -> Option<i32> {
+    RoleHierarchy::level(&self.roles)
+  }
+
+  // En la sucursal de la sesión también cuentan los roles asignados en ella
+  pub fn hierarchy_level_at(&self, location_id: Option<&str>) -> Option<i32> {
+    match (location_id, self.location_id()) {
+      (Some(location_id), Some(current)) if current == location_id => RoleHierarchy::level(self.roles.iter().chain(&self.location_roles)),
+      _ => self.hierarchy_level(),
+    }
+  }
+
+  // Solo se actúa sobre roles por debajo del propio nivel; sin roles, sobre ninguno
+  pub fn require_outranks(&self, hierarchy_level: i32) -> Result<(), Error> {
+    self.require_outranks_at(hierarchy_level, None)
+  }
+
+  pub fn require_outranks_at(&self, hierarchy_level: i32, location_id: Option<&str>) -> Result<(), Error> {
+    match self.hierarchy_level_at(location_id) {
+      Some(own) if own < hierarchy_level => Ok(()),
+      _ => Err(Error::RoleHierarchyViolation),
+    }
+  }
+
+  // Sobre otro usuario solo se actúa si todos sus roles, incluido el de su tipo de usuario, quedan
+  // por debajo del propio nivel. Sin ninguno solo queda el personal recién dado de alta, que aún no
+  // tiene permisos y espera su primer rol.
+  pub fn require_outranks_user(&self, user_roles: &[Role], location_id: Option<&str>) -> Result<(), Error> {
+    match RoleHierarchy::level(user_roles) {
+      Some(level) => self.require_outranks_at(level, location_id),
+      None => Ok(()),
+    }
+  }
+
+  pub fn require(&self, required: &RequiredPermission) -> Result<(), Error> {
+    PermissionGuard::check(&self.permissions, required)
+  }
+
+  // Operaciones sobre una sucursal: basta un permiso de todo el restaurante o uno de un rol
+  // asignado en esa sucursal, siempre que la sesión esté trabajando en ella
+  pub fn require_at_location(&self, required: &RequiredPermission, location_id: &str) -> Result<(), Error> {
+    if PermissionGuard::is_granted(&self.permissions, required) {
+      return Ok(());
+    }
+
+    match self.location_id().as_deref() {
+      Some(current) if current == location_id => PermissionGuard::check(&self.location_permissions, required),
+      Some(_) => Err(Error::LocationMismatch),
+      None => Err(Error::InvalidPermission(required.to_string())),
+    }
+  }
+
+  pub fn is_pos_session(&self) -> bool {
+    self.claims.scope == Some(TokenScope::Pos)
+  }
+
+  pub fn is_impersonation(&self) -> bool {
+    self.claims.scope == Some(TokenScope::Impersonation)
+  }
+
+  // Super admin que está actuando como este usuario
+  pub fn impersonator_id(&self) -> Option<String> {
+    self.claims.act.as_ref().map(|actor| actor.sub.clone())
+  }
+
+  // Operaciones sobre la cuenta (contraseña, 2FA, PIN) no se permiten desde un terminal compartido
+  // ni a quien está suplantando al usuario
+  pub fn require_full_session(&self) -> Result<(), Error> {
+    if self.is_pos_session() || self.is_impersonation() {
+      return Err(Error::UnauthorizedAccess);
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,42 @@
+use chrono::Utc;
+
+use crate::domain::entities::plan::{Feature, Plan, PlanLimit};
+use crate::domain::entities::subscription::Subscription;
+use crate::domain::error::Error;
+
+// Lo que puede usar el tenant de la petición según su plan
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum Entitlements {
+  // La plataforma no tiene plan ni límites
+  Platform,
+  Subscribed { subscription: Subscription, plan: Plan },
+  // Restaurante sin suscripción, ej. dado de alta antes de existir los planes
+  Unsubscribed,
+}
+
+impl Entitlements {
+  pub fn require_feature(&self, feature: Feature) -> Result<(), Error> {
+    match self.active_plan()? {
+      Some(plan) if !plan.includes(&feature) => Err(Error::FeatureNotInPlan(feature.to_string())),
+      _ => Ok(()),
+    }
+  }
+
+  // `current` es el uso antes de crear uno más: con un tope de 3, el cuarto ya no entra
+  pub fn require_capacity(&self, limit: PlanLimit, current: u64) -> Result<(), Error> {
+    match self.active_plan()?.and_then(|plan| plan.limit(limit)) {
+      Some(max) if current >= max => Err(Error::PlanLimitReached(limit.to_string())),
+      _ => Ok(()),
+    }
+  }
+
+  // None si no hay límites que comprobar (plataforma)
+  fn active_plan(&self) -> Result<Option<&Plan>, Error> {
+    match self {
+      Entitlements::Platform => Ok(None),
+      Entitlements::Subscribed { subscription, plan } if subscription.is_entitled_at(Utc::now()) => Ok(Some(plan)),
+      Entitlements::Subscribed { .. } | Entitlements::Unsubscribed => Err(Error::SubscriptionRequired),
+    }
+  }
+}
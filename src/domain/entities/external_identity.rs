@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+// Cuenta de un proveedor externo enlazada a un usuario; (issuer, subject) la identifica de forma estable
+// aunque el usuario cambie de email en el proveedor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalIdentity {
+  pub id: Option<Thing>,
+  pub user_id: Thing,
+  pub issuer: String,
+  pub subject: String,
+  pub email: Option<String>,
+  pub created_at: DateTime<Utc>,
+  pub last_login_at: Option<DateTime<Utc>>,
+}
+
+impl ExternalIdentity {
+  pub fn new(user_id: Thing, issuer: String, subject: String, email: Option<String>) -> Self {
+    Self {
+      id: None,
+      user_id,
+      issuer,
+      subject,
+      email,
+      created_at: Utc::now(),
+      last_login_at: None,
+    }
+  }
+}
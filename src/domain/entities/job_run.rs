@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobOutcome {
+  Succeeded,
+  Failed,
+}
+
+// Estado de una tarea programada; hay un registro por tarea (el id es su nombre)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRun {
+  pub id: Option<Thing>,
+  pub name: String,
+  pub schedule: String,
+  pub last_started_at: Option<DateTime<Utc>>,
+  pub last_finished_at: Option<DateTime<Utc>>,
+  pub last_outcome: Option<JobOutcome>,
+  // Resumen de lo que hizo la tarea o el error con el que falló
+  pub last_message: Option<String>,
+  pub next_run_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+use crate::domain::error::Error;
+use crate::domain::value_objects::address::Address;
+use crate::domain::value_objects::business_hours::BusinessHours;
+
+// Sucursal de un restaurante; el personal, los menús, el stock y los pedidos pertenecen a una
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Location {
+  pub id: Option<Thing>,
+  #[serde(default)]
+  pub restaurant_id: Option<Thing>,
+  pub name: String,
+  pub address: Address,
+  // Zona horaria IANA, ej. "America/Tegucigalpa"
+  pub timezone: String,
+  // Código ISO 4217, ej. "HNL"
+  pub currency: String,
+  #[serde(default)]
+  pub business_hours: Vec<BusinessHours>,
+  pub is_active: bool,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl Location {
+  pub fn new(name: String, address: Address, timezone: String, currency: String, business_hours: Vec<BusinessHours>) -> Self {
+    Self {
+      id: None,
+      restaurant_id: None,
+      name: name.trim().to_string(),
+      address,
+      timezone: timezone.trim().to_string(),
+      currency: currency.trim().to_uppercase(),
+      business_hours,
+      is_active: true,
+      created_at: Utc::now(),
+      updated_at: Utc::now(),
+    }
+  }
+
+  pub fn validate(&self) -> Result<(), Error> {
+    if self.name.is_empty() {
+      return Err(Error::ValidationError("Location name is required".to_string()));
+    }
+
+    // Sin base de zonas horarias solo se comprueba la forma "Area/Ciudad" (o "UTC")
+    let timezone_valid = (self.timezone == "UTC" || self.timezone.contains('/'))
+      && !self.timezone.starts_with('/')
+      && !self.timezone.ends_with('/')
+      && self.timezone.chars().all(|c| c.is_ascii_alphanumeric() || "/_+-".contains(c));
+
+    if !timezone_valid {
+      return Err(Error::ValidationError(format!("Invalid timezone: {}", self.timezone)));
+    }
+
+    if self.currency.len() != 3 || !self.currency.chars().all(|c| c.is_ascii_uppercase()) {
+      return Err(Error::ValidationError(format!("Invalid currency code: {}", self.currency)));
+    }
+
+    if self.business_hours.iter().any(|hours| hours.opens_at == hours.closes_at) {
+      return Err(Error::ValidationError("Business hours must open and close at different times".to_string()));
+    }
+
+    Ok(())
+  }
+}
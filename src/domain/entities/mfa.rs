@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+// Credencial TOTP de un usuario; queda pendiente (`enabled = false`) hasta que confirma un primer código
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotpCredential {
+  pub id: Option<Thing>,
+  pub user_id: Thing,
+  pub secret: String,
+  pub enabled: bool,
+  // Solo se guardan los hashes, los códigos en claro se muestran una única vez
+  pub recovery_codes: Vec<String>,
+  pub last_used_step: Option<i64>,
+  pub confirmed_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl TotpCredential {
+  pub fn new(user_id: Thing, secret: String) -> Self {
+    Self {
+      id: None,
+      user_id,
+      secret,
+      enabled: false,
+      recovery_codes: Vec::new(),
+      last_used_step: None,
+      confirmed_at: None,
+      created_at: Utc::now(),
+      updated_at: Utc::now(),
+    }
+  }
+}
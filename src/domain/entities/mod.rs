@@ -3,4 +3,21 @@ pub use user::*;
 pub mod role;
 pub mod permission;
 pub mod token;
-pub mod profile;
\ No newline at end of file
+pub mod profile;
+pub mod authenticated_user;
+pub mod one_time_token;
+pub mod mfa;
+pub mod pos_device;
+pub mod staff_pin;
+pub mod api_key;
+pub mod principal;
+pub mod revoked_token;
+pub mod job_run;
+pub mod audit_event;
+pub mod oidc_authorization;
+pub mod external_identity;
+pub mod restaurant;
+pub mod location;
+pub mod plan;
+pub mod subscription;
+pub mod entitlements;
\ No newline at end of file
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+// Autorización OIDC en curso: lo que hay que recordar entre la redirección al proveedor y el callback.
+// Se identifica por `state` y se borra al usarse.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcAuthorization {
+  pub id: Option<Thing>,
+  pub state: String,
+  pub nonce: String,
+  pub code_verifier: String,
+  pub expires_at: DateTime<Utc>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl OidcAuthorization {
+  pub fn new(state: String, nonce: String, code_verifier: String, expires_at: DateTime<Utc>) -> Self {
+    Self {
+      id: None,
+      state,
+      nonce,
+      code_verifier,
+      expires_at,
+      created_at: Utc::now(),
+    }
+  }
+}
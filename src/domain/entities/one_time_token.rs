@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenPurpose {
+  EmailVerification,
+  PasswordReset,
+  MfaPending,
+}
+
+impl std::fmt::Display for TokenPurpose {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let value = match self {
+      TokenPurpose::EmailVerification => "email_verification",
+      TokenPurpose::PasswordReset => "password_reset",
+      TokenPurpose::MfaPending => "mfa_pending",
+    };
+
+    write!(f, "{}", value)
+  }
+}
+
+// Registro de un token de un solo uso (el token firmado viaja al usuario, aquí solo se guarda su `jti`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OneTimeToken {
+  pub id: Option<Thing>,
+  pub user_id: Thing,
+  pub jti: String,
+  pub purpose: TokenPurpose,
+  pub expires_at: DateTime<Utc>,
+  pub used_at: Option<DateTime<Utc>>,
+  pub invalidated: bool,
+  pub created_at: DateTime<Utc>,
+}
+
+impl OneTimeToken {
+  pub fn new(user_id: Thing, jti: String, purpose: TokenPurpose, expires_at: DateTime<Utc>) -> Self {
+    Self {
+      id: None,
+      user_id,
+      jti,
+      purpose,
+      expires_at,
+      used_at: None,
+      invalidated: false,
+      created_at: Utc::now(),
+    }
+  }
+
+  pub fn is_expired(&self) -> bool {
+    self.expires_at <= Utc::now()
+  }
+
+  pub fn is_usable(&self) -> bool {
+    self.used_at.is_none() && !self.invalidated && !self.is_expired()
+  }
+}
\ No newline at end of file
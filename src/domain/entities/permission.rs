@@ -19,7 +19,7 @@ impl Permission {
   }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Resource {
   Orders,
@@ -50,22 +50,24 @@ impl std::str::FromStr for Resource {
   }
 }
 
-impl Resource {
-  pub fn to_string(&self) -> String {
-    match self {
-      Resource::Orders => "orders".to_string(),
-      Resource::Inventory => "inventory".to_string(),
-      Resource::Users => "users".to_string(),
-      Resource::Employees => "employees".to_string(),
-      Resource::Reports => "reports".to_string(),
-      Resource::Settings => "settings".to_string(),
-      Resource::Menu => "menu".to_string(),
-      Resource::Transactions => "transactions".to_string(),
-    }
+impl std::fmt::Display for Resource {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let value = match self {
+      Resource::Orders => "orders",
+      Resource::Inventory => "inventory",
+      Resource::Users => "users",
+      Resource::Employees => "employees",
+      Resource::Reports => "reports",
+      Resource::Settings => "settings",
+      Resource::Menu => "menu",
+      Resource::Transactions => "transactions",
+    };
+
+    write!(f, "{}", value)
   }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Action {
   Create,
@@ -94,16 +96,18 @@ impl std::str::FromStr for Action {
   }
 }
 
-impl Action {
-  pub fn to_string(&self) -> String {
-    match self {
-      Action::Create => "create".to_string(),
-      Action::Read => "read".to_string(),
-      Action::Update => "update".to_string(),
-      Action::Delete => "delete".to_string(),
-      Action::Approve => "approve".to_string(),
-      Action::Cancel => "cancel".to_string(),
-      Action::Manage => "manage".to_string(),
-    }
+impl std::fmt::Display for Action {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let value = match self {
+      Action::Create => "create",
+      Action::Read => "read",
+      Action::Update => "update",
+      Action::Delete => "delete",
+      Action::Approve => "approve",
+      Action::Cancel => "cancel",
+      Action::Manage => "manage",
+    };
+
+    write!(f, "{}", value)
   }
 }
\ No newline at end of file
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+// Plan con el que empieza su prueba un restaurante nuevo
+pub const DEFAULT_PLAN: &str = "starter";
+
+// Nivel de suscripción que vende la plataforma. Los límites en None no tienen tope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Plan {
+  pub id: Option<Thing>,
+  // Identificador público, ej. "starter"
+  pub code: String,
+  pub name: String,
+  pub limits: PlanLimits,
+  #[serde(default)]
+  pub features: Vec<Feature>,
+  // Días de prueba al darse de alta; 0 si el plan no tiene prueba
+  pub trial_days: u32,
+  pub is_active: bool,
+  pub created_at: DateTime<Utc>,
+}
+
+impl Plan {
+  pub fn includes(&self, feature: &Feature) -> bool {
+    self.features.contains(feature)
+  }
+
+  pub fn limit(&self, limit: PlanLimit) -> Option<u64> {
+    match limit {
+      PlanLimit::Locations => self.limits.max_locations,
+      PlanLimit::Staff => self.limits.max_staff,
+      PlanLimit::MenuItems => self.limits.max_menu_items,
+      PlanLimit::MonthlyOrders => self.limits.max_monthly_orders,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlanLimits {
+  pub max_locations: Option<u64>,
+  pub max_staff: Option<u64>,
+  pub max_menu_items: Option<u64>,
+  pub max_monthly_orders: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanLimit {
+  Locations,
+  Staff,
+  MenuItems,
+  MonthlyOrders,
+}
+
+impl PlanLimit {
+  pub const ALL: [PlanLimit; 4] = [
+    PlanLimit::Locations,
+    PlanLimit::Staff,
+    PlanLimit::MenuItems,
+    PlanLimit::MonthlyOrders,
+  ];
+}
+
+impl std::fmt::Display for PlanLimit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let value = match self {
+      PlanLimit::Locations => "locations",
+      PlanLimit::Staff => "staff",
+      PlanLimit::MenuItems => "menu_items",
+      PlanLimit::MonthlyOrders => "monthly_orders",
+    };
+
+    write!(f, "{}", value)
+  }
+}
+
+// Módulos que se activan o no según el plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Feature {
+  Pos,
+  ApiKeys,
+  Inventory,
+  Reservations,
+  Loyalty,
+}
+
+impl std::fmt::Display for Feature {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let value = match self {
+      Feature::Pos => "pos",
+      Feature::ApiKeys => "api_keys",
+      Feature::Inventory => "inventory",
+      Feature::Reservations => "reservations",
+      Feature::Loyalty => "loyalty",
+    };
+
+    write!(f, "{}", value)
+  }
+}
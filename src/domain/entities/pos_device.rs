@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+// Terminal compartido (tablet de caja o de meseros) autorizado para el login por PIN
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PosDevice {
+  pub id: Option<Thing>,
+  pub name: String,
+  // Hash Argon2 del secreto del dispositivo, el valor en claro solo se entrega al registrarlo
+  pub secret: String,
+  pub is_active: bool,
+  pub registered_by: Option<Thing>,
+  // Último empleado que inició sesión en el terminal
+  pub current_user: Option<Thing>,
+  pub last_used_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
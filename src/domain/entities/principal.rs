@@ -0,0 +1,21 @@
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::services::permission_guard::{PermissionGuard, RequiredPermission};
+
+// Quién hace la petición: un usuario con bearer token o un equipo con `X-Api-Key`
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum Principal {
+  User(AuthenticatedUser),
+  ApiKey(ApiKey),
+}
+
+impl Principal {
+  pub fn require(&self, required: &RequiredPermission) -> Result<(), Error> {
+    match self {
+      Principal::User(auth_user) => auth_user.require(required),
+      Principal::ApiKey(api_key) => PermissionGuard::check_scopes(&api_key.scopes, required),
+    }
+  }
+}
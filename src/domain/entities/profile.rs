@@ -22,6 +22,7 @@ pub struct Profile {
 }
 
 impl Profile {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(user_id: Thing, first_name: String, last_name: String, phone: String, address: Address, position: Option<String>, avatar: Option<String>, emergency_contact: Option<EmergencyContact>, birth_date: DateTime<Utc>) -> Self {
     Self { 
       id: None,
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+use crate::domain::value_objects::user_types::UserType;
+
+const DATABASE_PREFIX: &str = "tenant_";
+
+// Tenant de la plataforma: usuarios, roles y claves de cada restaurante quedan aislados del resto
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Restaurant {
+  pub id: Option<Thing>,
+  pub name: String,
+  // Identificador público del tenant: subdominio y header `X-Tenant`
+  pub slug: String,
+  // Base propia dentro del namespace para las cadenas que exigen aislamiento; None comparte la de la plataforma
+  #[serde(default)]
+  pub database: Option<String>,
+  pub is_active: bool,
+  // Cada rol suma los permisos de los roles propios del restaurante que quedan por debajo
+  #[serde(default)]
+  pub inherit_role_permissions: bool,
+  // Tipos de usuario con 2FA obligatorio; None sigue la política por defecto de la plataforma
+  #[serde(default)]
+  pub mfa_required_user_types: Option<Vec<UserType>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl Restaurant {
+  pub fn new(name: String, slug: String, dedicated_database: bool) -> Self {
+    Self {
+      id: None,
+      name,
+      database: dedicated_database.then(|| format!("{}{}", DATABASE_PREFIX, slug.replace('-', "_"))),
+      slug,
+      is_active: true,
+      inherit_role_permissions: false,
+      mfa_required_user_types: None,
+      created_at: Utc::now(),
+      updated_at: Utc::now(),
+    }
+  }
+}
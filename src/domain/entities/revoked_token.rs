@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+// Entrada de la lista de revocación de access tokens. Puede apuntar a un token concreto (`jti`)
+// o a todos los tokens del usuario emitidos hasta `issued_before` (cambio de contraseña, baja).
+// Se borra al llegar a `expires_at`, cuando ningún token afectado puede seguir vigente.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevokedToken {
+  pub id: Option<Thing>,
+  pub jti: Option<String>,
+  pub user_id: Thing,
+  pub issued_before: Option<DateTime<Utc>>,
+  pub expires_at: DateTime<Utc>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl RevokedToken {
+  pub fn for_token(jti: String, user_id: &str, expires_at: DateTime<Utc>) -> Self {
+    Self {
+      id: None,
+      jti: Some(jti),
+      user_id: Thing::from(("users", user_id)),
+      issued_before: None,
+      expires_at,
+      created_at: Utc::now(),
+    }
+  }
+
+  pub fn for_user(user_id: &str, expires_at: DateTime<Utc>) -> Self {
+    Self {
+      id: None,
+      jti: None,
+      user_id: Thing::from(("users", user_id)),
+      issued_before: Some(Utc::now()),
+      expires_at,
+      created_at: Utc::now(),
+    }
+  }
+
+  pub fn is_expired(&self) -> bool {
+    self.expires_at <= Utc::now()
+  }
+
+  // `iat` tiene resolución de segundos: un token emitido en el mismo segundo de la revocación también cae
+  pub fn matches(&self, jti: Option<&str>, user_id: &str, issued_at: DateTime<Utc>) -> bool {
+    if self.is_expired() {
+      return false;
+    }
+
+    let same_token = jti.is_some() && self.jti.as_deref() == jti;
+    let user_cutoff = self.user_id.id.to_string() == user_id
+      && self.issued_before.is_some_and(|issued_before| issued_at <= issued_before);
+
+    same_token || user_cutoff
+  }
+}
@@ -2,18 +2,45 @@ use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use surrealdb::sql::Thing;
 
+use crate::domain::error::Error;
+
+const MAX_NAME_LENGTH: usize = 50;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Role {
   pub id: Option<Thing>,
   pub name: String,
   pub description: String,
   pub hierarchy_level: i32,
+  // None en los roles de la plataforma, compartidos por todos los restaurantes
+  #[serde(default)]
+  pub restaurant_id: Option<Thing>,
   pub is_active: bool,
   pub created_at: DateTime<Utc>,
 }
 
 impl Role {
   pub fn new(name: String, description: String, hierarchy_level: i32) -> Self {
-    Self { id: None, name, description, hierarchy_level, is_active: true, created_at: Utc::now() }
+    Self { id: None, name, description, hierarchy_level, restaurant_id: None, is_active: true, created_at: Utc::now() }
+  }
+
+  // Los restaurantes eligen el nombre de sus roles (ej. "bartender"); se guarda como identificador
+  pub fn validate(&self) -> Result<(), Error> {
+    let valid = (2..=MAX_NAME_LENGTH).contains(&self.name.len())
+      && self.name.starts_with(|c: char| c.is_ascii_lowercase())
+      && self.name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if !valid {
+      return Err(Error::ValidationError(format!(
+        "Role name must be 2-{} lowercase letters, digits or underscores, starting with a letter",
+        MAX_NAME_LENGTH
+      )));
+    }
+
+    if self.hierarchy_level < 1 {
+      return Err(Error::ValidationError("Hierarchy level must be positive".to_string()));
+    }
+
+    Ok(())
   }
 }
\ No newline at end of file
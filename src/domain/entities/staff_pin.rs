@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Thing;
+
+// PIN de un empleado para los terminales POS; lleva su propio contador de intentos,
+// así un PIN mal escrito en caja no bloquea el login con contraseña
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaffPin {
+  pub id: Option<Thing>,
+  pub user_id: Thing,
+  pub pin: String,
+  pub failed_attempts: i32,
+  pub locked_until: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,115 @@
+use chrono::{DateTime, Duration, Months, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+use crate::domain::entities::plan::{Plan, PlanLimit};
+
+// Suscripción de un restaurante a un plan. El período se renueva cada mes y con él
+// se reinicia el contador de pedidos.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Subscription {
+  pub id: Option<Thing>,
+  pub restaurant_id: Thing,
+  pub plan: Thing,
+  pub status: SubscriptionStatus,
+  pub trial_ends_at: Option<DateTime<Utc>>,
+  pub current_period_start: DateTime<Utc>,
+  pub current_period_end: DateTime<Utc>,
+  #[serde(default)]
+  pub usage: Usage,
+  // Última vez que la tarea `usage_refresh` recontó el uso
+  pub usage_refreshed_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl Subscription {
+  // Alta en el plan: en prueba si el plan la tiene, si no directamente activa
+  pub fn start(restaurant_id: Thing, plan: &Plan) -> Self {
+    let now = Utc::now();
+    let status = match plan.trial_days {
+      0 => SubscriptionStatus::Active,
+      _ => SubscriptionStatus::Trialing,
+    };
+
+    Self {
+      id: None,
+      restaurant_id,
+      plan: plan.id.clone().unwrap_or_else(|| Thing::from(("plans", plan.code.as_str()))),
+      trial_ends_at: (status == SubscriptionStatus::Trialing).then(|| now + Duration::days(plan.trial_days.into())),
+      status,
+      current_period_start: now,
+      current_period_end: next_period_end(now),
+      usage: Usage::default(),
+      usage_refreshed_at: None,
+      created_at: now,
+      updated_at: now,
+    }
+  }
+
+  // Cambio de plan o de estado hecho por la plataforma; empieza un período nuevo
+  pub fn change(&mut self, plan: &Plan, status: SubscriptionStatus) {
+    let now = Utc::now();
+
+    self.plan = plan.id.clone().unwrap_or_else(|| Thing::from(("plans", plan.code.as_str())));
+    self.trial_ends_at = match status {
+      SubscriptionStatus::Trialing => Some(now + Duration::days(plan.trial_days.into())),
+      _ => None,
+    };
+    self.status = status;
+    self.current_period_start = now;
+    self.current_period_end = next_period_end(now);
+    self.updated_at = now;
+  }
+
+  // Una prueba vencida o una suscripción cancelada no dan acceso a nada del plan
+  pub fn is_entitled_at(&self, now: DateTime<Utc>) -> bool {
+    match self.status {
+      SubscriptionStatus::Trialing => self.trial_ends_at.is_some_and(|ends_at| ends_at > now),
+      SubscriptionStatus::Active | SubscriptionStatus::PastDue => true,
+      SubscriptionStatus::Canceled => false,
+    }
+  }
+
+  // Avanza los períodos ya cumplidos
+  pub fn roll_period(&mut self, now: DateTime<Utc>) {
+    while self.current_period_end <= now {
+      self.current_period_start = self.current_period_end;
+      self.current_period_end = next_period_end(self.current_period_start);
+    }
+  }
+}
+
+fn next_period_end(start: DateTime<Utc>) -> DateTime<Utc> {
+  start.checked_add_months(Months::new(1)).unwrap_or(start + Duration::days(30))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+  Trialing,
+  Active,
+  // Pago pendiente: se mantiene el acceso mientras la plataforma lo gestiona
+  PastDue,
+  Canceled,
+}
+
+// Uso del restaurante según el último recuento
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Usage {
+  pub locations: u64,
+  pub staff: u64,
+  pub menu_items: u64,
+  pub monthly_orders: u64,
+}
+
+impl Usage {
+  pub fn get(&self, limit: PlanLimit) -> u64 {
+    match limit {
+      PlanLimit::Locations => self.locations,
+      PlanLimit::Staff => self.staff,
+      PlanLimit::MenuItems => self.menu_items,
+      PlanLimit::MonthlyOrders => self.monthly_orders,
+    }
+  }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use surrealdb::sql::Thing;
+use uuid::Uuid;
+
+use crate::domain::value_objects::client_info::ClientInfo;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccessToken {
@@ -18,9 +21,20 @@ pub struct RefreshToken {
   pub created_at: DateTime<Utc>,
   pub used: bool,
   pub invalidated: bool,
+  // Se conserva en cada rotación: identifica la sesión aunque el refresh token cambie
+  pub session_id: String,
+  pub session_started_at: DateTime<Utc>,
+  pub device_name: Option<String>,
+  pub ip_address: Option<String>,
+  pub user_agent: Option<String>,
+  pub last_used_at: DateTime<Utc>,
+  // Sucursal elegida en la sesión; se copia en cada rotación como el resto de datos de la sesión
+  #[serde(default)]
+  pub location_id: Option<String>,
 }
 
 impl RefreshToken {
+  #[allow(dead_code)]
   pub fn new(user_id: Thing, token: String, access_token: String, expires_at: DateTime<Utc>) -> Self {
     Self { 
       id: None,
@@ -30,9 +44,28 @@ impl RefreshToken {
       expires_at, 
       created_at: Utc::now(), 
       used: false, 
-      invalidated: false 
+      invalidated: false,
+      session_id: Uuid::new_v4().to_string(),
+      session_started_at: Utc::now(),
+      device_name: None,
+      ip_address: None,
+      user_agent: None,
+      last_used_at: Utc::now(),
+      location_id: None,
     }
   }
+
+  pub fn is_expired(&self) -> bool {
+    self.expires_at <= Utc::now()
+  }
+
+  // Al renovar se toman la IP y el agente del cliente actual; el nombre del dispositivo solo si lo vuelve a enviar
+  pub fn with_client(mut self, client: &ClientInfo) -> Self {
+    self.device_name = client.device_name.clone().or(self.device_name);
+    self.ip_address = client.ip_address.clone();
+    self.user_agent = client.user_agent.clone();
+    self
+  }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
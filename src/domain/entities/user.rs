@@ -14,6 +14,9 @@ pub struct User {
   pub password: String,
   pub status: UserStatus,
   pub user_type: UserType,
+  // Restaurante al que pertenece; None para los usuarios de la plataforma
+  #[serde(default)]
+  pub restaurant_id: Option<Thing>,
   pub profile: Option<Profile>,
   pub roles: Option<Vec<Role>>,
   pub permissions: Option<Vec<Permission>>,
@@ -22,6 +25,10 @@ pub struct User {
   pub failed_login_attempts: i32,
   pub last_login: Option<DateTime<Utc>>,
   pub locked_until: Option<DateTime<Utc>>,
+  pub password_changed_at: Option<DateTime<Utc>>,
+  // Borrado lógico: la cuenta sigue en la base hasta la purga
+  #[serde(default)]
+  pub deleted_at: Option<DateTime<Utc>>,
   pub created_at: DateTime<Utc>,
   pub updated_at: DateTime<Utc>,
 }
@@ -34,11 +41,14 @@ impl User {
       password, 
       status, 
       user_type, 
+      restaurant_id: None,
       is_verified: false, 
       is_active: true, 
       failed_login_attempts: 0, 
       last_login: None, 
       locked_until: None,
+      password_changed_at: None,
+      deleted_at: None,
       roles: None,
       permissions: None,
       profile: None,
@@ -46,4 +56,9 @@ impl User {
       updated_at: Utc::now() 
     }
   }
+
+  // Las cuentas desactivadas o borradas no abren sesión aunque la credencial sea válida
+  pub fn can_sign_in(&self) -> bool {
+    self.is_active && self.deleted_at.is_none()
+  }
 }
\ No newline at end of file
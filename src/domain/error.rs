@@ -1,13 +1,20 @@
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 use serde::Serialize;
 
+use crate::domain::services::password_policy::{self, PasswordViolation};
+
 #[derive(Serialize)]
 pub struct ErrorResponse {
   pub code: u16,
   pub message: String,
+  // Solo en errores de política de contraseñas: todas las reglas incumplidas
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub violations: Option<Vec<PasswordViolation>>,
 }
 
 #[derive(Error, Debug)]
+#[allow(dead_code, clippy::enum_variant_names)]
 pub enum Error {
   // Errores de autenticación
   #[error("Invalid credentials")]
@@ -18,10 +25,43 @@ pub enum Error {
   
   #[error("Invalid token")]
   InvalidToken,
+
+  #[error("Missing authentication token")]
+  MissingToken,
   
   #[error("Unauthorized access")]
   UnauthorizedAccess,
 
+  #[error("Account locked until {0}")]
+  AccountLocked(DateTime<Utc>),
+
+  #[error("Invalid two-factor code")]
+  InvalidMfaCode,
+
+  #[error("Two-factor authentication is not enrolled")]
+  MfaNotEnrolled,
+
+  #[error("Two-factor authentication is already enabled")]
+  MfaAlreadyEnabled,
+
+  #[error("Two-factor authentication is required for this account")]
+  MfaRequired,
+
+  #[error("Session not found")]
+  SessionNotFound,
+
+  #[error("Password expired, reset it to sign in again")]
+  PasswordExpired,
+
+  #[error("Single sign-on is not configured")]
+  OidcNotConfigured,
+
+  #[error("The identity provider did not confirm the email address")]
+  UnverifiedExternalEmail,
+
+  #[error("Identity provider error: {0}")]
+  IdentityProviderError(String),
+
   // Errores de usuario
   #[error("User not found")]
   UserNotFound,
@@ -51,6 +91,9 @@ pub enum Error {
   #[error("Unauthorized operation")]
   UnauthorizedOperation,
 
+  #[error("Too many requests, retry in {0} seconds")]
+  TooManyRequests(u64),
+
   // Errores de perfil
   #[error("Profile not found")]
   ProfileNotFound,
@@ -61,15 +104,67 @@ pub enum Error {
   #[error("Invalid phone number")]
   InvalidPhone,
 
+  // Errores de contraseña
+  #[error("Password {}", password_policy::describe(.0))]
+  PasswordPolicy(Vec<PasswordViolation>),
+
   // Errores de rol y permisos
   #[error("Role not found")]
   RoleNotFound,
+
+  #[error("Role already exists with name: {0}")]
+  RoleAlreadyExists(String),
+
+  #[error("Role is at or above your own hierarchy level")]
+  RoleHierarchyViolation,
   
   #[error("Permission not found")]
   PermissionNotFound,
   
-  #[error("Invalid permission")]
-  InvalidPermission,
+  #[error("Missing permission: {0}")]
+  InvalidPermission(String),
+
+  // Errores de tenant
+  #[error("Restaurant not found")]
+  RestaurantNotFound,
+
+  #[error("Restaurant already exists with slug: {0}")]
+  RestaurantAlreadyExists(String),
+
+  #[error("Restaurant is inactive")]
+  RestaurantInactive,
+
+  #[error("The token does not belong to this restaurant")]
+  TenantMismatch,
+
+  #[error("Restaurant does not have a dedicated database")]
+  TenantNotDedicated,
+
+  // Errores de sucursal
+  #[error("Location not found")]
+  LocationNotFound,
+
+  #[error("Location already exists with name: {0}")]
+  LocationAlreadyExists(String),
+
+  #[error("User is not assigned to this location")]
+  LocationAccessDenied,
+
+  #[error("The token is scoped to another location")]
+  LocationMismatch,
+
+  // Errores de suscripción
+  #[error("Plan not found")]
+  PlanNotFound,
+
+  #[error("The restaurant has no active subscription")]
+  SubscriptionRequired,
+
+  #[error("Feature not included in the plan: {0}")]
+  FeatureNotInPlan(String),
+
+  #[error("Plan limit reached: {0}")]
+  PlanLimitReached(String),
 
   // Errores de validación
   #[error("Validation error: {0}")]
@@ -144,7 +239,6 @@ impl From<surrealdb::Error> for Error {
     match err {
       surrealdb::Error::Db(db_err) => Error::DatabaseError(db_err.to_string()),
       surrealdb::Error::Api(api_err) => Error::InfrastructureError(api_err.to_string()),
-      _ => Error::SurrealDBError(err.to_string()),
     }
   }
 }
@@ -154,10 +248,19 @@ impl actix_web::error::ResponseError for Error {
     let error_response = ErrorResponse {
       code: self.status_code().as_u16(),
       message: self.to_string(),
+      violations: match self {
+        Error::PasswordPolicy(violations) => Some(violations.clone()),
+        _ => None,
+      },
     };
 
-    actix_web::HttpResponse::build(self.status_code())
-      .json(error_response)
+    let mut response = actix_web::HttpResponse::build(self.status_code());
+
+    if let Error::TooManyRequests(retry_after) = self {
+      response.insert_header((actix_web::http::header::RETRY_AFTER, retry_after.to_string()));
+    }
+
+    response.json(error_response)
   }
 
   fn status_code(&self) -> actix_web::http::StatusCode {
@@ -167,6 +270,7 @@ impl actix_web::error::ResponseError for Error {
       Error::InvalidCredentials => StatusCode::UNAUTHORIZED,
       Error::TokenExpired => StatusCode::UNAUTHORIZED,
       Error::InvalidToken => StatusCode::UNAUTHORIZED,
+      Error::MissingToken => StatusCode::UNAUTHORIZED,
       Error::UserCreationError(_) => StatusCode::INTERNAL_SERVER_ERROR,
       Error::UserUpdateError(_) => StatusCode::INTERNAL_SERVER_ERROR,
       Error::UserDeletionError(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -174,10 +278,37 @@ impl actix_web::error::ResponseError for Error {
       Error::SerializationError(_) => StatusCode::INTERNAL_SERVER_ERROR,
       Error::DeserializationError(_) => StatusCode::INTERNAL_SERVER_ERROR,
       Error::UnauthorizedAccess => StatusCode::FORBIDDEN,
+      Error::UnauthorizedOperation => StatusCode::FORBIDDEN,
+      Error::InvalidOperation => StatusCode::BAD_REQUEST,
+      Error::AccountLocked(_) => StatusCode::LOCKED,
+      Error::InvalidMfaCode => StatusCode::UNAUTHORIZED,
+      Error::MfaNotEnrolled => StatusCode::BAD_REQUEST,
+      Error::MfaAlreadyEnabled => StatusCode::CONFLICT,
+      Error::MfaRequired => StatusCode::FORBIDDEN,
+      Error::SessionNotFound => StatusCode::NOT_FOUND,
+      Error::PasswordExpired => StatusCode::FORBIDDEN,
+      Error::OidcNotConfigured => StatusCode::NOT_FOUND,
+      Error::UnverifiedExternalEmail => StatusCode::FORBIDDEN,
+      Error::IdentityProviderError(_) => StatusCode::BAD_GATEWAY,
+      Error::PasswordPolicy(_) => StatusCode::BAD_REQUEST,
+      Error::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
       Error::UserNotFound => StatusCode::NOT_FOUND,
       Error::UserAlreadyExists(_) => StatusCode::CONFLICT,
       Error::ProfileNotFound => StatusCode::NOT_FOUND,
       Error::ProfileAlreadyExists => StatusCode::CONFLICT,
+      Error::RestaurantNotFound => StatusCode::NOT_FOUND,
+      Error::RestaurantAlreadyExists(_) => StatusCode::CONFLICT,
+      Error::RestaurantInactive => StatusCode::FORBIDDEN,
+      Error::TenantMismatch => StatusCode::FORBIDDEN,
+      Error::TenantNotDedicated => StatusCode::CONFLICT,
+      Error::LocationNotFound => StatusCode::NOT_FOUND,
+      Error::LocationAlreadyExists(_) => StatusCode::CONFLICT,
+      Error::LocationAccessDenied => StatusCode::FORBIDDEN,
+      Error::LocationMismatch => StatusCode::FORBIDDEN,
+      Error::PlanNotFound => StatusCode::NOT_FOUND,
+      Error::SubscriptionRequired => StatusCode::PAYMENT_REQUIRED,
+      Error::FeatureNotInPlan(_) => StatusCode::FORBIDDEN,
+      Error::PlanLimitReached(_) => StatusCode::PAYMENT_REQUIRED,
       Error::ValidationError(_) => StatusCode::BAD_REQUEST,
       Error::InvalidInput(_) => StatusCode::BAD_REQUEST,
       Error::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -185,8 +316,10 @@ impl actix_web::error::ResponseError for Error {
       Error::InvalidEmail => StatusCode::BAD_REQUEST,
       Error::InvalidPhone => StatusCode::BAD_REQUEST,
       Error::RoleNotFound => StatusCode::NOT_FOUND,
+      Error::RoleAlreadyExists(_) => StatusCode::CONFLICT,
+      Error::RoleHierarchyViolation => StatusCode::FORBIDDEN,
       Error::PermissionNotFound => StatusCode::NOT_FOUND,
-      Error::InvalidPermission => StatusCode::FORBIDDEN,
+      Error::InvalidPermission(_) => StatusCode::FORBIDDEN,
       Error::TransactionError(_) => StatusCode::INTERNAL_SERVER_ERROR,
       Error::InfrastructureError(_) => StatusCode::INTERNAL_SERVER_ERROR,
       Error::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait ApiKeyRepository {
+  // La clave llega en claro y solo se guarda su hash
+  async fn create(&self, api_key: &ApiKey, key: &str) -> Result<ApiKey, Error>;
+  async fn find_all(&self) -> Result<Vec<ApiKey>, Error>;
+  async fn find_by_id(&self, id: String) -> Result<Option<ApiKey>, Error>;
+  // Solo devuelve claves activas y no revocadas
+  async fn find_by_key(&self, key: &str) -> Result<Option<ApiKey>, Error>;
+  async fn revoke(&self, id: String) -> Result<ApiKey, Error>;
+  async fn touch_last_used(&self, id: String) -> Result<(), Error>;
+  async fn purge_revoked(&self, revoked_before: DateTime<Utc>) -> Result<u64, Error>;
+}
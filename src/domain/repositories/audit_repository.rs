@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::audit_event::{AuditEvent, AuditFilter};
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait AuditRepository {
+  async fn create(&self, event: &AuditEvent) -> Result<(), Error>;
+  // Devuelve la página pedida, de más reciente a más antiguo, y el total de eventos que cumplen el filtro
+  async fn find_all(&self, filter: &AuditFilter, offset: u64, limit: u64) -> Result<(Vec<AuditEvent>, u64), Error>;
+}
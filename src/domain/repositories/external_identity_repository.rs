@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::external_identity::ExternalIdentity;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait ExternalIdentityRepository {
+  async fn find_by_subject(&self, issuer: &str, subject: &str) -> Result<Option<ExternalIdentity>, Error>;
+  async fn create(&self, identity: &ExternalIdentity) -> Result<ExternalIdentity, Error>;
+  async fn record_login(&self, issuer: &str, subject: &str, email: Option<String>) -> Result<(), Error>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::job_run::JobRun;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait JobRunRepository {
+  // Crea o reemplaza el estado de la tarea `job_run.name`
+  async fn save(&self, job_run: &JobRun) -> Result<JobRun, Error>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::location::Location;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait LocationRepository {
+  async fn create(&self, location: &Location) -> Result<Location, Error>;
+  async fn find_by_id(&self, id: String) -> Result<Option<Location>, Error>;
+  async fn find_by_name(&self, name: &str) -> Result<Option<Location>, Error>;
+  async fn find_all(&self) -> Result<Vec<Location>, Error>;
+  async fn update(&self, id: String, location: &Location) -> Result<Location, Error>;
+  async fn delete(&self, id: String) -> Result<(), Error>;
+  // Sucursales activas en las que trabaja el usuario
+  async fn find_user_locations(&self, user_id: String) -> Result<Vec<Location>, Error>;
+  async fn is_user_assigned(&self, user_id: String, location_id: String) -> Result<bool, Error>;
+  async fn assign_user(&self, user_id: String, location_id: String, assigned_by: String) -> Result<(), Error>;
+  // También le quita los roles que tenía en esa sucursal
+  async fn remove_user(&self, user_id: String, location_id: String) -> Result<(), Error>;
+}
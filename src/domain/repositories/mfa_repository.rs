@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::mfa::TotpCredential;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait MfaRepository {
+  async fn find_by_user(&self, user_id: String) -> Result<Option<TotpCredential>, Error>;
+  // Reemplaza cualquier enrolamiento pendiente del usuario
+  async fn save_pending(&self, credential: &TotpCredential) -> Result<TotpCredential, Error>;
+  async fn enable(&self, user_id: String, recovery_codes: Vec<String>) -> Result<TotpCredential, Error>;
+  // Devuelve false si el paso ya se había usado (evita reutilizar un código dentro de su ventana)
+  async fn record_used_step(&self, user_id: String, step: i64) -> Result<bool, Error>;
+  // Devuelve false si el código no existe o ya se consumió
+  async fn consume_recovery_code(&self, user_id: String, code: &str) -> Result<bool, Error>;
+  async fn replace_recovery_codes(&self, user_id: String, recovery_codes: Vec<String>) -> Result<(), Error>;
+  async fn delete(&self, user_id: String) -> Result<(), Error>;
+}
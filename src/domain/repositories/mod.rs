@@ -3,3 +3,19 @@ pub mod role_repository;
 pub mod permission_repository;
 pub mod token_repository;
 pub mod profile_repository;
+pub mod one_time_token_repository;
+
+pub mod mfa_repository;
+pub mod pos_device_repository;
+pub mod staff_pin_repository;
+pub mod api_key_repository;
+pub mod revoked_token_repository;
+pub mod job_run_repository;
+pub mod audit_repository;
+pub mod oidc_authorization_repository;
+pub mod external_identity_repository;
+pub mod restaurant_repository;
+pub mod location_repository;
+pub mod plan_repository;
+pub mod subscription_repository;
+pub mod usage_repository;
\ No newline at end of file
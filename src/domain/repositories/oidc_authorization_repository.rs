@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::oidc_authorization::OidcAuthorization;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait OidcAuthorizationRepository {
+  async fn create(&self, authorization: &OidcAuthorization) -> Result<(), Error>;
+  // La borra y la devuelve si sigue vigente: un `state` solo se puede usar una vez
+  async fn consume(&self, state: &str) -> Result<Option<OidcAuthorization>, Error>;
+  async fn cleanup_expired(&self) -> Result<u64, Error>;
+}
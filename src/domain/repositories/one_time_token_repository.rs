@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::one_time_token::{OneTimeToken, TokenPurpose};
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait OneTimeTokenRepository {
+  async fn create(&self, token: &OneTimeToken) -> Result<OneTimeToken, Error>;
+  async fn find_by_jti(&self, jti: &str) -> Result<Option<OneTimeToken>, Error>;
+  async fn find_latest_for_user(&self, user_id: String, purpose: TokenPurpose) -> Result<Option<OneTimeToken>, Error>;
+  // Devuelve false si el token ya se había usado o invalidado
+  async fn consume(&self, jti: &str) -> Result<bool, Error>;
+  async fn invalidate_for_user(&self, user_id: String, purpose: TokenPurpose) -> Result<(), Error>;
+  async fn cleanup_expired(&self) -> Result<u64, Error>;
+}
\ No newline at end of file
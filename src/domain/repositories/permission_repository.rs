@@ -4,6 +4,7 @@ use crate::domain::entities::permission::Permission;
 use crate::domain::error::Error;
 
 #[async_trait]
+#[allow(dead_code)]
 pub trait PermissionRepository {
   async fn find_by_id(&self, id: String) -> Result<Option<Permission>, Error>;
   async fn find_all(&self) -> Result<Vec<Permission>, Error>;
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::plan::Plan;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait PlanRepository {
+  async fn find_by_id(&self, id: String) -> Result<Option<Plan>, Error>;
+  async fn find_by_code(&self, code: &str) -> Result<Option<Plan>, Error>;
+  async fn find_all(&self) -> Result<Vec<Plan>, Error>;
+}
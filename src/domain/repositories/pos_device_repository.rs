@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::pos_device::PosDevice;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait PosDeviceRepository {
+  // El secreto llega en claro y se guarda hasheado
+  async fn create(&self, name: &str, secret: &str, registered_by: String) -> Result<PosDevice, Error>;
+  async fn find_all(&self) -> Result<Vec<PosDevice>, Error>;
+  async fn find_by_id(&self, id: String) -> Result<Option<PosDevice>, Error>;
+  // Devuelve el dispositivo solo si está activo y el secreto coincide
+  async fn authenticate(&self, id: String, secret: &str) -> Result<Option<PosDevice>, Error>;
+  async fn deactivate(&self, id: String) -> Result<PosDevice, Error>;
+  async fn set_current_user(&self, id: String, user_id: String) -> Result<PosDevice, Error>;
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::domain::entities::profile::Profile;
 use crate::domain::error::Error;
@@ -10,4 +11,5 @@ pub trait ProfileRepository {
   async fn delete(&self, id: String) -> Result<(), Error>;
   async fn find_by_id(&self, id: String) -> Result<Option<Profile>, Error>;
   async fn find_by_user_id(&self, user_id: String) -> Result<Option<Profile>, Error>;
+  async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64, Error>;
 }
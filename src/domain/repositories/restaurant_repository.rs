@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait RestaurantRepository {
+  async fn find_by_id(&self, id: String) -> Result<Option<Restaurant>, Error>;
+  async fn find_by_slug(&self, slug: &str) -> Result<Option<Restaurant>, Error>;
+  async fn find_all(&self) -> Result<Vec<Restaurant>, Error>;
+  async fn create(&self, restaurant: &Restaurant) -> Result<Restaurant, Error>;
+  async fn set_role_inheritance(&self, id: String, enabled: bool) -> Result<Restaurant, Error>;
+  async fn set_mfa_policy(&self, id: String, required_user_types: Option<Vec<UserType>>) -> Result<Restaurant, Error>;
+  async fn delete(&self, id: String) -> Result<(), Error>;
+}
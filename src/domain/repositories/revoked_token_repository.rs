@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::revoked_token::RevokedToken;
+use crate::domain::error::Error;
+
+#[async_trait]
+#[allow(dead_code)]
+pub trait RevokedTokenRepository {
+  async fn revoke(&self, entry: &RevokedToken) -> Result<(), Error>;
+  // Busca una entrada vigente que revoque el token por su `jti` o por el corte del usuario
+  async fn find_matching(&self, jti: Option<String>, user_id: String, issued_at: DateTime<Utc>) -> Result<Option<RevokedToken>, Error>;
+  async fn cleanup_expired(&self) -> Result<u64, Error>;
+}
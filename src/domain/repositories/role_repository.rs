@@ -2,14 +2,22 @@ use async_trait::async_trait;
 
 use crate::domain::error::Error;
 use crate::domain::entities::role::Role;
+use crate::domain::services::permission_guard::RequiredPermission;
 #[async_trait]
+#[allow(dead_code)]
 pub trait RoleRepository {
   async fn find_by_id(&self, id: String) -> Result<Option<Role>, Error>;
   async fn find_all(&self) -> Result<Vec<Role>, Error>;
-  async fn create(&self, role: &Role) -> Result<Role, Error>;
+  // Entre los roles de la plataforma y los del restaurante
+  async fn find_by_name(&self, name: &str) -> Result<Option<Role>, Error>;
+  // Crea el rol del restaurante con sus permisos; los que falten en el catálogo se dan de alta
+  async fn create(&self, role: &Role, permissions: &[RequiredPermission]) -> Result<Role, Error>;
   async fn update(&self, id: String, role: &Role) -> Result<Role, Error>;
   async fn delete(&self, id: String) -> Result<(), Error>;
+  // Roles asignados sin sucursal, válidos en todo el restaurante
   async fn find_user_roles(&self, user_id: String) -> Result<Vec<Role>, Error>;
-  async fn assign_role_to_user(&self, user_id: String, role_id: String) -> Result<(), Error>;
-  async fn remove_role_from_user(&self, user_id: String, role_id: String) -> Result<(), Error>;
+  // Roles asignados solo en una sucursal, ej. `restaurant_manager` de una de ellas
+  async fn find_user_location_roles(&self, user_id: String, location_id: String) -> Result<Vec<Role>, Error>;
+  async fn assign_role_to_user(&self, user_id: String, role_id: String, location_id: Option<String>, assigned_by: String) -> Result<(), Error>;
+  async fn remove_role_from_user(&self, user_id: String, role_id: String, location_id: Option<String>) -> Result<(), Error>;
 }
\ No newline at end of file
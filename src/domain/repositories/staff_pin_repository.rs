@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::staff_pin::StaffPin;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait StaffPinRepository {
+  // Crea o reemplaza el PIN del usuario y reinicia su contador de intentos
+  async fn set_pin(&self, user_id: String, pin: &str) -> Result<StaffPin, Error>;
+  async fn find_by_user(&self, user_id: String) -> Result<Option<StaffPin>, Error>;
+  async fn verify(&self, user_id: String, pin: &str) -> Result<bool, Error>;
+  async fn update_failed_attempts(&self, user_id: String, attempts: i32) -> Result<StaffPin, Error>;
+  async fn lock(&self, user_id: String, locked_until: DateTime<Utc>) -> Result<StaffPin, Error>;
+  async fn delete(&self, user_id: String) -> Result<(), Error>;
+  async fn release_expired_locks(&self, expired_before: DateTime<Utc>) -> Result<u64, Error>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::subscription::Subscription;
+use crate::domain::error::Error;
+
+#[async_trait]
+pub trait SubscriptionRepository {
+  async fn find_by_restaurant(&self, restaurant_id: String) -> Result<Option<Subscription>, Error>;
+  // Crea o reemplaza la suscripción del restaurante; hay una sola por restaurante
+  async fn save(&self, subscription: &Subscription) -> Result<Subscription, Error>;
+}
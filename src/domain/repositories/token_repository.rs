@@ -4,10 +4,19 @@ use crate::domain::entities::token::RefreshToken;
 use crate::domain::error::Error;
 
 #[async_trait]
+#[allow(dead_code)]
 pub trait TokenRepository {
   async fn create_refresh_token(&self, refresh_token: &RefreshToken) -> Result<RefreshToken, Error>;
   async fn find_refresh_token(&self, token: &str) -> Result<Option<RefreshToken>, Error>;
+  // Devuelve false si el token ya estaba marcado como usado (posible reutilización)
+  async fn mark_refresh_token_used(&self, token: &str) -> Result<bool, Error>;
   async fn invalidate_refresh_token(&self, user_id: String) -> Result<(), Error>;
   async fn invalidate_all_user_tokens(&self, user_id: String) -> Result<(), Error>;
+  // Refresh tokens vigentes del usuario: uno por sesión abierta
+  async fn find_active_sessions(&self, user_id: String) -> Result<Vec<RefreshToken>, Error>;
+  // Devuelve false si la sesión no existe, no es del usuario o ya estaba cerrada
+  async fn revoke_session(&self, user_id: String, session_id: String) -> Result<bool, Error>;
+  // Cambia la sucursal del refresh token vigente de la sesión; false si la sesión ya no está abierta
+  async fn set_session_location(&self, user_id: String, session_id: String, location_id: Option<String>) -> Result<bool, Error>;
   async fn cleanup_expired_tokens(&self) -> Result<u64, Error>;
 }
\ No newline at end of file
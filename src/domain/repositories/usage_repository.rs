@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::subscription::Usage;
+use crate::domain::error::Error;
+
+// Recuento de lo que el restaurante tiene en uso; los pedidos solo cuentan desde `period_start`
+#[async_trait]
+pub trait UsageRepository {
+  async fn count(&self, period_start: DateTime<Utc>) -> Result<Usage, Error>;
+}
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::domain::entities::user::User;
-use crate::domain::entities::role::Role;
 use crate::domain::error::Error;
 
 #[async_trait]
@@ -11,9 +11,18 @@ pub trait UserRepository {
   async fn find_by_id(&self, id: String) -> Result<Option<User>, Error>;
   async fn find_by_email(&self, email: &str) -> Result<Option<User>, Error>;
   async fn delete(&self, id: String) -> Result<(), Error>;
-  async fn change_password(&self, user_id: String, new_password: &str) -> Result<User, Error>;
+  // Guarda el hash anterior en el historial, que se recorta a `history_size` entradas
+  async fn change_password(&self, user_id: String, new_password: &str, history_size: usize) -> Result<User, Error>;
+  // Compara con la contraseña actual y las `history - 1` anteriores
+  async fn is_recent_password(&self, user_id: String, password: &str, history: usize) -> Result<bool, Error>;
   async fn set_verification_status(&self, user_id: String, is_verified: bool) -> Result<User, Error>;
   async fn update_failed_login_attempts(&self, user_id: String, attempts: i32) -> Result<User, Error>;
+  async fn lock_account(&self, user_id: String, locked_until: DateTime<Utc>) -> Result<User, Error>;
+  async fn unlock_account(&self, user_id: String) -> Result<User, Error>;
+  async fn record_successful_login(&self, user_id: String) -> Result<User, Error>;
   async fn authenticate(&self, email: &str, password: &str) -> Result<Option<User>, Error>;
-  async fn assign_roles(&self, user_id: String, roles: Vec<Role>) -> Result<User, Error>;
+  // Olvida los bloqueos vencidos antes de `expired_before` para que el backoff vuelva a empezar
+  async fn release_expired_locks(&self, expired_before: DateTime<Utc>) -> Result<u64, Error>;
+  // Borra definitivamente los usuarios dados de baja antes de `deleted_before` junto con sus datos
+  async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64, Error>;
 }
\ No newline at end of file
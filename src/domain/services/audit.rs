@@ -0,0 +1,9 @@
+use crate::domain::entities::audit_event::AuditEvent;
+use crate::domain::repositories::audit_repository::AuditRepository;
+
+// Un fallo al guardar el evento no debe tumbar la operación auditada; queda al menos en el log
+pub async fn record<A: AuditRepository>(repository: &A, event: AuditEvent) {
+  if let Err(err) = repository.create(&event).await {
+    log::error!("Failed to record audit event {} ({}): {}", event.action, event.outcome, err);
+  }
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+use crate::domain::entities::user::User;
+
+#[derive(Debug, Clone)]
+pub struct LockoutPolicy {
+  pub max_failed_attempts: i32,
+  pub base_lockout_duration: Duration,
+  pub max_lockout_duration: Duration,
+}
+
+impl LockoutPolicy {
+  pub fn new(max_failed_attempts: i32, base_lockout_duration: Duration, max_lockout_duration: Duration) -> Self {
+    Self { max_failed_attempts, base_lockout_duration, max_lockout_duration }
+  }
+
+  pub fn is_locked(&self, user: &User) -> bool {
+    self.is_locked_until(user.locked_until)
+  }
+
+  pub fn is_locked_until(&self, locked_until: Option<DateTime<Utc>>) -> bool {
+    matches!(locked_until, Some(locked_until) if locked_until > Utc::now())
+  }
+
+  // Backoff exponencial: al llegar al máximo de intentos se bloquea `base`, luego 2x, 4x... hasta `max`
+  pub fn lockout_duration(&self, failed_attempts: i32) -> Option<Duration> {
+    if failed_attempts < self.max_failed_attempts {
+      return None;
+    }
+
+    let exponent = (failed_attempts - self.max_failed_attempts).min(31) as u32;
+    let duration = self.base_lockout_duration
+      .checked_mul(2u32.saturating_pow(exponent))
+      .unwrap_or(self.max_lockout_duration);
+
+    Some(duration.min(self.max_lockout_duration))
+  }
+
+  pub fn locked_until(&self, failed_attempts: i32) -> Option<DateTime<Utc>> {
+    self.lockout_duration(failed_attempts)
+      .and_then(|duration| chrono::Duration::from_std(duration).ok())
+      .map(|duration| Utc::now() + duration)
+  }
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::domain::error::Error;
+
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+  pub to: String,
+  pub subject: String,
+  pub body: String,
+}
+
+impl EmailMessage {
+  pub fn new(to: String, subject: String, body: String) -> Self {
+    Self { to, subject, body }
+  }
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+  async fn send(&self, message: &EmailMessage) -> Result<(), Error>;
+}
\ No newline at end of file
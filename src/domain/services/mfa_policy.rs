@@ -0,0 +1,42 @@
+use crate::domain::entities::mfa::TotpCredential;
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::value_objects::user_types::UserType;
+
+// Política del tenant: tipos de usuario que no pueden iniciar sesión sin segundo factor. La de la
+// configuración vale para la plataforma y es el mínimo de cada restaurante, que solo puede añadir tipos.
+#[derive(Debug, Clone)]
+pub struct MfaPolicy {
+  pub required_user_types: Vec<UserType>,
+}
+
+impl MfaPolicy {
+  pub fn new(required_user_types: Vec<UserType>) -> Self {
+    Self { required_user_types }
+  }
+
+  // Esta política más los tipos que exige el restaurante
+  pub fn for_restaurant(&self, restaurant: &Restaurant) -> Self {
+    self.adding(restaurant.mfa_required_user_types.as_deref())
+  }
+
+  pub fn adding(&self, user_types: Option<&[UserType]>) -> Self {
+    let mut required_user_types = self.required_user_types.clone();
+
+    for user_type in user_types.into_iter().flatten() {
+      if !required_user_types.contains(user_type) {
+        required_user_types.push(user_type.clone());
+      }
+    }
+
+    Self::new(required_user_types)
+  }
+
+  pub fn is_required_for(&self, user_type: &UserType) -> bool {
+    self.required_user_types.contains(user_type)
+  }
+
+  // El segundo paso aplica si el usuario ya lo activó o si la política lo exige
+  pub fn applies_to(&self, user_type: &UserType, credential: Option<&TotpCredential>) -> bool {
+    credential.is_some_and(|credential| credential.enabled) || self.is_required_for(user_type)
+  }
+}
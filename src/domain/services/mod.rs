@@ -1 +1,16 @@
-pub mod token;
\ No newline at end of file
+pub mod token;
+pub mod permission_guard;
+pub mod lockout;
+pub mod mailer;
+pub mod totp;
+pub mod mfa_policy;
+pub mod pos;
+pub mod signing_keys;
+pub mod audit;
+pub mod password_policy;
+pub mod rate_limit;
+pub mod oidc;
+pub mod tenant_database;
+pub mod role_templates;
+pub mod tenant_onboarding;
+pub mod role_hierarchy;
\ No newline at end of file
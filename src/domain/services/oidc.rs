@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use ring::digest::{digest, SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Deserializer};
+use std::time::Duration;
+use url::Url;
+
+use crate::domain::error::Error;
+
+#[derive(Debug, Clone)]
+pub struct OidcConfig {
+  // Sin barra final: se compara tal cual con el claim `iss` del ID token
+  pub issuer: String,
+  pub client_id: String,
+  // Clientes confidenciales; los públicos se apoyan solo en PKCE
+  pub client_secret: Option<String>,
+  pub redirect_uri: String,
+  pub scopes: String,
+  // Tiempo que tiene el usuario para volver del proveedor
+  pub authorization_duration: Duration,
+}
+
+// Lo que necesitamos del documento `/.well-known/openid-configuration`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+  pub issuer: String,
+  pub authorization_endpoint: String,
+  pub token_endpoint: String,
+  pub jwks_uri: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdTokenClaims {
+  pub iss: String,
+  pub sub: String,
+  pub nonce: Option<String>,
+  pub email: Option<String>,
+  // Algunos proveedores (Apple) lo mandan como cadena "true"/"false"
+  #[serde(default, deserialize_with = "bool_or_string")]
+  pub email_verified: bool,
+}
+
+impl IdTokenClaims {
+  // Email en el que se puede confiar para enlazar o crear una cuenta
+  pub fn verified_email(&self) -> Option<String> {
+    self.email
+      .as_deref()
+      .filter(|_| self.email_verified)
+      .map(|email| email.trim().to_lowercase())
+  }
+}
+
+// Proveedor de identidad externo: descubrimiento, canje del código y claves públicas
+#[async_trait]
+pub trait OidcProvider: Send + Sync {
+  async fn metadata(&self) -> Result<ProviderMetadata, Error>;
+  // Devuelve el ID token de la respuesta del token endpoint
+  async fn exchange_code(&self, metadata: &ProviderMetadata, code: &str, code_verifier: &str) -> Result<String, Error>;
+  async fn jwks(&self, metadata: &ProviderMetadata) -> Result<JwkSet, Error>;
+}
+
+// Proof Key for Code Exchange (RFC 7636), siempre con S256
+pub struct Pkce {
+  pub verifier: String,
+  pub challenge: String,
+}
+
+impl Pkce {
+  pub fn generate() -> Result<Self, Error> {
+    let verifier = random_token()?;
+    let challenge = code_challenge(&verifier);
+
+    Ok(Self { verifier, challenge })
+  }
+}
+
+pub fn code_challenge(verifier: &str) -> String {
+  URL_SAFE_NO_PAD.encode(digest(&SHA256, verifier.as_bytes()))
+}
+
+// 32 bytes aleatorios en base64url: sirve para `state`, `nonce` y el verificador PKCE
+pub fn random_token() -> Result<String, Error> {
+  let mut bytes = [0u8; 32];
+
+  SystemRandom::new()
+    .fill(&mut bytes)
+    .map_err(|_| Error::TokenGenerationError("Failed to generate random bytes".to_string()))?;
+
+  Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+pub fn authorization_url(config: &OidcConfig, metadata: &ProviderMetadata, state: &str, nonce: &str, code_challenge: &str) -> Result<String, Error> {
+  let mut url = Url::parse(&metadata.authorization_endpoint)
+    .map_err(|e| Error::IdentityProviderError(format!("Invalid authorization endpoint: {}", e)))?;
+
+  url.query_pairs_mut()
+    .append_pair("response_type", "code")
+    .append_pair("client_id", &config.client_id)
+    .append_pair("redirect_uri", &config.redirect_uri)
+    .append_pair("scope", &config.scopes)
+    .append_pair("state", state)
+    .append_pair("nonce", nonce)
+    .append_pair("code_challenge", code_challenge)
+    .append_pair("code_challenge_method", "S256");
+
+  Ok(url.to_string())
+}
+
+// Firma, emisor, audiencia, vencimiento y nonce; los algoritmos simétricos no se aceptan
+pub fn validate_id_token(id_token: &str, jwks: &JwkSet, config: &OidcConfig, nonce: &str) -> Result<IdTokenClaims, Error> {
+  let header = decode_header(id_token).map_err(|_| Error::InvalidToken)?;
+
+  if !matches!(header.alg, Algorithm::RS256 | Algorithm::RS384 | Algorithm::RS512 | Algorithm::ES256 | Algorithm::ES384 | Algorithm::EdDSA) {
+    return Err(Error::InvalidToken);
+  }
+
+  // Sin `kid` solo vale si el proveedor publica una única clave
+  let jwk = match &header.kid {
+    Some(kid) => jwks.find(kid),
+    None if jwks.keys.len() == 1 => jwks.keys.first(),
+    None => None,
+  }
+  .ok_or(Error::InvalidToken)?;
+
+  let key = DecodingKey::from_jwk(jwk).map_err(|_| Error::InvalidToken)?;
+
+  let mut validation = Validation::new(header.alg);
+  validation.set_issuer(&[&config.issuer]);
+  validation.set_audience(&[&config.client_id]);
+  validation.set_required_spec_claims(&["exp", "iat", "iss", "aud", "sub"]);
+
+  let claims = decode::<IdTokenClaims>(id_token, &key, &validation)
+    .map_err(|e| match e.kind() {
+      jsonwebtoken::errors::ErrorKind::ExpiredSignature => Error::TokenExpired,
+      _ => Error::InvalidToken,
+    })?
+    .claims;
+
+  // Ata el ID token a esta autorización: evita que se reinyecte uno emitido para otra
+  if claims.nonce.as_deref() != Some(nonce) {
+    return Err(Error::InvalidToken);
+  }
+
+  Ok(claims)
+}
+
+fn bool_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum BoolOrString {
+    Bool(bool),
+    String(String),
+  }
+
+  Ok(match Option::<BoolOrString>::deserialize(deserializer)? {
+    Some(BoolOrString::Bool(value)) => value,
+    Some(BoolOrString::String(value)) => value.eq_ignore_ascii_case("true"),
+    None => false,
+  })
+}
@@ -0,0 +1,162 @@
+use chrono::Utc;
+use ring::digest::{digest, SHA1_FOR_LEGACY_USE_ONLY};
+use serde::Serialize;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::domain::entities::user::User;
+use crate::domain::error::Error;
+use crate::domain::repositories::user_repository::UserRepository;
+use crate::domain::value_objects::user_types::UserType;
+
+// Argon2 sobre entradas enormes es caro: el tope evita usar el registro como vector de DoS
+pub const MAX_PASSWORD_LENGTH: usize = 128;
+
+// Hashes SHA-1 de contraseñas comunes en formato de rangos "PREFIJO:SUFIJO", ordenados
+const COMMON_PASSWORDS: &str = include_str!("../../../resources/common_passwords.txt");
+
+// Regla incumplida; viaja tal cual en el cuerpo del error para que el cliente las muestre todas
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum PasswordViolation {
+  MinLength { min: usize },
+  MaxLength { max: usize },
+  CharacterClasses { required: usize },
+  CommonPassword,
+  RecentlyUsed { history: usize },
+}
+
+impl std::fmt::Display for PasswordViolation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      PasswordViolation::MinLength { min } => write!(f, "must be at least {} characters long", min),
+      PasswordViolation::MaxLength { max } => write!(f, "must be at most {} characters long", max),
+      PasswordViolation::CharacterClasses { required } => {
+        write!(f, "must mix at least {} of lowercase, uppercase, digits and symbols", required)
+      }
+      PasswordViolation::CommonPassword => write!(f, "is too common"),
+      PasswordViolation::RecentlyUsed { history } => write!(f, "must differ from the last {} passwords", history),
+    }
+  }
+}
+
+pub fn describe(violations: &[PasswordViolation]) -> String {
+  violations.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
+#[derive(Debug, Clone)]
+pub struct PasswordPolicy {
+  pub min_length: usize,
+  // De 1 a 4: minúsculas, mayúsculas, dígitos y el resto (símbolos, espacios...)
+  pub min_character_classes: usize,
+  // Cuántas contraseñas recientes, incluida la actual, no se pueden repetir; 0 lo desactiva
+  pub history: usize,
+  // Vigencia de la contraseña del personal; los clientes no caducan
+  pub staff_max_age: Option<Duration>,
+}
+
+impl PasswordPolicy {
+  pub fn new(min_length: usize, min_character_classes: usize, history: usize, staff_max_age: Option<Duration>) -> Self {
+    Self { min_length, min_character_classes, history, staff_max_age }
+  }
+
+  // Reglas que se pueden comprobar sin consultar el historial del usuario
+  pub fn violations(&self, password: &str) -> Vec<PasswordViolation> {
+    let mut violations = Vec::new();
+    let length = password.chars().count();
+
+    if length < self.min_length {
+      violations.push(PasswordViolation::MinLength { min: self.min_length });
+    }
+
+    if length > MAX_PASSWORD_LENGTH {
+      violations.push(PasswordViolation::MaxLength { max: MAX_PASSWORD_LENGTH });
+    }
+
+    if character_classes(password) < self.min_character_classes {
+      violations.push(PasswordViolation::CharacterClasses { required: self.min_character_classes });
+    }
+
+    if is_common(password) {
+      violations.push(PasswordViolation::CommonPassword);
+    }
+
+    violations
+  }
+
+  pub fn validate(&self, password: &str) -> Result<(), Error> {
+    let violations = self.violations(password);
+
+    if !violations.is_empty() {
+      return Err(Error::PasswordPolicy(violations));
+    }
+
+    Ok(())
+  }
+
+  // Para cambios y restablecimientos: además de las reglas básicas revisa el historial del usuario
+  pub async fn validate_change<U: UserRepository>(&self, user_repository: &U, user_id: &str, password: &str) -> Result<(), Error> {
+    let mut violations = self.violations(password);
+
+    if user_repository.is_recent_password(user_id.to_string(), password, self.history).await? {
+      violations.push(PasswordViolation::RecentlyUsed { history: self.history });
+    }
+
+    if !violations.is_empty() {
+      return Err(Error::PasswordPolicy(violations));
+    }
+
+    Ok(())
+  }
+
+  // Hashes anteriores que hay que conservar: la contraseña actual ya cuenta como una del historial
+  pub fn history_size(&self) -> usize {
+    self.history.saturating_sub(1)
+  }
+
+  // Las cuentas sin fecha de cambio cuentan desde el alta
+  pub fn is_expired(&self, user: &User) -> bool {
+    let Some(max_age) = self.staff_max_age.and_then(|max_age| chrono::Duration::from_std(max_age).ok()) else {
+      return false;
+    };
+
+    if user.user_type == UserType::Customer {
+      return false;
+    }
+
+    user.password_changed_at.unwrap_or(user.created_at) + max_age <= Utc::now()
+  }
+}
+
+fn character_classes(password: &str) -> usize {
+  let has_lowercase = password.chars().any(char::is_lowercase);
+  let has_uppercase = password.chars().any(char::is_uppercase);
+  let has_digit = password.chars().any(|c| c.is_ascii_digit());
+  let has_other = password.chars().any(|c| !c.is_lowercase() && !c.is_uppercase() && !c.is_ascii_digit());
+
+  [has_lowercase, has_uppercase, has_digit, has_other].into_iter().filter(|has| *has).count()
+}
+
+// Se compara sin distinguir mayúsculas: "Password" no es mejor que "password"
+fn is_common(password: &str) -> bool {
+  static RANGES: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+  let ranges = RANGES.get_or_init(|| {
+    let mut ranges: Vec<&str> = COMMON_PASSWORDS
+      .lines()
+      .map(str::trim)
+      .filter(|line| !line.is_empty() && !line.starts_with('#'))
+      .collect();
+    ranges.sort_unstable();
+    ranges
+  });
+
+  let hash: String = digest(&SHA1_FOR_LEGACY_USE_ONLY, password.to_lowercase().as_bytes())
+    .as_ref()
+    .iter()
+    .map(|byte| format!("{:02X}", byte))
+    .collect();
+  let (prefix, suffix) = hash.split_at(5);
+
+  ranges.binary_search(&format!("{}:{}", prefix, suffix).as_str()).is_ok()
+}
@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use crate::domain::entities::api_key::ApiKeyScope;
+use crate::domain::entities::permission::{Action, Permission, Resource};
+use crate::domain::error::Error;
+
+// Permiso requerido por una ruta o caso de uso, se declara como "users:manage" u "orders:approve"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredPermission {
+  pub resource: Resource,
+  pub action: Action,
+}
+
+impl RequiredPermission {
+  pub fn new(resource: Resource, action: Action) -> Self {
+    Self { resource, action }
+  }
+
+  // `Manage` sobre un recurso implica cualquier otra acción sobre ese mismo recurso
+  pub fn is_granted_by(&self, permission: &Permission) -> bool {
+    permission.is_active && self.is_covered_by(&permission.resource, &permission.action)
+  }
+
+  pub fn is_granted_by_scope(&self, scope: &ApiKeyScope) -> bool {
+    self.is_covered_by(&scope.resource, &scope.action)
+  }
+
+  fn is_covered_by(&self, resource: &Resource, action: &Action) -> bool {
+    *resource == self.resource && (*action == self.action || *action == Action::Manage)
+  }
+}
+
+impl FromStr for RequiredPermission {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (resource, action) = s.split_once(':')
+      .ok_or(Error::InvalidInput(format!("Invalid permission: {}", s)))?;
+
+    Ok(Self {
+      resource: Resource::from_str(resource).map_err(|_| Error::InvalidResource)?,
+      action: Action::from_str(action).map_err(|_| Error::InvalidAction)?,
+    })
+  }
+}
+
+impl std::fmt::Display for RequiredPermission {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}:{}", self.resource, self.action)
+  }
+}
+
+pub struct PermissionGuard;
+
+impl PermissionGuard {
+  pub fn is_granted(permissions: &[Permission], required: &RequiredPermission) -> bool {
+    permissions.iter().any(|permission| required.is_granted_by(permission))
+  }
+
+  pub fn check(permissions: &[Permission], required: &RequiredPermission) -> Result<(), Error> {
+    if Self::is_granted(permissions, required) {
+      return Ok(());
+    }
+
+    Err(Error::InvalidPermission(required.to_string()))
+  }
+
+  pub fn check_scopes(scopes: &[ApiKeyScope], required: &RequiredPermission) -> Result<(), Error> {
+    if scopes.iter().any(|scope| required.is_granted_by_scope(scope)) {
+      return Ok(());
+    }
+
+    Err(Error::InvalidPermission(required.to_string()))
+  }
+}
+
+impl From<RequiredPermission> for ApiKeyScope {
+  fn from(required: RequiredPermission) -> Self {
+    ApiKeyScope { resource: required.resource, action: required.action }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::domain::entities::permission::{Permission, Resource};
+use crate::domain::error::Error;
+
+const POS_RESOURCES: [Resource; 3] = [Resource::Orders, Resource::Menu, Resource::Transactions];
+const PIN_MIN_LENGTH: usize = 4;
+const PIN_MAX_LENGTH: usize = 6;
+
+// Reglas de las sesiones abiertas con PIN en un terminal compartido
+pub struct PosScope;
+
+impl PosScope {
+  // Una sesión POS solo conserva los permisos sobre pedidos, menú y cobros, aunque el rol tenga más
+  pub fn restrict(permissions: Vec<Permission>) -> Vec<Permission> {
+    permissions
+      .into_iter()
+      .filter(|permission| POS_RESOURCES.contains(&permission.resource))
+      .collect()
+  }
+
+  pub fn validate_pin(pin: &str) -> Result<(), Error> {
+    let is_valid = (PIN_MIN_LENGTH..=PIN_MAX_LENGTH).contains(&pin.len())
+      && pin.chars().all(|c| c.is_ascii_digit());
+
+    if !is_valid {
+      return Err(Error::ValidationError(format!("PIN must have {} to {} digits", PIN_MIN_LENGTH, PIN_MAX_LENGTH)));
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::domain::error::Error;
+
+// Token bucket: admite ráfagas de hasta `capacity` peticiones y se rellena por completo en `period`.
+// Se escribe como "peticiones/segundos", p. ej. "5/300" son 5 peticiones cada 5 minutos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+  pub capacity: u32,
+  pub period: Duration,
+}
+
+impl RateLimit {
+  pub fn new(capacity: u32, period: Duration) -> Self {
+    Self { capacity, period }
+  }
+
+  // Tokens que se recuperan por segundo
+  pub fn refill_rate(&self) -> f64 {
+    self.capacity as f64 / self.period.as_secs_f64()
+  }
+}
+
+impl std::str::FromStr for RateLimit {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || format!("Invalid rate limit {:?}: expected \"<requests>/<seconds>\"", s);
+    let (capacity, seconds) = s.trim().split_once('/').ok_or_else(invalid)?;
+    let capacity: u32 = capacity.trim().parse().map_err(|_| invalid())?;
+    let seconds: u64 = seconds.trim().parse().map_err(|_| invalid())?;
+
+    if capacity == 0 || seconds == 0 {
+      return Err(invalid());
+    }
+
+    Ok(Self::new(capacity, Duration::from_secs(seconds)))
+  }
+}
+
+// Límites de un grupo de rutas; cada criterio es independiente y basta con que uno se agote
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitRules {
+  pub per_ip: Option<RateLimit>,
+  pub per_email: Option<RateLimit>,
+  pub per_api_key: Option<RateLimit>,
+}
+
+// Grupos de rutas con límites propios; el nombre separa sus buckets en el almacén
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitScope {
+  Login,
+  Register,
+  PasswordReset,
+  ApiKeys,
+}
+
+impl RateLimitScope {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      RateLimitScope::Login => "login",
+      RateLimitScope::Register => "register",
+      RateLimitScope::PasswordReset => "password_reset",
+      RateLimitScope::ApiKeys => "api_keys",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RateLimitDecision {
+  Allowed,
+  Limited { retry_after: Duration },
+}
+
+// Almacén de buckets. El de memoria vale para una sola instancia; con varias hace falta uno compartido
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+  // Consume un token del bucket `key`, creándolo lleno si no existe
+  async fn acquire(&self, key: &str, limit: &RateLimit) -> Result<RateLimitDecision, Error>;
+}
@@ -0,0 +1,39 @@
+use crate::domain::entities::role::Role;
+use crate::domain::value_objects::user_types::UserType;
+
+// Jerarquía de roles: un número menor manda más (super_admin = 1, admin = 2, y customer = 100, por
+// debajo de todo el personal). Nadie actúa sobre roles ni usuarios de su nivel o superiores. Con la
+// herencia activada en el restaurante, cada rol de personal tiene además los permisos de los roles
+// propios del restaurante que quedan por debajo.
+pub struct RoleHierarchy;
+
+impl RoleHierarchy {
+  // Nivel más alto entre los roles; None sin roles
+  pub fn level<'a>(roles: impl IntoIterator<Item = &'a Role>) -> Option<i32> {
+    roles.into_iter().map(|role| role.hierarchy_level).min()
+  }
+
+  // Nivel desde el que se hereda: solo cuentan los roles propios del restaurante, los de la
+  // plataforma nunca heredan
+  pub fn inheritance_level<'a>(roles: impl IntoIterator<Item = &'a Role>) -> Option<i32> {
+    Self::level(roles.into_iter().filter(|role| role.restaurant_id.is_some()))
+  }
+
+  // Roles del restaurante cuyos permisos hereda quien está en `level`
+  pub fn inherited(level: i32, roles: &[Role]) -> Vec<&Role> {
+    roles.iter()
+      .filter(|role| role.restaurant_id.is_some() && role.is_active && role.hierarchy_level > level)
+      .collect()
+  }
+
+  // Rol de la plataforma que recibe cada tipo de usuario al darse de alta; el personal recibe
+  // los suyos después
+  pub fn user_type_role(user_type: &UserType) -> Option<&'static str> {
+    match user_type {
+      UserType::SuperAdmin => Some("super_admin"),
+      UserType::Admin => Some("admin"),
+      UserType::Customer => Some("customer"),
+      UserType::Employee => None,
+    }
+  }
+}
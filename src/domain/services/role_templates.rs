@@ -0,0 +1,82 @@
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::services::permission_guard::RequiredPermission;
+
+// Rol que recibe cada restaurante nuevo, con sus permisos iniciales. El restaurante puede
+// cambiarlos después: las copias son suyas y no afectan a los demás.
+#[derive(Debug, Clone)]
+pub struct RoleTemplate {
+  pub name: &'static str,
+  pub description: &'static str,
+  pub hierarchy_level: i32,
+  pub permissions: Vec<RequiredPermission>,
+}
+
+impl RoleTemplate {
+  fn new(name: &'static str, description: &'static str, hierarchy_level: i32, permissions: &[(Resource, Action)]) -> Self {
+    Self {
+      name,
+      description,
+      hierarchy_level,
+      permissions: permissions.iter()
+        .map(|(resource, action)| RequiredPermission::new(resource.clone(), action.clone()))
+        .collect(),
+    }
+  }
+}
+
+// Rol con el que el dueño gestiona todo su restaurante
+pub const OWNER_ROLE: &str = "restaurant_manager";
+
+// Plantilla por nombre, para crear un rol propio a partir de ella
+pub fn find_role_template(name: &str) -> Option<RoleTemplate> {
+  default_role_templates().into_iter().find(|template| template.name == name)
+}
+
+// Roles del personal de cada restaurante; por debajo de `admin` (2) en la jerarquía
+pub fn default_role_templates() -> Vec<RoleTemplate> {
+  vec![
+    RoleTemplate::new(OWNER_ROLE, "Restaurant manager with full access to the restaurant", 3, &[
+      (Resource::Users, Action::Manage),
+      (Resource::Employees, Action::Manage),
+      (Resource::Orders, Action::Manage),
+      (Resource::Menu, Action::Manage),
+      (Resource::Inventory, Action::Manage),
+      (Resource::Transactions, Action::Manage),
+      (Resource::Reports, Action::Manage),
+      (Resource::Settings, Action::Manage),
+    ]),
+    RoleTemplate::new("head_chef", "Head chef in charge of the kitchen and the menu", 4, &[
+      (Resource::Menu, Action::Manage),
+      (Resource::Orders, Action::Read),
+      (Resource::Orders, Action::Update),
+      (Resource::Inventory, Action::Read),
+      (Resource::Inventory, Action::Update),
+      (Resource::Reports, Action::Read),
+    ]),
+    RoleTemplate::new("inventory_manager", "Inventory manager in charge of stock and suppliers", 4, &[
+      (Resource::Inventory, Action::Manage),
+      (Resource::Menu, Action::Read),
+      (Resource::Reports, Action::Read),
+    ]),
+    RoleTemplate::new("chef", "Chef preparing orders", 5, &[
+      (Resource::Orders, Action::Read),
+      (Resource::Orders, Action::Update),
+      (Resource::Menu, Action::Read),
+      (Resource::Inventory, Action::Read),
+    ]),
+    RoleTemplate::new("cashier", "Cashier charging orders", 5, &[
+      (Resource::Orders, Action::Create),
+      (Resource::Orders, Action::Read),
+      (Resource::Orders, Action::Cancel),
+      (Resource::Transactions, Action::Create),
+      (Resource::Transactions, Action::Read),
+      (Resource::Menu, Action::Read),
+    ]),
+    RoleTemplate::new("waiter", "Waiter taking orders at the tables", 6, &[
+      (Resource::Orders, Action::Create),
+      (Resource::Orders, Action::Read),
+      (Resource::Orders, Action::Update),
+      (Resource::Menu, Action::Read),
+    ]),
+  ]
+}
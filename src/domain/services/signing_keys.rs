@@ -0,0 +1,141 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::{
+  AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+  OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use ring::rsa::PublicKeyComponents;
+use ring::signature::{Ed25519KeyPair, KeyPair, RsaKeyPair};
+
+use crate::domain::error::Error;
+
+// Clave asimétrica para firmar access tokens; solo la parte pública sale por el JWKS
+#[derive(Clone)]
+pub struct SigningKey {
+  pub kid: String,
+  pub algorithm: Algorithm,
+  pub retired: bool,
+  encoding_key: EncodingKey,
+  decoding_key: DecodingKey,
+  jwk: Jwk,
+}
+
+impl std::fmt::Debug for SigningKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SigningKey")
+      .field("kid", &self.kid)
+      .field("algorithm", &self.algorithm)
+      .field("retired", &self.retired)
+      .finish()
+  }
+}
+
+impl SigningKey {
+  // Acepta la clave privada en PEM (PKCS#8, o PKCS#1 para RSA) y deriva de ella la clave pública
+  pub fn from_pem(kid: String, algorithm: Algorithm, private_pem: &[u8], retired: bool) -> Result<Self, Error> {
+    let invalid = |e: String| Error::ConfigError(format!("Invalid signing key {}: {}", kid, e));
+    let der = pem::parse(private_pem).map_err(|e| invalid(e.to_string()))?;
+
+    let (encoding_key, decoding_key, parameters, key_algorithm) = match algorithm {
+      Algorithm::RS256 => {
+        let key_pair = RsaKeyPair::from_pkcs8(der.contents())
+          .or_else(|_| RsaKeyPair::from_der(der.contents()))
+          .map_err(|e| invalid(e.to_string()))?;
+        let components = PublicKeyComponents::<Vec<u8>>::from(key_pair.public());
+        let n = URL_SAFE_NO_PAD.encode(&components.n);
+        let e = URL_SAFE_NO_PAD.encode(&components.e);
+
+        (
+          EncodingKey::from_rsa_pem(private_pem).map_err(|e| invalid(e.to_string()))?,
+          DecodingKey::from_rsa_components(&n, &e).map_err(|e| invalid(e.to_string()))?,
+          AlgorithmParameters::RSA(RSAKeyParameters { key_type: RSAKeyType::RSA, n, e }),
+          KeyAlgorithm::RS256,
+        )
+      }
+      Algorithm::EdDSA => {
+        let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(der.contents())
+          .map_err(|e| invalid(e.to_string()))?;
+        let x = URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref());
+
+        (
+          EncodingKey::from_ed_pem(private_pem).map_err(|e| invalid(e.to_string()))?,
+          DecodingKey::from_ed_components(&x).map_err(|e| invalid(e.to_string()))?,
+          AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+            key_type: OctetKeyPairType::OctetKeyPair,
+            curve: EllipticCurve::Ed25519,
+            x,
+          }),
+          KeyAlgorithm::EdDSA,
+        )
+      }
+      other => return Err(invalid(format!("unsupported algorithm {:?}, use RS256 or EdDSA", other))),
+    };
+
+    let jwk = Jwk {
+      common: CommonParameters {
+        public_key_use: Some(PublicKeyUse::Signature),
+        key_algorithm: Some(key_algorithm),
+        key_id: Some(kid.clone()),
+        ..Default::default()
+      },
+      algorithm: parameters,
+    };
+
+    Ok(Self { kid, algorithm, retired, encoding_key, decoding_key, jwk })
+  }
+
+  pub fn encoding_key(&self) -> &EncodingKey {
+    &self.encoding_key
+  }
+
+  pub fn decoding_key(&self) -> &DecodingKey {
+    &self.decoding_key
+  }
+}
+
+// Juego de claves identificadas por `kid`: una activa para firmar y el resto solo para verificar
+// mientras no estén retiradas. Vacío significa que se sigue firmando con el secreto HS256.
+#[derive(Debug, Clone, Default)]
+pub struct SigningKeys {
+  active_kid: Option<String>,
+  keys: Vec<SigningKey>,
+}
+
+impl SigningKeys {
+  pub fn new(active_kid: String, keys: Vec<SigningKey>) -> Result<Self, Error> {
+    for (index, key) in keys.iter().enumerate() {
+      if keys[..index].iter().any(|other| other.kid == key.kid) {
+        return Err(Error::ConfigError(format!("Duplicated signing key id: {}", key.kid)));
+      }
+    }
+
+    match keys.iter().find(|key| key.kid == active_kid) {
+      Some(key) if key.retired => {
+        Err(Error::ConfigError(format!("Active signing key {} is retired", active_kid)))
+      }
+      Some(_) => Ok(Self { active_kid: Some(active_kid), keys }),
+      None => Err(Error::ConfigError(format!("Active signing key {} not found", active_kid))),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.keys.is_empty()
+  }
+
+  pub fn active(&self) -> Option<&SigningKey> {
+    let active_kid = self.active_kid.as_ref()?;
+    self.keys.iter().find(|key| &key.kid == active_kid)
+  }
+
+  // Las claves retiradas ya no validan tokens aunque sigan en la configuración
+  pub fn verifying(&self, kid: &str) -> Option<&SigningKey> {
+    self.keys.iter().find(|key| key.kid == kid && !key.retired)
+  }
+
+  pub fn jwks(&self) -> JwkSet {
+    JwkSet {
+      keys: self.keys.iter().filter(|key| !key.retired).map(|key| key.jwk.clone()).collect(),
+    }
+  }
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::error::Error;
+
+// Ciclo de vida de la base dedicada de un restaurante. Los restaurantes sin base propia
+// comparten la de la plataforma y devuelven `TenantNotDedicated`.
+#[async_trait]
+pub trait TenantDatabaseProvisioner: Send + Sync {
+  // Crea la base con el esquema de la plataforma y empieza a enrutarle las peticiones del restaurante
+  async fn provision(&self, restaurant: &Restaurant) -> Result<(), Error>;
+  // Deja de enrutar al restaurante y borra la base con todos sus datos
+  async fn deprovision(&self, restaurant: &Restaurant) -> Result<(), Error>;
+  // Volcado SurrealQL completo de la base
+  async fn export(&self, restaurant: &Restaurant) -> Result<Vec<u8>, Error>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::location::Location;
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::entities::user::User;
+use crate::domain::error::Error;
+use crate::domain::services::role_templates::RoleTemplate;
+
+// Datos iniciales de un restaurante recién dado de alta
+#[async_trait]
+pub trait TenantOnboarding: Send + Sync {
+  // Crea en una sola transacción la primera sucursal, el dueño asignado a ella, los roles de
+  // `roles` con sus permisos y las asignaciones de `owner_roles` (de la plataforma o recién creados).
+  // Si algo falla no queda nada a medias. Devuelve el dueño y la sucursal ya guardados.
+  async fn onboard(
+    &self,
+    restaurant: &Restaurant,
+    location: &Location,
+    owner: &User,
+    roles: &[RoleTemplate],
+    owner_roles: &[&str],
+  ) -> Result<(User, Location), Error>;
+}
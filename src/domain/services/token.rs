@@ -1,20 +1,37 @@
-use jsonwebtoken::{encode, decode, Header, Validation, EncodingKey, DecodingKey};
+use jsonwebtoken::{encode, decode, decode_header, Header, Validation, EncodingKey, DecodingKey};
+use jsonwebtoken::jwk::JwkSet;
+use std::sync::Arc;
 use std::time::Duration;
 use serde::{Serialize, Deserialize};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::entities::token::RefreshToken;
+use crate::domain::entities::token::{AccessToken, RefreshToken, TokenPair};
+use crate::domain::entities::one_time_token::{OneTimeToken, TokenPurpose};
+use crate::domain::entities::revoked_token::RevokedToken;
 use crate::domain::entities::user::User;
 use crate::domain::error::Error;
+use crate::domain::services::pos::PosScope;
+use crate::domain::services::signing_keys::SigningKeys;
+use crate::domain::value_objects::client_info::ClientInfo;
 
-#[derive(Debug)]
-pub struct TokenPair {
-  pub access_token: String,
-  pub refresh_token: RefreshToken,
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenScope {
+  // Sesión abierta con PIN en un terminal compartido
+  Pos,
+  // Un super admin actuando como otro usuario; el claim `act` dice quién
+  Impersonation,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+// Quien actúa realmente en nombre de `sub` (claim `act` de RFC 8693)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActorClaims {
+  pub sub: String,
+  pub email: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenClaims {
   pub sub: String,         // user id
   pub email: String,
@@ -22,14 +39,53 @@ pub struct TokenClaims {
   pub permissions: Option<Vec<String>>,
   pub exp: i64,           // expiration timestamp
   pub iat: i64,           // issued at timestamp
+  // Permite revocar el token antes de que venza; los tokens anteriores a la lista de revocación no lo traen
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub jti: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub scope: Option<TokenScope>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub device_id: Option<String>,
+  // Sesión (familia de refresh tokens) a la que pertenece el access token
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sid: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub act: Option<ActorClaims>,
+  // Restaurante (tenant) del usuario; sin él el token es de la plataforma
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tid: Option<String>,
+  // Sucursal en la que trabaja la sesión; los roles asignados en ella solo valen con este claim
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub lid: Option<String>,
+}
+
+// Claims de los tokens de un solo uso; `aud` lleva el propósito para que no se puedan usar como access token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OneTimeTokenClaims {
+  pub sub: String,
+  pub jti: String,
+  pub aud: String,
+  pub exp: i64,
+  pub iat: i64,
+  // Restaurante del usuario: los enlaces del correo no pasan por el tenant de la petición
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tid: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct TokenConfig {
+  // Firma los tokens de un solo uso y, si no hay claves asimétricas configuradas, también los access tokens
   pub access_token_secret: String,
+  pub signing_keys: Arc<SigningKeys>,
   //pub refresh_token_secret: String,
   pub access_token_duration: Duration,
   pub refresh_token_duration: Duration,
+  pub verification_token_duration: Duration,
+  pub verification_resend_cooldown: Duration,
+  pub password_reset_token_duration: Duration,
+  pub mfa_token_duration: Duration,
+  pub pos_token_duration: Duration,
+  pub impersonation_token_duration: Duration,
 }
 
 #[derive(Debug, Clone)]
@@ -42,17 +98,30 @@ impl TokenService {
     Self { config }
   }
 
-  pub fn generate_token_pair(&self, user: &User) -> Result<TokenPair, Error> {
-    let access_token = self.generate_access_token(user)?;
-    let refresh_token = self.generate_refresh_token(user)?;
+  // Abre una sesión nueva
+  pub fn generate_token_pair(&self, user: &User, client: &ClientInfo) -> Result<TokenPair, Error> {
+    let refresh_token = self.generate_refresh_token(user, client)?;
+    let access_token = self.generate_access_token(user, Some(refresh_token.session_id.clone()), None)?;
     
-    Ok(TokenPair {
-      access_token,
-      refresh_token,
-    })
+    Ok(TokenPair::new(access_token, refresh_token))
+  }
+
+  // Renueva una sesión existente: el refresh token cambia pero la sesión sigue siendo la misma
+  pub fn rotate_token_pair(&self, user: &User, previous: &RefreshToken, client: &ClientInfo) -> Result<TokenPair, Error> {
+    let refresh_token = RefreshToken {
+      session_id: previous.session_id.clone(),
+      session_started_at: previous.session_started_at,
+      device_name: previous.device_name.clone(),
+      location_id: previous.location_id.clone(),
+      ..self.generate_refresh_token(user, client)?
+    }
+    .with_client(client);
+    let access_token = self.generate_access_token(user, Some(refresh_token.session_id.clone()), refresh_token.location_id.clone())?;
+
+    Ok(TokenPair::new(access_token, refresh_token))
   }
 
-  pub fn generate_access_token(&self, user: &User) -> Result<String, Error> {
+  pub fn generate_access_token(&self, user: &User, session_id: Option<String>, location_id: Option<String>) -> Result<AccessToken, Error> {
     let expiration = Utc::now() + self.config.access_token_duration;
 
     let claims = TokenClaims {
@@ -62,17 +131,75 @@ impl TokenService {
       permissions: user.permissions.as_ref().map(|p| p.iter().map(|perm| perm.name.clone()).collect()),
       exp: expiration.timestamp(),
       iat: Utc::now().timestamp(),
+      jti: Some(Uuid::new_v4().to_string()),
+      scope: None,
+      device_id: None,
+      sid: session_id,
+      act: None,
+      tid: tenant_id(user),
+      lid: location_id,
     };
   
-    encode(
-      &Header::default(),
-      &claims,
-      &EncodingKey::from_secret(self.config.access_token_secret.as_bytes()),
-    )
-    .map_err(|e| Error::TokenGenerationError(e.to_string()))
+    let token = self.encode_access_claims(&claims)?;
+
+    Ok(AccessToken { token, expires_at: expiration })
+  }
+
+  // Token corto y sin refresh para un terminal POS; los permisos se recortan al alcance POS
+  pub fn generate_pos_access_token(&self, user: &User, device_id: String) -> Result<AccessToken, Error> {
+    let expiration = Utc::now() + self.config.pos_token_duration;
+    let permissions = PosScope::restrict(user.permissions.clone().unwrap_or_default());
+
+    let claims = TokenClaims {
+      sub: user.id.clone().ok_or(Error::UserNotFound)?.id.to_string(),
+      email: user.email.clone(),
+      roles: user.roles.as_ref().map(|r| r.iter().map(|role| role.name.clone()).collect()).unwrap_or_default(),
+      permissions: Some(permissions.iter().map(|perm| perm.name.clone()).collect()),
+      exp: expiration.timestamp(),
+      iat: Utc::now().timestamp(),
+      jti: Some(Uuid::new_v4().to_string()),
+      scope: Some(TokenScope::Pos),
+      device_id: Some(device_id),
+      sid: None,
+      act: None,
+      tid: tenant_id(user),
+      lid: None,
+    };
+
+    let token = self.encode_access_claims(&claims)?;
+
+    Ok(AccessToken { token, expires_at: expiration })
+  }
+
+  // Token corto y sin refresh con los permisos de `subject`; al vencer hay que volver a pedirlo
+  pub fn generate_impersonation_token(&self, subject: &User, impersonator: &User) -> Result<AccessToken, Error> {
+    let expiration = Utc::now() + self.config.impersonation_token_duration;
+
+    let claims = TokenClaims {
+      sub: subject.id.clone().ok_or(Error::UserNotFound)?.id.to_string(),
+      email: subject.email.clone(),
+      roles: subject.roles.as_ref().map(|r| r.iter().map(|role| role.name.clone()).collect()).unwrap_or_default(),
+      permissions: subject.permissions.as_ref().map(|p| p.iter().map(|perm| perm.name.clone()).collect()),
+      exp: expiration.timestamp(),
+      iat: Utc::now().timestamp(),
+      jti: Some(Uuid::new_v4().to_string()),
+      scope: Some(TokenScope::Impersonation),
+      device_id: None,
+      sid: None,
+      act: Some(ActorClaims {
+        sub: impersonator.id.clone().ok_or(Error::UserNotFound)?.id.to_string(),
+        email: impersonator.email.clone(),
+      }),
+      tid: tenant_id(subject),
+      lid: None,
+    };
+
+    let token = self.encode_access_claims(&claims)?;
+
+    Ok(AccessToken { token, expires_at: expiration })
   }
 
-  pub fn generate_refresh_token(&self, user: &User) -> Result<RefreshToken, Error> {
+  pub fn generate_refresh_token(&self, user: &User, client: &ClientInfo) -> Result<RefreshToken, Error> {
     let refresh_token = RefreshToken {
       id: None,
       user_id: user.id.clone().unwrap(),
@@ -82,15 +209,96 @@ impl TokenService {
       expires_at: Utc::now() + self.config.refresh_token_duration,
       token: Uuid::new_v4().to_string(),
       created_at: Utc::now(),
+      session_id: Uuid::new_v4().to_string(),
+      session_started_at: Utc::now(),
+      device_name: client.device_name.clone(),
+      ip_address: client.ip_address.clone(),
+      user_agent: client.user_agent.clone(),
+      last_used_at: Utc::now(),
+      location_id: None,
     };
 
     Ok(refresh_token)
   }
 
   pub fn verify_access_token(&self, token: &str) -> Result<TokenClaims, Error> {
-    let validation = Validation::default();
+    let header = decode_header(token).map_err(|_| Error::InvalidToken)?;
 
-    let claims = decode::<TokenClaims>(
+    // Con claves asimétricas el `kid` elige la clave y su algoritmo; sin él solo vale el modo HS256
+    let claims = match header.kid {
+      Some(kid) => {
+        let key = self.config.signing_keys.verifying(&kid).ok_or(Error::InvalidToken)?;
+        decode::<TokenClaims>(token, key.decoding_key(), &Validation::new(key.algorithm))
+      }
+      None if self.config.signing_keys.is_empty() => decode::<TokenClaims>(
+        token,
+        &DecodingKey::from_secret(self.config.access_token_secret.as_bytes()),
+        &Validation::default(),
+      ),
+      None => return Err(Error::InvalidToken),
+    }
+    .map_err(|e| match e.kind() {
+      jsonwebtoken::errors::ErrorKind::ExpiredSignature => Error::TokenExpired,
+      _ => Error::InvalidToken,
+    })?;
+
+    Ok(claims.claims)
+  }
+
+  // Claves públicas vigentes para que otros servicios validen los access tokens por su cuenta
+  pub fn jwks(&self) -> JwkSet {
+    self.config.signing_keys.jwks()
+  }
+
+  fn encode_access_claims(&self, claims: &TokenClaims) -> Result<String, Error> {
+    match self.config.signing_keys.active() {
+      Some(key) => {
+        let mut header = Header::new(key.algorithm);
+        header.kid = Some(key.kid.clone());
+        encode(&header, claims, key.encoding_key())
+      }
+      None => encode(
+        &Header::default(),
+        claims,
+        &EncodingKey::from_secret(self.config.access_token_secret.as_bytes()),
+      ),
+    }
+    .map_err(|e| Error::TokenGenerationError(e.to_string()))
+  }
+
+  pub fn generate_one_time_token(&self, user: &User, purpose: TokenPurpose) -> Result<(String, OneTimeToken), Error> {
+    let user_id = user.id.clone().ok_or(Error::UserNotFound)?;
+    let duration = match purpose {
+      TokenPurpose::EmailVerification => self.config.verification_token_duration,
+      TokenPurpose::PasswordReset => self.config.password_reset_token_duration,
+      TokenPurpose::MfaPending => self.config.mfa_token_duration,
+    };
+    let expiration = Utc::now() + duration;
+
+    let claims = OneTimeTokenClaims {
+      sub: user_id.id.to_string(),
+      jti: Uuid::new_v4().to_string(),
+      aud: purpose.to_string(),
+      exp: expiration.timestamp(),
+      iat: Utc::now().timestamp(),
+      tid: tenant_id(user),
+    };
+
+    let token = encode(
+      &Header::default(),
+      &claims,
+      &EncodingKey::from_secret(self.config.access_token_secret.as_bytes()),
+    )
+    .map_err(|e| Error::TokenGenerationError(e.to_string()))?;
+
+    Ok((token, OneTimeToken::new(user_id, claims.jti, purpose, expiration)))
+  }
+
+  pub fn verify_one_time_token(&self, token: &str, purpose: TokenPurpose) -> Result<OneTimeTokenClaims, Error> {
+    let mut validation = Validation::default();
+    validation.set_audience(&[purpose.to_string()]);
+
+    let claims = decode::<OneTimeTokenClaims>(
       token,
       &DecodingKey::from_secret(self.config.access_token_secret.as_bytes()),
       &validation,
@@ -103,7 +311,28 @@ impl TokenService {
     Ok(claims.claims)
   }
 
+  // Revoca solo este access token; la entrada vive lo mismo que el token
+  pub fn revocation_for_token(&self, claims: &TokenClaims) -> Option<RevokedToken> {
+    let jti = claims.jti.clone()?;
+    let expires_at = DateTime::from_timestamp(claims.exp, 0)?;
+
+    Some(RevokedToken::for_token(jti, &claims.sub, expires_at))
+  }
+
+  // Revoca todos los access tokens ya emitidos al usuario; basta con cubrir la vida del token más largo
+  pub fn revocation_for_user(&self, user_id: &str) -> RevokedToken {
+    let longest = self.config.access_token_duration
+      .max(self.config.pos_token_duration)
+      .max(self.config.impersonation_token_duration);
+
+    RevokedToken::for_user(user_id, Utc::now() + longest)
+  }
+
   pub fn is_token_expired(&self, claims: &TokenClaims) -> bool {
     claims.exp < Utc::now().timestamp()
   }
+}
+
+fn tenant_id(user: &User) -> Option<String> {
+  user.restaurant_id.as_ref().map(|restaurant| restaurant.id.to_string())
 }
\ No newline at end of file
@@ -0,0 +1,81 @@
+use chrono::Utc;
+use totp_rs::{Algorithm, Secret, TOTP};
+use uuid::Uuid;
+
+use crate::domain::error::Error;
+
+const DIGITS: usize = 6;
+const STEP_SECONDS: i64 = 30;
+const RECOVERY_CODES: usize = 10;
+
+#[derive(Debug, Clone)]
+pub struct TotpConfig {
+  pub issuer: String,
+  // Pasos de 30s aceptados antes y después del actual para tolerar relojes desfasados
+  pub allowed_skew: i64,
+}
+
+// TOTP según RFC 6238 (SHA1, 6 dígitos, 30 segundos), compatible con las apps de autenticación habituales
+#[derive(Debug, Clone)]
+pub struct TotpService {
+  config: TotpConfig,
+}
+
+impl TotpService {
+  pub fn new(config: TotpConfig) -> Self {
+    Self { config }
+  }
+
+  pub fn generate_secret(&self) -> String {
+    Secret::generate_secret().to_encoded().to_string()
+  }
+
+  // URI `otpauth://` que la app de autenticación lee desde el código QR
+  pub fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String, Error> {
+    Ok(self.totp(secret, account_name)?.get_url())
+  }
+
+  // Devuelve el paso de tiempo que coincide con el código, ignorando los ya usados
+  pub fn verify(&self, secret: &str, code: &str, last_used_step: Option<i64>) -> Result<Option<i64>, Error> {
+    let totp = self.totp(secret, "")?;
+    let code = code.trim();
+    let current_step = Utc::now().timestamp() / STEP_SECONDS;
+
+    let step = (-self.config.allowed_skew..=self.config.allowed_skew)
+      .map(|offset| current_step + offset)
+      .filter(|step| last_used_step.is_none_or(|last| *step > last))
+      .find(|step| totp.check(code, (*step * STEP_SECONDS) as u64));
+
+    Ok(step)
+  }
+
+  pub fn generate_recovery_codes(&self) -> Vec<String> {
+    (0..RECOVERY_CODES)
+      .map(|_| {
+        let raw = Uuid::new_v4().simple().to_string();
+        format!("{}-{}", &raw[..5], &raw[5..10])
+      })
+      .collect()
+  }
+
+  pub fn normalize_recovery_code(code: &str) -> String {
+    code.trim().to_lowercase()
+  }
+
+  fn totp(&self, secret: &str, account_name: &str) -> Result<TOTP, Error> {
+    let secret = Secret::Encoded(secret.to_string())
+      .to_bytes()
+      .map_err(|e| Error::InfrastructureError(format!("Invalid TOTP secret: {:?}", e)))?;
+
+    TOTP::new(
+      Algorithm::SHA1,
+      DIGITS,
+      0,
+      STEP_SECONDS as u64,
+      secret,
+      Some(self.config.issuer.clone()),
+      account_name.to_string(),
+    )
+    .map_err(|e| Error::InfrastructureError(format!("Invalid TOTP parameters: {}", e)))
+  }
+}
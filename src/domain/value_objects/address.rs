@@ -10,6 +10,7 @@ pub struct Address {
 }
 
 impl Address {
+  #[allow(dead_code)]
   pub fn new(street: String, city: String, state: String, country: String, postal_code: String) -> Self {
     Self { street, city, state, country, postal_code }
   }
@@ -0,0 +1,11 @@
+use chrono::{NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+// Turno de un día, ej. {"day": "mon", "opens_at": "09:00:00", "closes_at": "22:00:00"}.
+// Un día puede tener varios turnos; si cierra antes de abrir, el turno termina pasada la medianoche.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BusinessHours {
+  pub day: Weekday,
+  pub opens_at: NaiveTime,
+  pub closes_at: NaiveTime,
+}
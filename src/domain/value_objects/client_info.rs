@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+// Largo máximo que se guarda de cada dato; vienen del cliente y no se validan
+const MAX_LENGTH: usize = 255;
+
+// Datos del dispositivo que abre o renueva una sesión, se muestran en el listado de sesiones
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientInfo {
+  pub device_name: Option<String>,
+  pub ip_address: Option<String>,
+  pub user_agent: Option<String>,
+}
+
+impl ClientInfo {
+  pub fn new(device_name: Option<&str>, ip_address: Option<&str>, user_agent: Option<&str>) -> Self {
+    Self {
+      device_name: clean(device_name),
+      ip_address: clean(ip_address),
+      user_agent: clean(user_agent),
+    }
+  }
+}
+
+fn clean(value: Option<&str>) -> Option<String> {
+  value
+    .map(str::trim)
+    .filter(|value| !value.is_empty())
+    .map(|value| value.chars().take(MAX_LENGTH).collect())
+}
@@ -8,6 +8,7 @@ pub struct EmergencyContact {
 }
 
 impl EmergencyContact {
+  #[allow(dead_code)]
   pub fn new(name: String, phone: String, relationship: String) -> Self {
     Self { name, phone, relationship }
   }
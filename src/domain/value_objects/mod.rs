@@ -2,3 +2,7 @@ pub mod address;
 pub mod emergency_contact;
 pub mod user_status;
 pub mod user_types;
+
+pub mod client_info;
+pub mod tenant;
+pub mod business_hours;
\ No newline at end of file
@@ -0,0 +1,38 @@
+use surrealdb::sql::Thing;
+
+use crate::domain::entities::restaurant::Restaurant;
+
+const RESTAURANTS_TABLE: &str = "restaurants";
+
+// Tenant sobre el que trabaja una petición; sin restaurante es el ámbito de la plataforma
+// (super admins y datos del sistema). Los repositorios con tenant filtran todo por él.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TenantContext {
+  restaurant: Option<Thing>,
+}
+
+impl TenantContext {
+  pub fn platform() -> Self {
+    Self { restaurant: None }
+  }
+
+  // Id sin el nombre de la tabla, tal como viaja en el claim `tid`
+  pub fn from_restaurant_id(restaurant_id: Option<&str>) -> Self {
+    Self { restaurant: restaurant_id.map(|id| Thing::from((RESTAURANTS_TABLE, id))) }
+  }
+
+  pub fn is_platform(&self) -> bool {
+    self.restaurant.is_none()
+  }
+
+  // Valor con el que se filtra y se guarda `restaurant_id`; NONE en el ámbito de la plataforma
+  pub fn record(&self) -> Option<Thing> {
+    self.restaurant.clone()
+  }
+}
+
+impl From<&Restaurant> for TenantContext {
+  fn from(restaurant: &Restaurant) -> Self {
+    Self { restaurant: restaurant.id.clone() }
+  }
+}
@@ -1,5 +1,19 @@
 use crate::domain::error::Error;
 use crate::domain::services::token::TokenConfig;
+use crate::domain::services::signing_keys::{SigningKey, SigningKeys};
+use crate::domain::services::lockout::LockoutPolicy;
+use crate::domain::services::mfa_policy::MfaPolicy;
+use crate::domain::services::oidc::OidcConfig;
+use crate::domain::services::password_policy::PasswordPolicy;
+use crate::domain::services::rate_limit::{RateLimit, RateLimitRules, RateLimitScope};
+use crate::domain::services::totp::TotpConfig;
+use crate::domain::value_objects::user_types::UserType;
+use crate::infrastructure::scheduler::schedule::CronSchedule;
+use jsonwebtoken::Algorithm;
+use serde::Deserialize;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[derive(Debug, Clone)]
@@ -13,11 +27,88 @@ pub struct DatabaseConfig {
 pub struct ServerConfig {
   pub host: String,
   pub port: u16,
+  // Proxies cuyo `X-Forwarded-For` se cree; de cualquier otro origen la IP es la del socket
+  pub trusted_proxies: Vec<IpAddr>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MailerDriver {
+  Smtp,
+  Log,
+}
+
+impl std::str::FromStr for MailerDriver {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(match s {
+      "smtp" => MailerDriver::Smtp,
+      "log" => MailerDriver::Log,
+      _ => return Err(format!("Invalid mailer driver: {}", s)),
+    })
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct MailerConfig {
+  pub driver: MailerDriver,
+  pub from: String,
+  pub smtp_host: Option<String>,
+  pub smtp_port: u16,
+  pub smtp_username: Option<String>,
+  pub smtp_password: Option<String>,
+  pub outbox_path: Option<String>,
+  // URL pública de la API, se usa para armar los enlaces de los correos
+  pub app_url: String,
+}
+
+// Horarios de las tareas programadas; `None` desactiva la tarea
+#[derive(Debug, Clone)]
+pub struct SchedulerConfig {
+  pub token_cleanup: Option<CronSchedule>,
+  pub lock_expiry: Option<CronSchedule>,
+  pub soft_delete_purge: Option<CronSchedule>,
+  pub usage_refresh: Option<CronSchedule>,
+  pub soft_delete_retention: Duration,
+}
+
+// Límites por grupo de rutas; cada middleware `RateLimiter` elige el suyo con `rules`
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+  pub login: RateLimitRules,
+  pub register: RateLimitRules,
+  pub password_reset: RateLimitRules,
+  pub api_keys: RateLimitRules,
+}
+
+impl RateLimitConfig {
+  pub fn rules(&self, scope: RateLimitScope) -> &RateLimitRules {
+    match scope {
+      RateLimitScope::Login => &self.login,
+      RateLimitScope::Register => &self.register,
+      RateLimitScope::PasswordReset => &self.password_reset,
+      RateLimitScope::ApiKeys => &self.api_keys,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
 pub struct Config {
   pub token_config: TokenConfig,
+  pub lockout_policy: LockoutPolicy,
+  pub pin_lockout_policy: LockoutPolicy,
+  pub mfa_policy: MfaPolicy,
+  pub password_policy: PasswordPolicy,
+  pub totp_config: TotpConfig,
+  pub mailer: MailerConfig,
+  pub scheduler: SchedulerConfig,
+  pub rate_limits: RateLimitConfig,
+  // `None` deja el login con proveedor externo desactivado
+  pub oidc: Option<OidcConfig>,
+  // Sirve un proveedor OIDC de pruebas en `/mock-oidc`; nunca en producción
+  pub oidc_mock_issuer: bool,
+  // Dominio bajo el que cada restaurante tiene su subdominio (`{slug}.{dominio}`); `None` solo acepta el header
+  pub tenant_base_domain: Option<String>,
   pub database: DatabaseConfig,
   pub server: ServerConfig,
 }
@@ -28,9 +119,84 @@ impl Config {
       token_config: TokenConfig {
         access_token_secret: std::env::var("ACCESS_TOKEN_SECRET")
           .map_err(|_| Error::ConfigError("ACCESS_TOKEN_SECRET not set".to_string()))?,
+        signing_keys: Arc::new(signing_keys("JWT_KEYS_FILE", "JWT_ACTIVE_KID")?),
         access_token_duration: Duration::from_secs(30*60), // 30 minutos en segundos
         refresh_token_duration: Duration::from_secs(7 *24 * 60 * 60), // 7 días en segundos
+        verification_token_duration: Duration::from_secs(24 * 60 * 60), // 1 día en segundos
+        verification_resend_cooldown: Duration::from_secs(env_or("VERIFICATION_RESEND_COOLDOWN_SECONDS", 60)),
+        password_reset_token_duration: Duration::from_secs(env_or("PASSWORD_RESET_TOKEN_SECONDS", 30 * 60)), // 30 minutos
+        mfa_token_duration: Duration::from_secs(env_or("MFA_TOKEN_SECONDS", 5 * 60)), // 5 minutos
+        pos_token_duration: Duration::from_secs(env_or("POS_TOKEN_SECONDS", 15 * 60)), // 15 minutos
+        impersonation_token_duration: Duration::from_secs(env_or("IMPERSONATION_TOKEN_SECONDS", 10 * 60)), // 10 minutos
+      },
+      lockout_policy: LockoutPolicy::new(
+        env_or("LOGIN_MAX_FAILED_ATTEMPTS", 5),
+        Duration::from_secs(env_or("LOGIN_LOCKOUT_BASE_SECONDS", 60)), // 1 minuto
+        Duration::from_secs(env_or("LOGIN_LOCKOUT_MAX_SECONDS", 24 * 60 * 60)), // 1 día
+      ),
+      pin_lockout_policy: LockoutPolicy::new(
+        env_or("PIN_MAX_FAILED_ATTEMPTS", 5),
+        Duration::from_secs(env_or("PIN_LOCKOUT_BASE_SECONDS", 5 * 60)), // 5 minutos
+        Duration::from_secs(env_or("PIN_LOCKOUT_MAX_SECONDS", 4 * 60 * 60)), // 4 horas
+      ),
+      mfa_policy: MfaPolicy::new(required_user_types("MFA_REQUIRED_USER_TYPES")?),
+      password_policy: PasswordPolicy::new(
+        env_or("PASSWORD_MIN_LENGTH", 10),
+        env_or("PASSWORD_MIN_CHARACTER_CLASSES", 3),
+        env_or("PASSWORD_HISTORY", 5),
+        // 0 desactiva la caducidad
+        Some(env_or("STAFF_PASSWORD_MAX_AGE_DAYS", 90))
+          .filter(|days| *days > 0)
+          .map(|days| Duration::from_secs(days * 24 * 60 * 60)),
+      ),
+      totp_config: TotpConfig {
+        issuer: env_or("MFA_ISSUER", "Restaurant SaaS".to_string()),
+        allowed_skew: env_or("MFA_ALLOWED_SKEW_STEPS", 1),
+      },
+      mailer: MailerConfig {
+        driver: env_or("MAILER_DRIVER", MailerDriver::Log),
+        from: env_or("MAILER_FROM", "no-reply@restaurant.local".to_string()),
+        smtp_host: std::env::var("SMTP_HOST").ok(),
+        smtp_port: env_or("SMTP_PORT", 587),
+        smtp_username: std::env::var("SMTP_USERNAME").ok(),
+        smtp_password: std::env::var("SMTP_PASSWORD").ok(),
+        outbox_path: std::env::var("MAILER_OUTBOX_PATH").ok(),
+        app_url: env_or("APP_URL", "http://localhost:8080".to_string()),
       },
+      scheduler: SchedulerConfig {
+        token_cleanup: job_schedule("JOB_TOKEN_CLEANUP_SCHEDULE", "*/15 * * * *")?, // cada 15 minutos
+        lock_expiry: job_schedule("JOB_LOCK_EXPIRY_SCHEDULE", "*/5 * * * *")?, // cada 5 minutos
+        soft_delete_purge: job_schedule("JOB_SOFT_DELETE_PURGE_SCHEDULE", "30 3 * * *")?, // todos los días 03:30 UTC
+        usage_refresh: job_schedule("JOB_USAGE_REFRESH_SCHEDULE", "0 * * * *")?, // cada hora
+        soft_delete_retention: Duration::from_secs(env_or("SOFT_DELETE_RETENTION_DAYS", 30) * 24 * 60 * 60),
+      },
+      rate_limits: RateLimitConfig {
+        login: RateLimitRules {
+          per_ip: rate_limit("RATE_LIMIT_LOGIN_PER_IP", "20/60")?,
+          per_email: rate_limit("RATE_LIMIT_LOGIN_PER_EMAIL", "5/300")?,
+          per_api_key: None,
+        },
+        register: RateLimitRules {
+          per_ip: rate_limit("RATE_LIMIT_REGISTER_PER_IP", "10/3600")?,
+          per_email: rate_limit("RATE_LIMIT_REGISTER_PER_EMAIL", "3/3600")?,
+          per_api_key: None,
+        },
+        password_reset: RateLimitRules {
+          per_ip: rate_limit("RATE_LIMIT_PASSWORD_RESET_PER_IP", "10/3600")?,
+          per_email: rate_limit("RATE_LIMIT_PASSWORD_RESET_PER_EMAIL", "3/3600")?,
+          per_api_key: None,
+        },
+        api_keys: RateLimitRules {
+          per_ip: None,
+          per_email: None,
+          per_api_key: rate_limit("RATE_LIMIT_PER_API_KEY", "600/60")?,
+        },
+      },
+      oidc: oidc_config()?,
+      oidc_mock_issuer: env_or("OIDC_MOCK_ISSUER", false),
+      tenant_base_domain: Some(env_or("TENANT_BASE_DOMAIN", String::new()))
+        .map(|domain| domain.trim().trim_start_matches('.').to_lowercase())
+        .filter(|domain| !domain.is_empty()),
       database: DatabaseConfig {
         url: std::env::var("DATABASE_URL").expect("DATABASE_URL must be set"),
         namespace: std::env::var("DATABASE_NAMESPACE").expect("SURREAL_DB_NAMESPACE must be set"),
@@ -39,7 +205,133 @@ impl Config {
       server: ServerConfig {
         host: std::env::var("SERVER_HOST").expect("SERVER_HOST must be set"),
         port: std::env::var("SERVER_PORT").expect("SERVER_PORT must be set").parse().expect("SERVER_PORT must be a valid number"),
+        trusted_proxies: trusted_proxies("TRUSTED_PROXIES")?,
       },
     })
   }
+}
+
+// Variables opcionales: si no existen o no se pueden parsear se usa el valor por defecto
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+  std::env::var(key)
+    .ok()
+    .and_then(|value| value.parse().ok())
+    .unwrap_or(default)
+}
+
+// Lista separada por comas (p. ej. "admin,super_admin"); vacía o ausente significa que nadie está obligado
+fn required_user_types(key: &str) -> Result<Vec<UserType>, Error> {
+  std::env::var(key)
+    .unwrap_or_default()
+    .split(',')
+    .map(str::trim)
+    .filter(|value| !value.is_empty())
+    .map(|value| value.parse().map_err(Error::ConfigError))
+    .collect()
+}
+
+// IPs separadas por comas; vacía o ausente significa que no hay proxy delante
+fn trusted_proxies(key: &str) -> Result<Vec<IpAddr>, Error> {
+  std::env::var(key)
+    .unwrap_or_default()
+    .split(',')
+    .map(str::trim)
+    .filter(|value| !value.is_empty())
+    .map(|value| value.parse().map_err(|_| Error::ConfigError(format!("Invalid proxy address in {}: {}", key, value))))
+    .collect()
+}
+
+// Expresión cron de 5 campos; una variable vacía desactiva la tarea
+fn job_schedule(key: &str, default: &str) -> Result<Option<CronSchedule>, Error> {
+  let value = std::env::var(key).unwrap_or_else(|_| default.to_string());
+
+  if value.trim().is_empty() {
+    return Ok(None);
+  }
+
+  value.parse().map(Some).map_err(Error::ConfigError)
+}
+
+// "peticiones/segundos"; una variable vacía desactiva el límite
+fn rate_limit(key: &str, default: &str) -> Result<Option<RateLimit>, Error> {
+  let value = std::env::var(key).unwrap_or_else(|_| default.to_string());
+
+  if value.trim().is_empty() {
+    return Ok(None);
+  }
+
+  value.parse().map(Some).map_err(Error::ConfigError)
+}
+
+// Con OIDC_MOCK_ISSUER y sin OIDC_ISSUER se apunta al proveedor de pruebas de la propia API
+fn oidc_config() -> Result<Option<OidcConfig>, Error> {
+  let app_url = env_or("APP_URL", "http://localhost:8080".to_string());
+  let mock_issuer = env_or("OIDC_MOCK_ISSUER", false);
+  let issuer = match std::env::var("OIDC_ISSUER").ok().filter(|value| !value.trim().is_empty()) {
+    Some(issuer) => issuer,
+    None if mock_issuer => format!("{}/mock-oidc", app_url.trim_end_matches('/')),
+    None => return Ok(None),
+  };
+
+  let client_id = match std::env::var("OIDC_CLIENT_ID").ok().filter(|value| !value.trim().is_empty()) {
+    Some(client_id) => client_id,
+    None if mock_issuer => "mock-client".to_string(),
+    None => return Err(Error::ConfigError("OIDC_CLIENT_ID must be set when OIDC_ISSUER is".to_string())),
+  };
+
+  Ok(Some(OidcConfig {
+    issuer: issuer.trim_end_matches('/').to_string(),
+    client_id,
+    client_secret: std::env::var("OIDC_CLIENT_SECRET").ok().filter(|value| !value.is_empty()),
+    redirect_uri: env_or("OIDC_REDIRECT_URI", format!("{}/api/v1/auth/oidc/callback", app_url.trim_end_matches('/'))),
+    scopes: env_or("OIDC_SCOPES", "openid email profile".to_string()),
+    authorization_duration: Duration::from_secs(env_or("OIDC_AUTHORIZATION_SECONDS", 10 * 60)), // 10 minutos
+  }))
+}
+
+#[derive(Debug, Deserialize)]
+struct SigningKeysFile {
+  active_kid: String,
+  keys: Vec<SigningKeyEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SigningKeyEntry {
+  kid: String,
+  algorithm: Algorithm,
+  private_key_path: String,
+  #[serde(default)]
+  retired: bool,
+}
+
+// Manifiesto JSON con las claves de firma; las rutas relativas se resuelven desde su carpeta.
+// Sin manifiesto los access tokens siguen firmándose con ACCESS_TOKEN_SECRET (HS256).
+fn signing_keys(file_key: &str, active_kid_key: &str) -> Result<SigningKeys, Error> {
+  let Some(path) = std::env::var(file_key).ok().filter(|value| !value.is_empty()) else {
+    return Ok(SigningKeys::default());
+  };
+
+  let content = std::fs::read_to_string(&path)
+    .map_err(|e| Error::ConfigError(format!("Cannot read {}: {}", path, e)))?;
+  let file: SigningKeysFile = serde_json::from_str(&content)
+    .map_err(|e| Error::ConfigError(format!("Invalid {}: {}", path, e)))?;
+  let base_dir = Path::new(&path).parent().unwrap_or(Path::new("."));
+
+  let keys = file.keys
+    .into_iter()
+    .map(|entry| {
+      let key_path = base_dir.join(&entry.private_key_path);
+      let pem = std::fs::read(&key_path)
+        .map_err(|e| Error::ConfigError(format!("Cannot read {}: {}", key_path.display(), e)))?;
+      SigningKey::from_pem(entry.kid, entry.algorithm, &pem, entry.retired)
+    })
+    .collect::<Result<Vec<_>, Error>>()?;
+
+  // La variable de entorno permite cambiar la clave activa sin tocar el manifiesto
+  let active_kid = std::env::var(active_kid_key)
+    .ok()
+    .filter(|value| !value.is_empty())
+    .unwrap_or(file.active_kid);
+
+  SigningKeys::new(active_kid, keys)
 }
\ No newline at end of file
@@ -1 +1,4 @@
-pub mod surreal_connection;
\ No newline at end of file
+pub mod surreal_connection;
+pub mod tenant_router;
+pub mod surreal_tenant_provisioner;
+pub mod surreal_tenant_onboarding;
\ No newline at end of file
@@ -3,25 +3,70 @@ use surrealdb::engine::remote::ws::{Client, Ws};
 use std::sync::Arc;
 
 use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::tenant_router::TenantRouter;
 
 pub struct DatabaseConnection {
   pub client: Arc<Surreal<Client>>,
+  url: String,
+  namespace: String,
+  tenants: TenantRouter,
 }
 
 impl DatabaseConnection {
   pub async fn new(url: &str, namespace: &str, database: &str) -> Result<Self, Error> {
+    let db = Self::connect(url, namespace, database).await?;
+
+    Ok(Self {
+      client: Arc::new(db),
+      url: url.to_string(),
+      namespace: namespace.to_string(),
+      tenants: TenantRouter::new(),
+    })
+  }
+
+  async fn connect(url: &str, namespace: &str, database: &str) -> Result<Surreal<Client>, Error> {
     let db: Surreal<Client> = Surreal::init();
 
     println!("Connecting to database... {}", url);
-    let _  = db.connect::<Ws>(url).await?;
+    db.connect::<Ws>(url).await?;
 
     db.use_ns(namespace).use_db(database).await?;
 
-    Ok(Self { client: Arc::new(db) })
+    Ok(db)
   }
 
   pub fn get_client(&self) -> Arc<Surreal<Client>> {
     self.client.clone()
   }
 
+  // Base dedicada del restaurante si la tiene; si no, la compartida
+  pub fn client_for(&self, tenant: &TenantContext) -> Arc<Surreal<Client>> {
+    self.dedicated_client(tenant).unwrap_or_else(|| self.get_client())
+  }
+
+  pub fn dedicated_client(&self, tenant: &TenantContext) -> Option<Arc<Surreal<Client>>> {
+    self.tenants.route(tenant)
+  }
+
+  // Abre la conexión a la base dedicada de un restaurante y le enruta sus peticiones
+  pub async fn attach_tenant(&self, tenant: &TenantContext, database: &str) -> Result<Arc<Surreal<Client>>, Error> {
+    let client = Arc::new(Self::connect(&self.url, &self.namespace, database).await?);
+    self.tenants.attach(tenant, client.clone());
+
+    Ok(client)
+  }
+
+  pub fn detach_tenant(&self, tenant: &TenantContext) {
+    self.tenants.detach(tenant);
+  }
+
+  // Plataforma (base compartida) y restaurantes con base dedicada
+  pub fn tenants(&self) -> Vec<TenantContext> {
+    let mut tenants = vec![TenantContext::platform()];
+    tenants.extend(self.tenants.tenants());
+
+    tenants
+  }
+
 }
\ No newline at end of file
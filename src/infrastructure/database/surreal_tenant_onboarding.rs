@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::entities::location::Location;
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::entities::user::User;
+use crate::domain::error::Error;
+use crate::domain::services::role_templates::RoleTemplate;
+use crate::domain::services::tenant_onboarding::TenantOnboarding;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Los ids se generan aquí para poder enlazar los registros dentro de la transacción sin leer
+// sus resultados. Los permisos se buscan por nombre en el catálogo y se crean si faltan.
+const ONBOARDING_QUERY: &str = r#"
+  BEGIN TRANSACTION;
+
+  LET $owner = type::thing("users", $owner_id);
+  LET $location = type::thing("locations", $location_id);
+
+  CREATE $location SET
+    name = $location_data.name,
+    address = $location_data.address,
+    timezone = $location_data.timezone,
+    currency = $location_data.currency,
+    business_hours = $location_data.business_hours,
+    is_active = true,
+    restaurant_id = $restaurant_id,
+    created_at = time::now(),
+    updated_at = time::now();
+
+  CREATE $owner SET
+    email = $email,
+    password = crypto::argon2::generate($password),
+    status = 'pending_verification',
+    user_type = $user_type,
+    restaurant_id = $restaurant_id,
+    created_at = time::now(),
+    updated_at = time::now(),
+    password_changed_at = time::now(),
+    is_verified = false,
+    failed_login_attempts = 0;
+
+  CREATE users_locations SET
+    user = $owner,
+    location = $location,
+    assigned_by = $owner,
+    assigned_at = time::now();
+
+  FOR $template IN $roles {
+    LET $role = (CREATE roles SET
+      name = $template.name,
+      description = $template.description,
+      hierarchy_level = $template.hierarchy_level,
+      restaurant_id = $restaurant_id,
+      is_active = true,
+      created_at = time::now()
+    RETURN VALUE id)[0];
+
+    FOR $permission IN $template.permissions {
+      LET $name = string::concat($permission.resource, '.', $permission.action);
+      LET $existing = (SELECT VALUE id FROM permissions WHERE name = $name LIMIT 1)[0];
+      LET $permission_id = IF $existing = NONE {
+        (CREATE permissions SET
+          name = $name,
+          description = string::concat($permission.action, ' ', $permission.resource),
+          resource = $permission.resource,
+          action = $permission.action
+        RETURN VALUE id)[0]
+      } ELSE {
+        $existing
+      };
+
+      CREATE roles_permissions SET role = $role, permission = $permission_id;
+    };
+  };
+
+  FOR $role IN (SELECT VALUE id FROM roles
+    WHERE name INSIDE $owner_roles AND is_active = true
+    AND (restaurant_id = NONE OR restaurant_id = $restaurant_id)) {
+    CREATE users_roles SET
+      user = $owner,
+      role = $role,
+      assigned_by = $owner,
+      assigned_at = time::now();
+  };
+
+  COMMIT TRANSACTION;
+"#;
+
+pub struct SurrealTenantOnboarding {
+  connection: Arc<DatabaseConnection>,
+}
+
+impl SurrealTenantOnboarding {
+  pub fn new(connection: Arc<DatabaseConnection>) -> Self {
+    Self { connection }
+  }
+}
+
+#[async_trait]
+impl TenantOnboarding for SurrealTenantOnboarding {
+  async fn onboard(
+    &self,
+    restaurant: &Restaurant,
+    location: &Location,
+    owner: &User,
+    roles: &[RoleTemplate],
+    owner_roles: &[&str],
+  ) -> Result<(User, Location), Error> {
+    let tenant = TenantContext::from(restaurant);
+    let db = self.connection.client_for(&tenant);
+    let owner_id = Uuid::new_v4().simple().to_string();
+    let location_id = Uuid::new_v4().simple().to_string();
+
+    let roles: Vec<_> = roles.iter().map(|template| json!({
+      "name": template.name,
+      "description": template.description,
+      "hierarchy_level": template.hierarchy_level,
+      "permissions": template.permissions.iter()
+        .map(|permission| json!({ "resource": permission.resource, "action": permission.action }))
+        .collect::<Vec<_>>(),
+    })).collect();
+
+    db.query(ONBOARDING_QUERY)
+      .bind(("owner_id", owner_id.clone()))
+      .bind(("location_id", location_id.clone()))
+      .bind(("location_data", location.clone()))
+      .bind(("email", owner.email.clone()))
+      .bind(("password", owner.password.clone()))
+      .bind(("user_type", owner.user_type.clone()))
+      .bind(("roles", roles))
+      .bind(("owner_roles", owner_roles.iter().map(|role| role.to_string()).collect::<Vec<_>>()))
+      .bind(("restaurant_id", tenant.record()))
+      .await?
+      .check()?;
+
+    let mut response = db
+      .query(r#"
+        SELECT * FROM type::thing("users", $owner_id);
+        SELECT * FROM type::thing("locations", $location_id);
+      "#)
+      .bind(("owner_id", owner_id))
+      .bind(("location_id", location_id))
+      .await?;
+
+    let owner: Option<User> = response.take(0)?;
+    let location: Option<Location> = response.take(1)?;
+
+    owner.zip(location).ok_or(Error::CreationFailed)
+  }
+}
@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use std::sync::Arc;
+
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::error::Error;
+use crate::domain::repositories::restaurant_repository::RestaurantRepository;
+use crate::domain::services::tenant_database::TenantDatabaseProvisioner;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::repositories::surreal_restaurant_repository::SurrealRestaurantRepository;
+
+const SCHEMA: &str = include_str!("../../../resources/database.surql");
+// A partir de esta línea el script solo se aplica a la base de la plataforma
+const PLATFORM_ONLY_MARKER: &str = "-- @platform-only";
+
+pub struct SurrealTenantProvisioner {
+  connection: Arc<DatabaseConnection>,
+}
+
+impl SurrealTenantProvisioner {
+  pub fn new(connection: Arc<DatabaseConnection>) -> Self {
+    Self { connection }
+  }
+
+  // Al arrancar se abren las conexiones de las bases dedicadas que ya existen
+  pub async fn attach_existing(&self) -> Result<usize, Error> {
+    let restaurants = SurrealRestaurantRepository::new(&self.connection).find_all().await?;
+    let mut attached = 0;
+
+    for restaurant in restaurants {
+      if let Some(database) = &restaurant.database {
+        self.connection.attach_tenant(&TenantContext::from(&restaurant), database).await?;
+        attached += 1;
+      }
+    }
+
+    Ok(attached)
+  }
+}
+
+#[async_trait]
+impl TenantDatabaseProvisioner for SurrealTenantProvisioner {
+  async fn provision(&self, restaurant: &Restaurant) -> Result<(), Error> {
+    let database = restaurant.database.as_deref().ok_or(Error::TenantNotDedicated)?;
+    let tenant = TenantContext::from(restaurant);
+    let client = self.connection.attach_tenant(&tenant, database).await?;
+
+    let applied = match client.query(tenant_schema()).await {
+      Ok(response) => response.check().map(|_| ()),
+      Err(err) => Err(err),
+    };
+
+    if let Err(err) = applied {
+      self.connection.detach_tenant(&tenant);
+      return Err(err.into());
+    }
+
+    log::info!("Provisioned database {} for restaurant {}", database, restaurant.slug);
+    Ok(())
+  }
+
+  async fn deprovision(&self, restaurant: &Restaurant) -> Result<(), Error> {
+    let database = restaurant.database.as_deref().ok_or(Error::TenantNotDedicated)?;
+    self.connection.detach_tenant(&TenantContext::from(restaurant));
+
+    // El nombre sale del slug validado, así que es un identificador seguro
+    self.connection.get_client()
+      .query(format!("REMOVE DATABASE IF EXISTS `{}`", database))
+      .await?
+      .check()?;
+
+    log::info!("Removed database {} of restaurant {}", database, restaurant.slug);
+    Ok(())
+  }
+
+  async fn export(&self, restaurant: &Restaurant) -> Result<Vec<u8>, Error> {
+    // Nunca se cae a la base compartida: volcaría los datos de todos los restaurantes
+    let client = self.connection
+      .dedicated_client(&TenantContext::from(restaurant))
+      .ok_or(Error::TenantNotDedicated)?;
+
+    let mut backup = client.export(()).await?;
+    let mut dump = Vec::new();
+
+    while let Some(chunk) = backup.next().await {
+      dump.extend(chunk?);
+    }
+
+    Ok(dump)
+  }
+}
+
+// El script sin los `USE`, que cambiarían la base de la conexión, ni la parte de la plataforma
+fn tenant_schema() -> String {
+  let schema = SCHEMA.split(PLATFORM_ONLY_MARKER).next().unwrap_or_default();
+
+  schema
+    .lines()
+    .filter(|line| !line.trim_start().to_uppercase().starts_with("USE "))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
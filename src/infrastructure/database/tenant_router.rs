@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+
+use crate::domain::value_objects::tenant::TenantContext;
+
+type TenantDatabase = (TenantContext, Arc<Surreal<Client>>);
+
+// Clientes de las bases dedicadas, uno por restaurante aislado. La base que fija `use_db` es de la
+// sesión de la conexión, así que cada restaurante necesita su propio cliente: cambiar de base sobre
+// un cliente compartido afectaría a las peticiones que están en curso con él.
+#[derive(Default)]
+pub struct TenantRouter {
+  databases: RwLock<HashMap<String, TenantDatabase>>,
+}
+
+impl TenantRouter {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  // None si el tenant usa la base compartida
+  pub fn route(&self, tenant: &TenantContext) -> Option<Arc<Surreal<Client>>> {
+    let key = tenant.record()?.to_string();
+
+    self.databases.read().ok()?.get(&key).map(|(_, client)| client.clone())
+  }
+
+  pub fn attach(&self, tenant: &TenantContext, client: Arc<Surreal<Client>>) {
+    if let (Some(record), Ok(mut databases)) = (tenant.record(), self.databases.write()) {
+      databases.insert(record.to_string(), (tenant.clone(), client));
+    }
+  }
+
+  pub fn detach(&self, tenant: &TenantContext) -> Option<Arc<Surreal<Client>>> {
+    let key = tenant.record()?.to_string();
+
+    self.databases.write().ok()?.remove(&key).map(|(_, client)| client)
+  }
+
+  // Restaurantes con base dedicada, para las tareas que tienen que recorrerlas todas
+  pub fn tenants(&self) -> Vec<TenantContext> {
+    self.databases.read()
+      .map(|databases| databases.values().map(|(tenant, _)| tenant.clone()).collect())
+      .unwrap_or_default()
+  }
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::io::AsyncWriteExt;
+
+use crate::domain::error::Error;
+use crate::domain::services::mailer::{EmailMessage, Mailer};
+
+// Sustituto para desarrollo local: escribe los correos en el log y, si se configura, en un archivo
+pub struct LogMailer {
+  outbox_path: Option<String>,
+}
+
+impl LogMailer {
+  pub fn new(outbox_path: Option<String>) -> Self {
+    Self { outbox_path }
+  }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+  async fn send(&self, message: &EmailMessage) -> Result<(), Error> {
+    log::info!("Email to {}: {}\n{}", message.to, message.subject, message.body);
+
+    if let Some(path) = &self.outbox_path {
+      let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+
+      let entry = format!(
+        "Date: {}\nTo: {}\nSubject: {}\n\n{}\n\n---\n\n",
+        Utc::now().to_rfc3339(),
+        message.to,
+        message.subject,
+        message.body
+      );
+
+      file.write_all(entry.as_bytes()).await?;
+    }
+
+    Ok(())
+  }
+}
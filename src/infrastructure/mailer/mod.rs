@@ -0,0 +1,15 @@
+pub mod smtp_mailer;
+pub mod log_mailer;
+
+use std::sync::Arc;
+
+use crate::domain::error::Error;
+use crate::domain::services::mailer::Mailer;
+use crate::infrastructure::config_env::{MailerConfig, MailerDriver};
+
+pub fn build_mailer(config: &MailerConfig) -> Result<Arc<dyn Mailer>, Error> {
+  Ok(match config.driver {
+    MailerDriver::Smtp => Arc::new(smtp_mailer::SmtpMailer::new(config)?),
+    MailerDriver::Log => Arc::new(log_mailer::LogMailer::new(config.outbox_path.clone())),
+  })
+}
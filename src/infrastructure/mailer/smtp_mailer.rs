@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::domain::error::Error;
+use crate::domain::services::mailer::{EmailMessage, Mailer};
+use crate::infrastructure::config_env::MailerConfig;
+
+pub struct SmtpMailer {
+  transport: AsyncSmtpTransport<Tokio1Executor>,
+  from: Mailbox,
+}
+
+impl SmtpMailer {
+  pub fn new(config: &MailerConfig) -> Result<Self, Error> {
+    let host = config.smtp_host.clone()
+      .ok_or(Error::ConfigError("SMTP_HOST must be set when MAILER_DRIVER=smtp".to_string()))?;
+
+    let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)
+      .map_err(|e| Error::ConfigError(e.to_string()))?
+      .port(config.smtp_port);
+
+    if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+      builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+    }
+
+    let from = config.from.parse::<Mailbox>()
+      .map_err(|e| Error::ConfigError(format!("Invalid MAILER_FROM: {}", e)))?;
+
+    Ok(Self { transport: builder.build(), from })
+  }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+  async fn send(&self, message: &EmailMessage) -> Result<(), Error> {
+    let to = message.to.parse::<Mailbox>().map_err(|_| Error::InvalidEmail)?;
+
+    let email = Message::builder()
+      .from(self.from.clone())
+      .to(to)
+      .subject(message.subject.clone())
+      .header(ContentType::TEXT_PLAIN)
+      .body(message.body.clone())
+      .map_err(|e| Error::InfrastructureError(e.to_string()))?;
+
+    self.transport
+      .send(email)
+      .await
+      .map_err(|e| Error::ServiceUnavailable(e.to_string()))?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::domain::error::Error;
+use crate::domain::services::rate_limit::{RateLimit, RateLimitDecision, RateLimitStore};
+
+// Cada cuánto se descartan los buckets que ya se rellenaron por completo
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+struct Bucket {
+  tokens: f64,
+  updated_at: Instant,
+  // Pasado este tiempo sin uso el bucket vuelve a estar lleno y equivale a no tenerlo
+  full_after: Duration,
+}
+
+struct Buckets {
+  entries: HashMap<String, Bucket>,
+  pruned_at: Instant,
+}
+
+// Buckets en memoria del proceso; con varias instancias cada una lleva su propia cuenta
+pub struct MemoryRateLimitStore {
+  buckets: Mutex<Buckets>,
+}
+
+impl MemoryRateLimitStore {
+  pub fn new() -> Self {
+    Self {
+      buckets: Mutex::new(Buckets { entries: HashMap::new(), pruned_at: Instant::now() }),
+    }
+  }
+}
+
+impl Default for MemoryRateLimitStore {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[async_trait]
+impl RateLimitStore for MemoryRateLimitStore {
+  async fn acquire(&self, key: &str, limit: &RateLimit) -> Result<RateLimitDecision, Error> {
+    let now = Instant::now();
+    let mut buckets = self.buckets.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if now.duration_since(buckets.pruned_at) >= PRUNE_INTERVAL {
+      buckets.entries.retain(|_, bucket| now.duration_since(bucket.updated_at) < bucket.full_after);
+      buckets.pruned_at = now;
+    }
+
+    let capacity = limit.capacity as f64;
+    let bucket = buckets.entries.entry(key.to_string()).or_insert_with(|| Bucket {
+      tokens: capacity,
+      updated_at: now,
+      full_after: limit.period,
+    });
+
+    let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+    bucket.tokens = (bucket.tokens + elapsed * limit.refill_rate()).min(capacity);
+    bucket.updated_at = now;
+    bucket.full_after = limit.period;
+
+    if bucket.tokens >= 1.0 {
+      bucket.tokens -= 1.0;
+      return Ok(RateLimitDecision::Allowed);
+    }
+
+    let retry_after = Duration::from_secs_f64((1.0 - bucket.tokens) / limit.refill_rate());
+
+    Ok(RateLimitDecision::Limited { retry_after })
+  }
+}
@@ -1,4 +1,9 @@
 pub mod database;
 pub mod repositories;
 pub mod config_env;
-pub mod web;
\ No newline at end of file
+pub mod mailer;
+pub mod web;
+pub mod revocation_cache;
+pub mod scheduler;
+pub mod memory_rate_limit_store;
+pub mod oidc;
\ No newline at end of file
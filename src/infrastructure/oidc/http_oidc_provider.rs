@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::domain::error::Error;
+use crate::domain::services::oidc::{OidcConfig, OidcProvider, ProviderMetadata};
+
+#[derive(Deserialize)]
+struct TokenResponse {
+  id_token: Option<String>,
+}
+
+// Habla con el proveedor por HTTP. No guarda el descubrimiento ni las claves: los logins externos
+// son pocos y así una rotación de claves del proveedor se ve en el acto.
+pub struct HttpOidcProvider {
+  client: reqwest::Client,
+  config: OidcConfig,
+}
+
+impl HttpOidcProvider {
+  pub fn new(config: OidcConfig) -> Result<Self, Error> {
+    let client = reqwest::Client::builder()
+      .timeout(Duration::from_secs(10))
+      .build()
+      .map_err(|e| Error::ConfigError(format!("Cannot build OIDC HTTP client: {}", e)))?;
+
+    Ok(Self { client, config })
+  }
+
+  async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, Error> {
+    self.client
+      .get(url)
+      .send()
+      .await
+      .and_then(|response| response.error_for_status())
+      .map_err(|e| Error::IdentityProviderError(format!("GET {} failed: {}", url, e)))?
+      .json()
+      .await
+      .map_err(|e| Error::IdentityProviderError(format!("Invalid response from {}: {}", url, e)))
+  }
+}
+
+#[async_trait]
+impl OidcProvider for HttpOidcProvider {
+  async fn metadata(&self) -> Result<ProviderMetadata, Error> {
+    let url = format!("{}/.well-known/openid-configuration", self.config.issuer);
+    let metadata: ProviderMetadata = self.get_json(&url).await?;
+
+    // Un documento que dice ser de otro emisor no es de fiar (OIDC Discovery, sección 4.3)
+    if metadata.issuer.trim_end_matches('/') != self.config.issuer {
+      return Err(Error::IdentityProviderError(format!("Issuer mismatch in discovery document: {}", metadata.issuer)));
+    }
+
+    Ok(metadata)
+  }
+
+  async fn exchange_code(&self, metadata: &ProviderMetadata, code: &str, code_verifier: &str) -> Result<String, Error> {
+    let mut form = vec![
+      ("grant_type", "authorization_code"),
+      ("code", code),
+      ("redirect_uri", self.config.redirect_uri.as_str()),
+      ("client_id", self.config.client_id.as_str()),
+      ("code_verifier", code_verifier),
+    ];
+
+    if let Some(secret) = &self.config.client_secret {
+      form.push(("client_secret", secret.as_str()));
+    }
+
+    let response = self.client
+      .post(&metadata.token_endpoint)
+      .form(&form)
+      .send()
+      .await
+      .map_err(|e| Error::IdentityProviderError(format!("Token request failed: {}", e)))?;
+
+    // Un código inválido, vencido o con otro verificador es culpa del cliente, no del proveedor
+    if response.status().is_client_error() {
+      return Err(Error::InvalidToken);
+    }
+
+    let response: TokenResponse = response
+      .error_for_status()
+      .map_err(|e| Error::IdentityProviderError(format!("Token request failed: {}", e)))?
+      .json()
+      .await
+      .map_err(|e| Error::IdentityProviderError(format!("Invalid token response: {}", e)))?;
+
+    response.id_token.ok_or(Error::IdentityProviderError("Token response without id_token".to_string()))
+  }
+
+  async fn jwks(&self, metadata: &ProviderMetadata) -> Result<JwkSet, Error> {
+    self.get_json(&metadata.jwks_uri).await
+  }
+}
@@ -0,0 +1,209 @@
+use chrono::Utc;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{encode, Algorithm, Header};
+use ring::digest::{digest, SHA256};
+use ring::rand::SystemRandom;
+use ring::signature::Ed25519KeyPair;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use url::Url;
+
+use crate::domain::error::Error;
+use crate::domain::services::oidc::{code_challenge, random_token};
+use crate::domain::services::signing_keys::{SigningKey, SigningKeys};
+
+const MOCK_KID: &str = "mock-oidc";
+const CODE_DURATION: Duration = Duration::from_secs(60);
+const ID_TOKEN_DURATION: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_EMAIL: &str = "customer@mock-oidc.local";
+
+#[derive(Debug, Deserialize)]
+pub struct MockAuthorizeRequest {
+  pub response_type: String,
+  pub client_id: String,
+  pub redirect_uri: String,
+  pub state: Option<String>,
+  pub nonce: Option<String>,
+  pub code_challenge: Option<String>,
+  pub code_challenge_method: Option<String>,
+  // Cuenta con la que "entra" el usuario; no hay pantalla de login
+  pub login_hint: Option<String>,
+  // Permite probar el rechazo de emails sin verificar
+  pub email_verified: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MockTokenRequest {
+  pub grant_type: String,
+  pub code: String,
+  pub redirect_uri: String,
+  pub client_id: String,
+  pub code_verifier: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MockTokenResponse {
+  pub access_token: String,
+  pub token_type: String,
+  pub expires_in: u64,
+  pub id_token: String,
+}
+
+#[derive(Debug, Serialize)]
+struct MockIdTokenClaims {
+  iss: String,
+  sub: String,
+  aud: String,
+  exp: i64,
+  iat: i64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  nonce: Option<String>,
+  email: String,
+  email_verified: bool,
+}
+
+struct MockGrant {
+  client_id: String,
+  redirect_uri: String,
+  nonce: Option<String>,
+  code_challenge: String,
+  email: String,
+  email_verified: bool,
+  expires_at: Instant,
+}
+
+// Proveedor OIDC mínimo para probar el flujo sin salir de la máquina: autoriza sin preguntar
+// a quien diga `login_hint`, exige PKCE S256 y firma los ID tokens con una clave Ed25519 efímera.
+pub struct MockIssuer {
+  issuer: String,
+  client_id: String,
+  keys: SigningKeys,
+  grants: Mutex<HashMap<String, MockGrant>>,
+}
+
+impl MockIssuer {
+  pub fn new(issuer: String, client_id: String) -> Result<Self, Error> {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+      .map_err(|_| Error::ConfigError("Cannot generate mock OIDC key".to_string()))?;
+    let pem = pem::encode(&pem::Pem::new("PRIVATE KEY", pkcs8.as_ref().to_vec()));
+    let key = SigningKey::from_pem(MOCK_KID.to_string(), Algorithm::EdDSA, pem.as_bytes(), false)?;
+
+    Ok(Self {
+      issuer,
+      client_id,
+      keys: SigningKeys::new(MOCK_KID.to_string(), vec![key])?,
+      grants: Mutex::new(HashMap::new()),
+    })
+  }
+
+  pub fn metadata(&self) -> serde_json::Value {
+    serde_json::json!({
+      "issuer": self.issuer,
+      "authorization_endpoint": format!("{}/authorize", self.issuer),
+      "token_endpoint": format!("{}/token", self.issuer),
+      "jwks_uri": format!("{}/jwks", self.issuer),
+      "response_types_supported": ["code"],
+      "subject_types_supported": ["public"],
+      "id_token_signing_alg_values_supported": ["EdDSA"],
+      "code_challenge_methods_supported": ["S256"],
+    })
+  }
+
+  pub fn jwks(&self) -> JwkSet {
+    self.keys.jwks()
+  }
+
+  // Devuelve la URL a la que se redirige al navegador con el código
+  pub fn authorize(&self, request: MockAuthorizeRequest) -> Result<String, Error> {
+    if request.response_type != "code" {
+      return Err(Error::InvalidInput("response_type must be code".to_string()));
+    }
+
+    if request.client_id != self.client_id {
+      return Err(Error::InvalidInput("unknown client_id".to_string()));
+    }
+
+    if request.code_challenge_method.as_deref() != Some("S256") {
+      return Err(Error::InvalidInput("code_challenge_method must be S256".to_string()));
+    }
+
+    let code_challenge = request.code_challenge
+      .ok_or(Error::InvalidInput("code_challenge is required".to_string()))?;
+    let mut redirect = Url::parse(&request.redirect_uri)
+      .map_err(|_| Error::InvalidInput("invalid redirect_uri".to_string()))?;
+    let code = random_token()?;
+
+    self.grants.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).insert(code.clone(), MockGrant {
+      client_id: request.client_id,
+      redirect_uri: request.redirect_uri,
+      nonce: request.nonce,
+      code_challenge,
+      email: request.login_hint.filter(|email| !email.trim().is_empty()).unwrap_or(DEFAULT_EMAIL.to_string()),
+      email_verified: request.email_verified.unwrap_or(true),
+      expires_at: Instant::now() + CODE_DURATION,
+    });
+
+    redirect.query_pairs_mut().append_pair("code", &code);
+
+    if let Some(state) = request.state {
+      redirect.query_pairs_mut().append_pair("state", &state);
+    }
+
+    Ok(redirect.to_string())
+  }
+
+  pub fn token(&self, request: MockTokenRequest) -> Result<MockTokenResponse, Error> {
+    let grant = {
+      let mut grants = self.grants.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+      grants.retain(|_, grant| grant.expires_at > Instant::now());
+      grants.remove(&request.code)
+    }
+    .ok_or(Error::InvalidToken)?;
+
+    let verifier_matches = request.code_verifier
+      .as_deref()
+      .is_some_and(|verifier| code_challenge(verifier) == grant.code_challenge);
+
+    if request.grant_type != "authorization_code"
+      || request.client_id != grant.client_id
+      || request.redirect_uri != grant.redirect_uri
+      || !verifier_matches
+    {
+      return Err(Error::InvalidToken);
+    }
+
+    let now = Utc::now();
+    let claims = MockIdTokenClaims {
+      iss: self.issuer.clone(),
+      // Estable por email para que los siguientes logins encuentren la identidad enlazada
+      sub: digest(&SHA256, grant.email.to_lowercase().as_bytes())
+        .as_ref()
+        .iter()
+        .take(16)
+        .map(|byte| format!("{:02x}", byte))
+        .collect(),
+      aud: grant.client_id,
+      exp: (now + ID_TOKEN_DURATION).timestamp(),
+      iat: now.timestamp(),
+      nonce: grant.nonce,
+      email: grant.email,
+      email_verified: grant.email_verified,
+    };
+
+    let key = self.keys.active().ok_or(Error::ConfigError("Mock OIDC key missing".to_string()))?;
+    let mut header = Header::new(key.algorithm);
+    header.kid = Some(key.kid.clone());
+
+    let id_token = encode(&header, &claims, key.encoding_key())
+      .map_err(|e| Error::TokenGenerationError(e.to_string()))?;
+
+    Ok(MockTokenResponse {
+      access_token: random_token()?,
+      token_type: "Bearer".to_string(),
+      expires_in: ID_TOKEN_DURATION.as_secs(),
+      id_token,
+    })
+  }
+}
@@ -0,0 +1,11 @@
+pub mod http_oidc_provider;
+pub mod mock_issuer;
+
+use std::sync::Arc;
+
+use crate::domain::error::Error;
+use crate::domain::services::oidc::{OidcConfig, OidcProvider};
+
+pub fn build_oidc_provider(config: &OidcConfig) -> Result<Arc<dyn OidcProvider>, Error> {
+  Ok(Arc::new(http_oidc_provider::HttpOidcProvider::new(config.clone())?))
+}
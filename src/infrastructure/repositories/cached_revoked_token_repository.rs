@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use crate::domain::entities::revoked_token::RevokedToken;
+use crate::domain::repositories::revoked_token_repository::RevokedTokenRepository;
+use crate::domain::error::Error;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::repositories::surreal_revoked_token_repository::SurrealRevokedTokenRepository;
+use crate::infrastructure::revocation_cache::RevocationCache;
+
+// Lista de revocación en memoria con SurrealDB detrás: la memoria evita la consulta para los
+// tokens ya revocados en este proceso y la base de datos comparte las revocaciones entre instancias
+pub struct CachedRevokedTokenRepository {
+  cache: Arc<RevocationCache>,
+  fallback: SurrealRevokedTokenRepository,
+}
+
+impl CachedRevokedTokenRepository {
+  pub fn new(connection: &DatabaseConnection, cache: Arc<RevocationCache>) -> Self {
+    Self {
+      cache,
+      fallback: SurrealRevokedTokenRepository::new(connection),
+    }
+  }
+}
+
+#[async_trait]
+impl RevokedTokenRepository for CachedRevokedTokenRepository {
+  async fn revoke(&self, entry: &RevokedToken) -> Result<(), Error> {
+    self.cache.insert(entry.clone());
+    self.fallback.revoke(entry).await
+  }
+
+  async fn find_matching(&self, jti: Option<String>, user_id: String, issued_at: DateTime<Utc>) -> Result<Option<RevokedToken>, Error> {
+    if let Some(entry) = self.cache.find_matching(jti.as_deref(), &user_id, issued_at) {
+      return Ok(Some(entry));
+    }
+
+    let entry = self.fallback.find_matching(jti, user_id, issued_at).await?;
+
+    if let Some(entry) = &entry {
+      self.cache.insert(entry.clone());
+    }
+
+    Ok(entry)
+  }
+
+  async fn cleanup_expired(&self) -> Result<u64, Error> {
+    self.cache.remove_expired();
+    self.fallback.cleanup_expired().await
+  }
+}
@@ -3,3 +3,20 @@ pub mod surreal_profile_repository;
 pub mod surreal_permission_repository;
 pub mod surreal_role_repository;
 pub mod surreal_token_repository;
+pub mod surreal_one_time_token_repository;
+
+pub mod surreal_mfa_repository;
+pub mod surreal_pos_device_repository;
+pub mod surreal_staff_pin_repository;
+pub mod surreal_api_key_repository;
+pub mod surreal_revoked_token_repository;
+pub mod cached_revoked_token_repository;
+pub mod surreal_job_run_repository;
+pub mod surreal_audit_repository;
+pub mod surreal_oidc_authorization_repository;
+pub mod surreal_external_identity_repository;
+pub mod surreal_restaurant_repository;
+pub mod surreal_location_repository;
+pub mod surreal_plan_repository;
+pub mod surreal_subscription_repository;
+pub mod surreal_usage_repository;
\ No newline at end of file
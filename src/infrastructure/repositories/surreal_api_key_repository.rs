@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::Datetime;
+use std::sync::Arc;
+
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::repositories::api_key_repository::ApiKeyRepository;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Una clave pertenece al restaurante en el que se crea y no existe para los demás;
+// `purge_revoked` es mantenimiento y recorre todos
+pub struct SurrealApiKeyRepository {
+  db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
+}
+
+impl SurrealApiKeyRepository {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
+    Self { 
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
+    }
+  }
+}
+
+#[async_trait]
+impl ApiKeyRepository for SurrealApiKeyRepository {
+  // Las claves son aleatorias y largas, un SHA-256 basta y evita un Argon2 en cada petición
+  async fn create(&self, api_key: &ApiKey, key: &str) -> Result<ApiKey, Error> {
+    let result: Option<ApiKey> = self.db
+      .query(r#"
+        CREATE api_keys SET
+          name = $api_key.name,
+          key_hash = crypto::sha256($key),
+          prefix = $api_key.prefix,
+          restaurant_id = $restaurant_id,
+          scopes = $api_key.scopes,
+          created_by = $api_key.created_by,
+          is_active = $api_key.is_active,
+          expires_at = $expires_at,
+          created_at = time::now(),
+          updated_at = time::now()
+      "#)
+      .bind(("api_key", api_key.clone()))
+      .bind(("key", key.to_string()))
+      .bind(("expires_at", api_key.expires_at.map(Datetime::from)))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::CreationFailed)
+  }
+
+  async fn find_all(&self) -> Result<Vec<ApiKey>, Error> {
+    let api_keys: Vec<ApiKey> = self.db
+      .query("SELECT * FROM api_keys WHERE restaurant_id = $restaurant_id ORDER BY created_at DESC")
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(api_keys)
+  }
+
+  async fn find_by_id(&self, id: String) -> Result<Option<ApiKey>, Error> {
+    let api_key: Option<ApiKey> = self.db
+      .query("SELECT * FROM type::thing($tb, $id) WHERE restaurant_id = $restaurant_id")
+      .bind(("tb", "api_keys"))
+      .bind(("id", id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(api_key)
+  }
+
+  async fn find_by_key(&self, key: &str) -> Result<Option<ApiKey>, Error> {
+    let api_key: Option<ApiKey> = self.db
+      .query(r#"
+        SELECT * FROM api_keys
+        WHERE key_hash = crypto::sha256($key) AND is_active = true AND revoked_at = NONE
+          AND restaurant_id = $restaurant_id
+        LIMIT 1
+      "#)
+      .bind(("key", key.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(api_key)
+  }
+
+  async fn revoke(&self, id: String) -> Result<ApiKey, Error> {
+    let result: Option<ApiKey> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id) 
+        SET 
+          is_active = false,
+          revoked_at = time::now(),
+          updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
+      "#)
+      .bind(("tb", "api_keys"))
+      .bind(("id", id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::InvalidInput("API key not found".to_string()))
+  }
+
+  // Como mucho una escritura por minuto y clave, no en cada petición
+  async fn touch_last_used(&self, id: String) -> Result<(), Error> {
+    let _: Vec<ApiKey> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id)
+        SET last_used_at = time::now()
+        WHERE last_used_at = NONE OR last_used_at < time::now() - 1m
+      "#)
+      .bind(("tb", "api_keys"))
+      .bind(("id", id))
+      .await?
+      .take(0)?;
+
+    Ok(())
+  }
+
+  async fn purge_revoked(&self, revoked_before: DateTime<Utc>) -> Result<u64, Error> {
+    let purged: Vec<ApiKey> = self.db
+      .query(r#"
+        DELETE api_keys
+        WHERE revoked_at != NONE AND revoked_at <= $revoked_before
+        RETURN BEFORE
+      "#)
+      .bind(("revoked_before", Datetime::from(revoked_before)))
+      .await?
+      .take(0)?;
+
+    Ok(purged.len() as u64)
+  }
+}
\ No newline at end of file
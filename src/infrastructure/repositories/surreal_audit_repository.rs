@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::{Datetime, Thing};
+use std::sync::Arc;
+
+use crate::domain::entities::audit_event::{AuditEvent, AuditFilter};
+use crate::domain::repositories::audit_repository::AuditRepository;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Cada evento queda en el registro del restaurante en el que ocurrió
+pub struct SurrealAuditRepository {
+  db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
+}
+
+impl SurrealAuditRepository {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
+    Self {
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
+    }
+  }
+}
+
+#[derive(Deserialize)]
+struct Count {
+  count: u64,
+}
+
+#[async_trait]
+impl AuditRepository for SurrealAuditRepository {
+  async fn create(&self, event: &AuditEvent) -> Result<(), Error> {
+    let _: Vec<AuditEvent> = self.db
+      .query(r#"
+        CREATE audit_events SET
+          action = $action,
+          outcome = $outcome,
+          actor_id = $actor_id,
+          impersonator_id = $impersonator_id,
+          restaurant_id = $restaurant_id,
+          target_type = $target_type,
+          target_id = $target_id,
+          ip_address = $ip_address,
+          user_agent = $user_agent,
+          reason = $reason,
+          changes = $changes,
+          created_at = $created_at
+      "#)
+      .bind(("action", event.action))
+      .bind(("outcome", event.outcome))
+      .bind(("actor_id", event.actor_id.clone()))
+      .bind(("impersonator_id", event.impersonator_id.clone()))
+      .bind(("target_type", event.target_type.clone()))
+      .bind(("target_id", event.target_id.clone()))
+      .bind(("ip_address", event.ip_address.clone()))
+      .bind(("user_agent", event.user_agent.clone()))
+      .bind(("reason", event.reason.clone()))
+      .bind(("changes", event.changes.clone()))
+      .bind(("created_at", Datetime::from(event.created_at)))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(())
+  }
+
+  async fn find_all(&self, filter: &AuditFilter, offset: u64, limit: u64) -> Result<(Vec<AuditEvent>, u64), Error> {
+    // Mismo WHERE para la página y para el total
+    let condition = r#"
+      restaurant_id = $restaurant_id
+      AND ($actor_id = NONE OR actor_id = $actor_id)
+      AND ($impersonator_id = NONE OR impersonator_id = $impersonator_id)
+      AND ($target_type = NONE OR target_type = $target_type)
+      AND ($target_id = NONE OR target_id = $target_id)
+      AND ($action = NONE OR action = $action)
+      AND ($outcome = NONE OR outcome = $outcome)
+      AND ($ip_address = NONE OR ip_address = $ip_address)
+      AND ($from = NONE OR created_at >= $from)
+      AND ($to = NONE OR created_at <= $to)
+    "#;
+
+    let mut response = self.db
+      .query(format!(
+        "SELECT * FROM audit_events WHERE {} ORDER BY created_at DESC LIMIT $limit START $offset;
+         SELECT count() FROM audit_events WHERE {} GROUP ALL;",
+        condition, condition
+      ))
+      .bind(("actor_id", filter.actor_id.as_deref().map(|id| Thing::from(("users", id)))))
+      .bind(("impersonator_id", filter.impersonator_id.as_deref().map(|id| Thing::from(("users", id)))))
+      .bind(("target_type", filter.target_type.clone()))
+      .bind(("target_id", filter.target_id.clone()))
+      .bind(("action", filter.action))
+      .bind(("outcome", filter.outcome))
+      .bind(("ip_address", filter.ip_address.clone()))
+      .bind(("from", filter.from.map(Datetime::from)))
+      .bind(("to", filter.to.map(Datetime::from)))
+      .bind(("limit", limit))
+      .bind(("offset", offset))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?;
+
+    let events: Vec<AuditEvent> = response.take(0)?;
+    let total: Option<Count> = response.take(1)?;
+
+    Ok((events, total.map(|total| total.count).unwrap_or(0)))
+  }
+}
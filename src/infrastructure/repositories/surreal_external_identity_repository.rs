@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::Datetime;
+use std::sync::Arc;
+
+use crate::domain::entities::external_identity::ExternalIdentity;
+use crate::domain::repositories::external_identity_repository::ExternalIdentityRepository;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Una misma cuenta externa se enlaza por separado en cada restaurante
+pub struct SurrealExternalIdentityRepository {
+  db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
+}
+
+impl SurrealExternalIdentityRepository {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
+    Self {
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
+    }
+  }
+}
+
+#[async_trait]
+impl ExternalIdentityRepository for SurrealExternalIdentityRepository {
+  async fn find_by_subject(&self, issuer: &str, subject: &str) -> Result<Option<ExternalIdentity>, Error> {
+    let identity: Option<ExternalIdentity> = self.db
+      .query("SELECT * FROM external_identities WHERE issuer = $issuer AND subject = $subject AND restaurant_id = $restaurant_id LIMIT 1")
+      .bind(("issuer", issuer.to_string()))
+      .bind(("subject", subject.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(identity)
+  }
+
+  async fn create(&self, identity: &ExternalIdentity) -> Result<ExternalIdentity, Error> {
+    let created: Option<ExternalIdentity> = self.db
+      .query(r#"
+        CREATE external_identities SET
+          user_id = $user_id,
+          restaurant_id = $restaurant_id,
+          issuer = $issuer,
+          subject = $subject,
+          email = $email,
+          created_at = $created_at,
+          last_login_at = time::now()
+      "#)
+      .bind(("user_id", identity.user_id.clone()))
+      .bind(("issuer", identity.issuer.clone()))
+      .bind(("subject", identity.subject.clone()))
+      .bind(("email", identity.email.clone()))
+      .bind(("created_at", Datetime::from(identity.created_at)))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    created.ok_or(Error::CreationFailed)
+  }
+
+  async fn record_login(&self, issuer: &str, subject: &str, email: Option<String>) -> Result<(), Error> {
+    let _: Vec<ExternalIdentity> = self.db
+      .query(r#"
+        UPDATE external_identities
+        SET email = $email, last_login_at = time::now()
+        WHERE issuer = $issuer AND subject = $subject AND restaurant_id = $restaurant_id
+      "#)
+      .bind(("issuer", issuer.to_string()))
+      .bind(("subject", subject.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .bind(("email", email))
+      .await?
+      .take(0)?;
+
+    Ok(())
+  }
+}
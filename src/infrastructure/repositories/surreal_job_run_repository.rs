@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::Datetime;
+use std::sync::Arc;
+
+use crate::domain::entities::job_run::JobRun;
+use crate::domain::repositories::job_run_repository::JobRunRepository;
+use crate::domain::error::Error;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+pub struct SurrealJobRunRepository {
+  db: Arc<Surreal<Client>>,
+}
+
+impl SurrealJobRunRepository {
+  pub fn new(connection: &DatabaseConnection) -> Self {
+    Self { 
+      db: connection.get_client()
+    }
+  }
+}
+
+#[async_trait]
+impl JobRunRepository for SurrealJobRunRepository {
+  async fn save(&self, job_run: &JobRun) -> Result<JobRun, Error> {
+    let result: Option<JobRun> = self.db
+      .query(r#"
+        UPSERT type::thing("job_runs", $name) SET
+          name = $name,
+          schedule = $schedule,
+          last_started_at = $last_started_at,
+          last_finished_at = $last_finished_at,
+          last_outcome = $last_outcome,
+          last_message = $last_message,
+          next_run_at = $next_run_at,
+          updated_at = time::now()
+      "#)
+      .bind(("name", job_run.name.clone()))
+      .bind(("schedule", job_run.schedule.clone()))
+      .bind(("last_started_at", job_run.last_started_at.map(Datetime::from)))
+      .bind(("last_finished_at", job_run.last_finished_at.map(Datetime::from)))
+      .bind(("last_outcome", job_run.last_outcome.clone()))
+      .bind(("last_message", job_run.last_message.clone()))
+      .bind(("next_run_at", job_run.next_run_at.map(Datetime::from)))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::CreationFailed)
+  }
+}
@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use std::sync::Arc;
+use surrealdb::sql::Thing as Record;
+
+use crate::domain::entities::location::Location;
+use crate::domain::repositories::location_repository::LocationRepository;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Sucursales del tenant con el que se crea y asignación del personal a cada una
+pub struct SurrealLocationRepository {
+  db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
+}
+
+impl SurrealLocationRepository {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
+    Self {
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
+    }
+  }
+}
+
+#[async_trait]
+impl LocationRepository for SurrealLocationRepository {
+  async fn create(&self, location: &Location) -> Result<Location, Error> {
+    let result: Option<Location> = self.db
+      .query(r#"
+        CREATE locations SET
+          name = $location.name,
+          address = $location.address,
+          timezone = $location.timezone,
+          currency = $location.currency,
+          business_hours = $location.business_hours,
+          is_active = true,
+          restaurant_id = $restaurant_id,
+          created_at = time::now(),
+          updated_at = time::now()
+      "#)
+      .bind(("location", location.clone()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::CreationFailed)
+  }
+
+  async fn find_by_id(&self, id: String) -> Result<Option<Location>, Error> {
+    let location: Option<Location> = self.db
+      .query("SELECT * FROM type::thing($tb, $id) WHERE is_active = true AND restaurant_id = $restaurant_id")
+      .bind(("tb", "locations"))
+      .bind(("id", id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(location)
+  }
+
+  async fn find_by_name(&self, name: &str) -> Result<Option<Location>, Error> {
+    let location: Option<Location> = self.db
+      .query("SELECT * FROM locations WHERE name = $name AND restaurant_id = $restaurant_id LIMIT 1")
+      .bind(("name", name.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(location)
+  }
+
+  async fn find_all(&self) -> Result<Vec<Location>, Error> {
+    let locations: Vec<Location> = self.db
+      .query("SELECT * FROM locations WHERE is_active = true AND restaurant_id = $restaurant_id ORDER BY name")
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(locations)
+  }
+
+  async fn update(&self, id: String, location: &Location) -> Result<Location, Error> {
+    let result: Option<Location> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id) SET
+          name = $location.name,
+          address = $location.address,
+          timezone = $location.timezone,
+          currency = $location.currency,
+          business_hours = $location.business_hours,
+          updated_at = time::now()
+        WHERE is_active = true AND restaurant_id = $restaurant_id
+      "#)
+      .bind(("tb", "locations"))
+      .bind(("id", id))
+      .bind(("location", location.clone()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::LocationNotFound)
+  }
+
+  // Baja lógica: las asignaciones se conservan pero dejan de valer mientras la sucursal esté inactiva
+  async fn delete(&self, id: String) -> Result<(), Error> {
+    let result: Option<Location> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id) SET
+          is_active = false,
+          updated_at = time::now()
+        WHERE is_active = true AND restaurant_id = $restaurant_id
+      "#)
+      .bind(("tb", "locations"))
+      .bind(("id", id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    result.map(|_| ()).ok_or(Error::LocationNotFound)
+  }
+
+  async fn find_user_locations(&self, user_id: String) -> Result<Vec<Location>, Error> {
+    let locations: Vec<Location> = self.db
+      .query(r#"
+        SELECT VALUE location.* FROM users_locations
+        WHERE user = type::thing("users", $user_id)
+          AND location.restaurant_id = $restaurant_id
+          AND location.is_active = true
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(locations)
+  }
+
+  async fn is_user_assigned(&self, user_id: String, location_id: String) -> Result<bool, Error> {
+    let assignments: Vec<Record> = self.db
+      .query(r#"
+        SELECT VALUE id FROM users_locations
+        WHERE user = type::thing("users", $user_id)
+          AND location = type::thing("locations", $location_id)
+          AND location.restaurant_id = $restaurant_id
+          AND location.is_active = true
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("location_id", location_id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(!assignments.is_empty())
+  }
+
+  // Asignar a alguien que ya está en la sucursal no hace nada
+  async fn assign_user(&self, user_id: String, location_id: String, assigned_by: String) -> Result<(), Error> {
+    let assigned: Vec<Record> = self.db
+      .query(r#"
+        LET $user = (SELECT VALUE id FROM type::thing("users", $user_id) WHERE restaurant_id = $restaurant_id)[0];
+        LET $location = (SELECT VALUE id FROM type::thing("locations", $location_id)
+          WHERE is_active = true AND restaurant_id = $restaurant_id)[0];
+        LET $existing = SELECT VALUE id FROM users_locations WHERE user = $user AND location = $location;
+        LET $assigned = IF $user = NONE OR $location = NONE {
+          []
+        } ELSE IF $existing != [] {
+          $existing
+        } ELSE {
+          (CREATE users_locations SET
+            user = $user,
+            location = $location,
+            assigned_by = type::thing("users", $assigned_by),
+            assigned_at = time::now()
+          RETURN VALUE id)
+        };
+        RETURN $assigned;
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("location_id", location_id))
+      .bind(("assigned_by", assigned_by))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(4)?;
+
+    if assigned.is_empty() {
+      return Err(Error::UserNotFound);
+    }
+
+    Ok(())
+  }
+
+  async fn remove_user(&self, user_id: String, location_id: String) -> Result<(), Error> {
+    let removed: Vec<Record> = self.db
+      .query(r#"
+        LET $assignments = SELECT VALUE id FROM users_locations
+          WHERE user = type::thing("users", $user_id)
+          AND location = type::thing("locations", $location_id)
+          AND location.restaurant_id = $restaurant_id;
+        DELETE $assignments;
+        DELETE users_roles WHERE $assignments != []
+          AND user = type::thing("users", $user_id)
+          AND location = type::thing("locations", $location_id);
+        RETURN $assignments;
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("location_id", location_id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(3)?;
+
+    if removed.is_empty() {
+      return Err(Error::UserNotFound);
+    }
+
+    Ok(())
+  }
+}
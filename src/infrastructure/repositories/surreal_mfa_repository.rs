@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use std::sync::Arc;
+
+use crate::domain::entities::mfa::TotpCredential;
+use crate::domain::repositories::mfa_repository::MfaRepository;
+use crate::domain::error::Error;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+pub struct SurrealMfaRepository {
+  db: Arc<Surreal<Client>>,
+}
+
+impl SurrealMfaRepository {
+  pub fn new(connection: &DatabaseConnection) -> Self {
+    Self { 
+      db: connection.get_client()
+    }
+  }
+}
+
+#[async_trait]
+impl MfaRepository for SurrealMfaRepository {
+  async fn find_by_user(&self, user_id: String) -> Result<Option<TotpCredential>, Error> {
+    let credential: Option<TotpCredential> = self.db
+      .query(r#"
+        SELECT * FROM mfa_credentials
+        WHERE user_id = type::thing("users", $user_id)
+        LIMIT 1
+      "#)
+      .bind(("user_id", user_id))
+      .await?
+      .take(0)?;
+
+    Ok(credential)
+  }
+
+  async fn save_pending(&self, credential: &TotpCredential) -> Result<TotpCredential, Error> {
+    let _: Vec<TotpCredential> = self.db
+      .query("DELETE mfa_credentials WHERE user_id = $user_id AND enabled = false")
+      .bind(("user_id", credential.user_id.clone()))
+      .await?
+      .take(0)?;
+
+    let result: Option<TotpCredential> = self.db.create("mfa_credentials").content(credential.clone()).await?;
+
+    result.ok_or(Error::CreationFailed)
+  }
+
+  async fn enable(&self, user_id: String, recovery_codes: Vec<String>) -> Result<TotpCredential, Error> {
+    let result: Option<TotpCredential> = self.db
+      .query(r#"
+        UPDATE mfa_credentials
+        SET
+          enabled = true,
+          recovery_codes = array::map($codes, |$code| crypto::sha256($code)),
+          confirmed_at = time::now(),
+          updated_at = time::now()
+        WHERE user_id = type::thing("users", $user_id)
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("codes", recovery_codes))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::UserUpdateError("Failed to enable two-factor authentication".to_string()))
+  }
+
+  async fn record_used_step(&self, user_id: String, step: i64) -> Result<bool, Error> {
+    let updated: Vec<TotpCredential> = self.db
+      .query(r#"
+        UPDATE mfa_credentials
+        SET
+          last_used_step = $step,
+          updated_at = time::now()
+        WHERE user_id = type::thing("users", $user_id)
+          AND (last_used_step = NONE OR last_used_step < $step)
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("step", step))
+      .await?
+      .take(0)?;
+
+    Ok(!updated.is_empty())
+  }
+
+  async fn consume_recovery_code(&self, user_id: String, code: &str) -> Result<bool, Error> {
+    let updated: Vec<TotpCredential> = self.db
+      .query(r#"
+        LET $hash = crypto::sha256($code);
+        UPDATE mfa_credentials
+        SET
+          recovery_codes -= $hash,
+          updated_at = time::now()
+        WHERE user_id = type::thing("users", $user_id)
+          AND enabled = true
+          AND recovery_codes CONTAINS $hash
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("code", code.to_string()))
+      .await?
+      .take(1)?;
+
+    Ok(!updated.is_empty())
+  }
+
+  async fn replace_recovery_codes(&self, user_id: String, recovery_codes: Vec<String>) -> Result<(), Error> {
+    let _: Vec<TotpCredential> = self.db
+      .query(r#"
+        UPDATE mfa_credentials
+        SET
+          recovery_codes = array::map($codes, |$code| crypto::sha256($code)),
+          updated_at = time::now()
+        WHERE user_id = type::thing("users", $user_id) AND enabled = true
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("codes", recovery_codes))
+      .await?
+      .take(0)?;
+
+    Ok(())
+  }
+
+  async fn delete(&self, user_id: String) -> Result<(), Error> {
+    let _: Vec<TotpCredential> = self.db
+      .query(r#"DELETE mfa_credentials WHERE user_id = type::thing("users", $user_id)"#)
+      .bind(("user_id", user_id))
+      .await?
+      .take(0)?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::Datetime;
+use std::sync::Arc;
+
+use crate::domain::entities::oidc_authorization::OidcAuthorization;
+use crate::domain::repositories::oidc_authorization_repository::OidcAuthorizationRepository;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// El `state` solo se canjea en el restaurante en el que empezó el login
+pub struct SurrealOidcAuthorizationRepository {
+  db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
+}
+
+impl SurrealOidcAuthorizationRepository {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
+    Self {
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
+    }
+  }
+}
+
+#[async_trait]
+impl OidcAuthorizationRepository for SurrealOidcAuthorizationRepository {
+  async fn create(&self, authorization: &OidcAuthorization) -> Result<(), Error> {
+    let created: Option<OidcAuthorization> = self.db
+      .query(r#"
+        CREATE oidc_authorizations SET
+          state = $state,
+          nonce = $nonce,
+          code_verifier = $code_verifier,
+          restaurant_id = $restaurant_id,
+          expires_at = $expires_at,
+          created_at = $created_at
+      "#)
+      .bind(("state", authorization.state.clone()))
+      .bind(("nonce", authorization.nonce.clone()))
+      .bind(("code_verifier", authorization.code_verifier.clone()))
+      .bind(("expires_at", Datetime::from(authorization.expires_at)))
+      .bind(("created_at", Datetime::from(authorization.created_at)))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    created.map(|_| ()).ok_or(Error::CreationFailed)
+  }
+
+  async fn consume(&self, state: &str) -> Result<Option<OidcAuthorization>, Error> {
+    let deleted: Vec<OidcAuthorization> = self.db
+      .query(r#"
+        DELETE oidc_authorizations
+        WHERE state = $state AND restaurant_id = $restaurant_id
+        RETURN BEFORE
+      "#)
+      .bind(("state", state.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    // Una vencida también se borra, pero no se acepta
+    Ok(deleted.into_iter().find(|authorization| authorization.expires_at > chrono::Utc::now()))
+  }
+
+  async fn cleanup_expired(&self) -> Result<u64, Error> {
+    let deleted: Vec<OidcAuthorization> = self.db
+      .query(r#"
+        DELETE oidc_authorizations
+        WHERE expires_at <= time::now()
+        RETURN BEFORE
+      "#)
+      .await?
+      .take(0)?;
+
+    Ok(deleted.len() as u64)
+  }
+}
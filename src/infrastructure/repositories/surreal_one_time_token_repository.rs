@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use std::sync::Arc;
+
+use crate::domain::entities::one_time_token::{OneTimeToken, TokenPurpose};
+use crate::domain::repositories::one_time_token_repository::OneTimeTokenRepository;
+use crate::domain::error::Error;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+pub struct SurrealOneTimeTokenRepository {
+  db: Arc<Surreal<Client>>,
+}
+
+impl SurrealOneTimeTokenRepository {
+  pub fn new(connection: &DatabaseConnection) -> Self {
+    Self { 
+      db: connection.get_client()
+    }
+  }
+}
+
+#[async_trait]
+impl OneTimeTokenRepository for SurrealOneTimeTokenRepository {
+  async fn create(&self, token: &OneTimeToken) -> Result<OneTimeToken, Error> {
+    let result: Option<OneTimeToken> = self.db.create("one_time_tokens").content(token.clone()).await?;
+
+    result.ok_or(Error::CreationFailed)
+  }
+
+  async fn find_by_jti(&self, jti: &str) -> Result<Option<OneTimeToken>, Error> {
+    let token: Option<OneTimeToken> = self.db
+      .query("SELECT * FROM one_time_tokens WHERE jti = $jti LIMIT 1")
+      .bind(("jti", jti.to_string()))
+      .await?
+      .take(0)?;
+
+    Ok(token)
+  }
+
+  async fn find_latest_for_user(&self, user_id: String, purpose: TokenPurpose) -> Result<Option<OneTimeToken>, Error> {
+    let token: Option<OneTimeToken> = self.db
+      .query(r#"
+        SELECT * FROM one_time_tokens
+        WHERE user_id = type::thing("users", $user_id) AND purpose = $purpose
+        ORDER BY created_at DESC
+        LIMIT 1
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("purpose", purpose))
+      .await?
+      .take(0)?;
+
+    Ok(token)
+  }
+
+  async fn consume(&self, jti: &str) -> Result<bool, Error> {
+    let updated: Vec<OneTimeToken> = self.db
+      .query(r#"
+        UPDATE one_time_tokens
+        SET used_at = time::now()
+        WHERE jti = $jti AND used_at = NONE AND invalidated = false
+      "#)
+      .bind(("jti", jti.to_string()))
+      .await?
+      .take(0)?;
+
+    Ok(!updated.is_empty())
+  }
+
+  async fn invalidate_for_user(&self, user_id: String, purpose: TokenPurpose) -> Result<(), Error> {
+    let _: Vec<OneTimeToken> = self.db
+      .query(r#"
+        UPDATE one_time_tokens
+        SET invalidated = true
+        WHERE user_id = type::thing("users", $user_id) AND purpose = $purpose AND used_at = NONE
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("purpose", purpose))
+      .await?
+      .take(0)?;
+
+    Ok(())
+  }
+
+  async fn cleanup_expired(&self) -> Result<u64, Error> {
+    let deleted: Vec<OneTimeToken> = self.db
+      .query(r#"
+        DELETE one_time_tokens
+        WHERE expires_at <= time::now()
+        RETURN BEFORE
+      "#)
+      .await?
+      .take(0)?;
+
+    Ok(deleted.len() as u64)
+  }
+}
\ No newline at end of file
@@ -2,21 +2,27 @@ use async_trait::async_trait;
 use surrealdb::Surreal;
 use surrealdb::engine::remote::ws::Client;
 use std::sync::Arc;
+use surrealdb::sql::Thing as Record;
 
 use crate::domain::entities::permission::Permission;
 use crate::domain::repositories::permission_repository::PermissionRepository;
 use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
 
 use crate::infrastructure::database::surreal_connection::DatabaseConnection;
 
+// Catálogo de permisos y enlaces `roles_permissions` de la base del tenant: un restaurante con base
+// dedicada tiene allí sus roles y los permisos que estos enlazan
 pub struct SurrealPermissionRepository {
   db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
 }
 
 impl SurrealPermissionRepository {
-  pub fn new(connection: &DatabaseConnection) -> Self {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
     Self { 
-      db: connection.get_client()
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
     }
   }
 }
@@ -32,9 +38,8 @@ impl PermissionRepository for SurrealPermissionRepository {
   async fn find_role_permissions(&self, role_id: String) -> Result<Vec<Permission>, Error> {
     let permissions: Vec<Permission> = self.db
       .query(r#"
-        SELECT permission.* FROM role_permission
-        RELATE type::thing("role", $role_id) -> role_permission -> permission
-        WHERE permission.is_active = true
+        SELECT VALUE permission.* FROM roles_permissions
+        WHERE role = type::thing("roles", $role_id) AND permission.is_active = true
       "#)
       .bind(("role_id", role_id.clone()))
       .await?
@@ -43,20 +48,33 @@ impl PermissionRepository for SurrealPermissionRepository {
     Ok(permissions)
   }
 
+  // Mismo enlace que usan el alta de roles y la de restaurantes; solo sobre roles del propio tenant
   async fn assign_permission_to_role(&self, role_id: String, permission_id: String) -> Result<(), Error> {
-    let result: Option<()> = self.db
+    let assigned: Vec<Record> = self.db
       .query(r#"
-        RELATE $role_id -> role_permission -> $permission_id
+        LET $role = (SELECT VALUE id FROM type::thing("roles", $role_id) WHERE restaurant_id = $restaurant_id)[0];
+        LET $permission = (SELECT VALUE id FROM type::thing("permissions", $permission_id) WHERE is_active = true)[0];
+        LET $existing = SELECT VALUE id FROM roles_permissions WHERE role = $role AND permission = $permission;
+        LET $assigned = IF $role = NONE OR $permission = NONE {
+          []
+        } ELSE IF $existing != [] {
+          $existing
+        } ELSE {
+          (CREATE roles_permissions SET role = $role, permission = $permission RETURN VALUE id)
+        };
+        RETURN $assigned;
       "#)
-      .bind(("role_id", role_id.clone()))
-      .bind(("permission_id", permission_id.clone()))
+      .bind(("role_id", role_id))
+      .bind(("permission_id", permission_id))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
-      .take(0)?;
+      .take(4)?;
 
-    match result {
-      Some(_) => Ok(()),
-      None => Err(Error::PermissionNotFound)
+    if assigned.is_empty() {
+      return Err(Error::PermissionNotFound);
     }
+
+    Ok(())
   }
 
   async fn find_by_id(&self, id: String) -> Result<Option<Permission>, Error> {
@@ -119,20 +137,25 @@ impl PermissionRepository for SurrealPermissionRepository {
   }
 
   async fn remove_permission_from_role(&self, role_id: String, permission_id: String) -> Result<(), Error> {
-    let result: Option<()> = self.db
+    let removed: Vec<Record> = self.db
       .query(r#"
-        DELETE FROM role_permission 
-        WHERE in = type::thing("roles", $role_id) 
-        AND out = type::thing("permissions", $permission_id)
+        LET $links = SELECT VALUE id FROM roles_permissions
+          WHERE role = type::thing("roles", $role_id)
+          AND permission = type::thing("permissions", $permission_id)
+          AND role.restaurant_id = $restaurant_id;
+        DELETE $links;
+        RETURN $links;
       "#)
-      .bind(("role_id", role_id.clone()))
-      .bind(("permission_id", permission_id.clone()))
+      .bind(("role_id", role_id))
+      .bind(("permission_id", permission_id))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
-      .take(0)?;
-    
-    match result {
-      Some(_) => Ok(()),
-      None => Err(Error::PermissionNotFound)
+      .take(2)?;
+
+    if removed.is_empty() {
+      return Err(Error::PermissionNotFound);
     }
+
+    Ok(())
   }
 }
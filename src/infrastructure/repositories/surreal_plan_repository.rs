@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use std::sync::Arc;
+
+use crate::domain::entities::plan::Plan;
+use crate::domain::repositories::plan_repository::PlanRepository;
+use crate::domain::error::Error;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Catálogo de planes de la plataforma, igual para todos los restaurantes
+pub struct SurrealPlanRepository {
+  db: Arc<Surreal<Client>>,
+}
+
+impl SurrealPlanRepository {
+  pub fn new(connection: &DatabaseConnection) -> Self {
+    Self {
+      db: connection.get_client()
+    }
+  }
+}
+
+#[async_trait]
+impl PlanRepository for SurrealPlanRepository {
+  async fn find_by_id(&self, id: String) -> Result<Option<Plan>, Error> {
+    let plan: Option<Plan> = self.db
+      .query("SELECT * FROM type::thing($tb, $id)")
+      .bind(("tb", "plans"))
+      .bind(("id", id))
+      .await?
+      .take(0)?;
+
+    Ok(plan)
+  }
+
+  async fn find_by_code(&self, code: &str) -> Result<Option<Plan>, Error> {
+    let plan: Option<Plan> = self.db
+      .query("SELECT * FROM plans WHERE code = $code AND is_active = true LIMIT 1")
+      .bind(("code", code.to_string()))
+      .await?
+      .take(0)?;
+
+    Ok(plan)
+  }
+
+  async fn find_all(&self) -> Result<Vec<Plan>, Error> {
+    let plans: Vec<Plan> = self.db
+      .query("SELECT * FROM plans WHERE is_active = true ORDER BY created_at")
+      .await?
+      .take(0)?;
+
+    Ok(plans)
+  }
+}
@@ -0,0 +1,130 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use std::sync::Arc;
+
+use crate::domain::entities::pos_device::PosDevice;
+use crate::domain::repositories::pos_device_repository::PosDeviceRepository;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Cada terminal pertenece al restaurante en el que se registró
+pub struct SurrealPosDeviceRepository {
+  db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
+}
+
+impl SurrealPosDeviceRepository {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
+    Self { 
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
+    }
+  }
+}
+
+#[async_trait]
+impl PosDeviceRepository for SurrealPosDeviceRepository {
+  async fn create(&self, name: &str, secret: &str, registered_by: String) -> Result<PosDevice, Error> {
+    let result: Option<PosDevice> = self.db
+      .query(r#"
+        CREATE pos_devices SET
+          name = $name,
+          secret = CRYPTO::ARGON2::GENERATE($secret),
+          is_active = true,
+          registered_by = type::thing("users", $registered_by),
+          restaurant_id = $restaurant_id,
+          created_at = time::now(),
+          updated_at = time::now()
+      "#)
+      .bind(("name", name.to_string()))
+      .bind(("secret", secret.to_string()))
+      .bind(("registered_by", registered_by))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::CreationFailed)
+  }
+
+  async fn find_all(&self) -> Result<Vec<PosDevice>, Error> {
+    let devices: Vec<PosDevice> = self.db
+      .query("SELECT * FROM pos_devices WHERE restaurant_id = $restaurant_id ORDER BY created_at DESC")
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(devices)
+  }
+
+  async fn find_by_id(&self, id: String) -> Result<Option<PosDevice>, Error> {
+    let device: Option<PosDevice> = self.db
+      .query("SELECT * FROM type::thing($tb, $id) WHERE restaurant_id = $restaurant_id")
+      .bind(("tb", "pos_devices"))
+      .bind(("id", id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(device)
+  }
+
+  async fn authenticate(&self, id: String, secret: &str) -> Result<Option<PosDevice>, Error> {
+    let result: Option<PosDevice> = self.db
+      .query(r#"
+        LET $device = (SELECT * FROM type::thing("pos_devices", $id) WHERE is_active = true AND restaurant_id = $restaurant_id)[0];
+        IF $device.secret != NONE AND CRYPTO::ARGON2::COMPARE($device.secret, $secret) {
+          RETURN $device
+        } ELSE {
+          RETURN NONE
+        }
+      "#)
+      .bind(("id", id))
+      .bind(("secret", secret.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(1)?;
+
+    Ok(result)
+  }
+
+  async fn deactivate(&self, id: String) -> Result<PosDevice, Error> {
+    let result: Option<PosDevice> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id) 
+        SET 
+          is_active = false,
+          current_user = NONE,
+          updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
+      "#)
+      .bind(("tb", "pos_devices"))
+      .bind(("id", id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::InvalidInput("POS device not found".to_string()))
+  }
+
+  async fn set_current_user(&self, id: String, user_id: String) -> Result<PosDevice, Error> {
+    let result: Option<PosDevice> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id) 
+        SET 
+          current_user = type::thing("users", $user_id),
+          last_used_at = time::now(),
+          updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
+      "#)
+      .bind(("tb", "pos_devices"))
+      .bind(("id", id))
+      .bind(("user_id", user_id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::InvalidInput("POS device not found".to_string()))
+  }
+}
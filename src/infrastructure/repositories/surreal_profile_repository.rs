@@ -1,4 +1,6 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use surrealdb::sql::Datetime;
 use surrealdb::Surreal;
 use surrealdb::engine::remote::ws::Client;
 use std::sync::Arc;
@@ -7,15 +9,19 @@ use crate::infrastructure::database::surreal_connection::DatabaseConnection;
 use crate::domain::entities::profile::Profile;
 use crate::domain::repositories::profile_repository::ProfileRepository;
 use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
 
+// El perfil no guarda el tenant: se filtra por el restaurante de su usuario
 pub struct SurrealProfileRepository {
   db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
 }
 
 impl SurrealProfileRepository {
-  pub fn new(connection: &DatabaseConnection) -> Self {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
     Self { 
-      db: connection.get_client()
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
     }
   }
 }
@@ -29,7 +35,17 @@ impl ProfileRepository for SurrealProfileRepository {
   }
 
   async fn update(&self, id: String, profile: &Profile) -> Result<Profile, Error> {
-    let result: Option<Profile> = self.db.update(("profiles", id)).content(profile.clone()).await?;
+    let result: Option<Profile> = self.db
+      .query(r#"
+        UPDATE type::thing("profiles", $id)
+        CONTENT $profile
+        WHERE user.restaurant_id = $restaurant_id
+      "#)
+      .bind(("id", id))
+      .bind(("profile", profile.clone()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
 
     result.ok_or(Error::ProfileNotFound)
   }
@@ -40,9 +56,12 @@ impl ProfileRepository for SurrealProfileRepository {
         UPDATE type::thing("profiles", $id) 
         SET
           is_active = false,
+          deleted_at = time::now(),
           updated_at = time::now()
+        WHERE user.restaurant_id = $restaurant_id
       "#)
       .bind(("id", id))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
     
@@ -54,8 +73,9 @@ impl ProfileRepository for SurrealProfileRepository {
 
   async fn find_by_id(&self, id: String) -> Result<Option<Profile>, Error> {
     let profile: Option<Profile> = self.db
-      .query(r#"SELECT * FROM profiles type::thing("profiles", $id)"#)
+      .query(r#"SELECT * FROM type::thing("profiles", $id) WHERE user.restaurant_id = $restaurant_id"#)
       .bind(("id", id.clone()))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
 
@@ -65,12 +85,39 @@ impl ProfileRepository for SurrealProfileRepository {
   async fn find_by_user_id(&self, user_id: String) -> Result<Option<Profile>, Error> {
     let profile: Option<Profile> = self.db
       .query(r#"
-        SELECT * FROM profiles WHERE user_id = type::thing("profiles", $id)
+        SELECT * FROM profiles WHERE user = type::thing("users", $id) AND user.restaurant_id = $restaurant_id
       "#)
       .bind(("id", user_id.clone()))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
     
     Ok(profile)
   }
-}
+
+  async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64, Error> {
+    // Primero se suelta la referencia desde el usuario para no dejarla apuntando a un perfil borrado
+    let _: Vec<serde_json::Value> = self.db
+      .query(r#"
+        UPDATE users
+        SET profile = NONE
+        WHERE profile.deleted_at != NONE AND profile.deleted_at <= $deleted_before
+        RETURN NONE
+      "#)
+      .bind(("deleted_before", Datetime::from(deleted_before)))
+      .await?
+      .take(0)?;
+
+    let purged: Vec<Profile> = self.db
+      .query(r#"
+        DELETE profiles
+        WHERE deleted_at != NONE AND deleted_at <= $deleted_before
+        RETURN BEFORE
+      "#)
+      .bind(("deleted_before", Datetime::from(deleted_before)))
+      .await?
+      .take(0)?;
+
+    Ok(purged.len() as u64)
+  }
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use std::sync::Arc;
+
+use crate::domain::entities::restaurant::Restaurant;
+use crate::domain::repositories::restaurant_repository::RestaurantRepository;
+use crate::domain::value_objects::user_types::UserType;
+use crate::domain::error::Error;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Los restaurantes son datos de la plataforma: este repositorio no lleva tenant
+pub struct SurrealRestaurantRepository {
+  db: Arc<Surreal<Client>>,
+}
+
+impl SurrealRestaurantRepository {
+  pub fn new(connection: &DatabaseConnection) -> Self {
+    Self {
+      db: connection.get_client()
+    }
+  }
+}
+
+#[async_trait]
+impl RestaurantRepository for SurrealRestaurantRepository {
+  async fn find_by_id(&self, id: String) -> Result<Option<Restaurant>, Error> {
+    let restaurant: Option<Restaurant> = self.db
+      .query("SELECT * FROM type::thing($tb, $id)")
+      .bind(("tb", "restaurants"))
+      .bind(("id", id))
+      .await?
+      .take(0)?;
+
+    Ok(restaurant)
+  }
+
+  async fn find_by_slug(&self, slug: &str) -> Result<Option<Restaurant>, Error> {
+    let restaurant: Option<Restaurant> = self.db
+      .query("SELECT * FROM restaurants WHERE slug = $slug LIMIT 1")
+      .bind(("slug", slug.to_string()))
+      .await?
+      .take(0)?;
+
+    Ok(restaurant)
+  }
+
+  async fn find_all(&self) -> Result<Vec<Restaurant>, Error> {
+    let restaurants: Vec<Restaurant> = self.db
+      .query("SELECT * FROM restaurants ORDER BY name")
+      .await?
+      .take(0)?;
+
+    Ok(restaurants)
+  }
+
+  async fn create(&self, restaurant: &Restaurant) -> Result<Restaurant, Error> {
+    let created: Option<Restaurant> = self.db
+      .query(r#"
+        CREATE restaurants SET
+          name = $name,
+          slug = $slug,
+          database = $database,
+          is_active = $is_active,
+          created_at = time::now(),
+          updated_at = time::now()
+      "#)
+      .bind(("name", restaurant.name.clone()))
+      .bind(("slug", restaurant.slug.clone()))
+      .bind(("database", restaurant.database.clone()))
+      .bind(("is_active", restaurant.is_active))
+      .await?
+      .take(0)?;
+
+    created.ok_or(Error::CreationFailed)
+  }
+
+  async fn set_role_inheritance(&self, id: String, enabled: bool) -> Result<Restaurant, Error> {
+    let updated: Option<Restaurant> = self.db
+      .query(r#"
+        UPDATE type::thing("restaurants", $id) SET
+          inherit_role_permissions = $enabled,
+          updated_at = time::now()
+      "#)
+      .bind(("id", id))
+      .bind(("enabled", enabled))
+      .await?
+      .take(0)?;
+
+    updated.ok_or(Error::RestaurantNotFound)
+  }
+
+  async fn set_mfa_policy(&self, id: String, required_user_types: Option<Vec<UserType>>) -> Result<Restaurant, Error> {
+    let updated: Option<Restaurant> = self.db
+      .query(r#"
+        UPDATE type::thing("restaurants", $id) SET
+          mfa_required_user_types = $required_user_types,
+          updated_at = time::now()
+      "#)
+      .bind(("id", id))
+      .bind(("required_user_types", required_user_types))
+      .await?
+      .take(0)?;
+
+    updated.ok_or(Error::RestaurantNotFound)
+  }
+
+  async fn delete(&self, id: String) -> Result<(), Error> {
+    // La suscripción se va con el restaurante
+    let deleted: Option<Restaurant> = self.db
+      .query(r#"
+        DELETE type::thing("subscriptions", $id);
+        DELETE type::thing($tb, $id) RETURN BEFORE;
+      "#)
+      .bind(("tb", "restaurants"))
+      .bind(("id", id))
+      .await?
+      .take(1)?;
+
+    deleted.map(|_| ()).ok_or(Error::RestaurantNotFound)
+  }
+}
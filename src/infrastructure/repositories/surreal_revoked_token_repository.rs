@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::Datetime;
+use std::sync::Arc;
+
+use crate::domain::entities::revoked_token::RevokedToken;
+use crate::domain::repositories::revoked_token_repository::RevokedTokenRepository;
+use crate::domain::error::Error;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+pub struct SurrealRevokedTokenRepository {
+  db: Arc<Surreal<Client>>,
+}
+
+impl SurrealRevokedTokenRepository {
+  pub fn new(connection: &DatabaseConnection) -> Self {
+    Self { 
+      db: connection.get_client()
+    }
+  }
+}
+
+#[async_trait]
+impl RevokedTokenRepository for SurrealRevokedTokenRepository {
+  async fn revoke(&self, entry: &RevokedToken) -> Result<(), Error> {
+    let _: Vec<RevokedToken> = self.db
+      .query(r#"
+        CREATE revoked_tokens SET
+          jti = $jti,
+          user_id = $user_id,
+          issued_before = $issued_before,
+          expires_at = $expires_at,
+          created_at = time::now()
+      "#)
+      .bind(("jti", entry.jti.clone()))
+      .bind(("user_id", entry.user_id.clone()))
+      .bind(("issued_before", entry.issued_before.map(Datetime::from)))
+      .bind(("expires_at", Datetime::from(entry.expires_at)))
+      .await?
+      .take(0)?;
+
+    Ok(())
+  }
+
+  async fn find_matching(&self, jti: Option<String>, user_id: String, issued_at: DateTime<Utc>) -> Result<Option<RevokedToken>, Error> {
+    let entry: Option<RevokedToken> = self.db
+      .query(r#"
+        SELECT * FROM revoked_tokens
+        WHERE expires_at > time::now()
+          AND (
+            ($jti != NONE AND jti = $jti)
+            OR (user_id = type::thing("users", $user_id) AND issued_before != NONE AND issued_before >= $issued_at)
+          )
+        LIMIT 1
+      "#)
+      .bind(("jti", jti))
+      .bind(("user_id", user_id))
+      .bind(("issued_at", Datetime::from(issued_at)))
+      .await?
+      .take(0)?;
+
+    Ok(entry)
+  }
+
+  async fn cleanup_expired(&self) -> Result<u64, Error> {
+    let deleted: Vec<RevokedToken> = self.db
+      .query(r#"
+        DELETE revoked_tokens
+        WHERE expires_at <= time::now()
+        RETURN BEFORE
+      "#)
+      .await?
+      .take(0)?;
+
+    Ok(deleted.len() as u64)
+  }
+}
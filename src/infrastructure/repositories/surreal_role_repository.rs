@@ -1,71 +1,181 @@
 use async_trait::async_trait;
 use surrealdb::Surreal;
 use surrealdb::engine::remote::ws::Client;
+use serde_json::json;
 use std::sync::Arc;
+use surrealdb::sql::Thing as Record;
+use uuid::Uuid;
 
 use crate::domain::entities::role::Role;
 use crate::domain::repositories::role_repository::RoleRepository;
 use crate::domain::error::Error;
+use crate::domain::services::permission_guard::RequiredPermission;
+use crate::domain::value_objects::tenant::TenantContext;
 
 use crate::infrastructure::database::surreal_connection::DatabaseConnection;
 
+// Igual que en el alta de restaurantes: los permisos se buscan por nombre y se crean si faltan
+const CREATE_ROLE_QUERY: &str = r#"
+  BEGIN TRANSACTION;
+
+  LET $role = type::thing("roles", $role_id);
+
+  CREATE $role SET
+    name = $data.name,
+    description = $data.description,
+    hierarchy_level = $data.hierarchy_level,
+    is_active = $data.is_active,
+    restaurant_id = $restaurant_id,
+    created_at = time::now();
+
+  FOR $permission IN $permissions {
+    LET $name = string::concat($permission.resource, '.', $permission.action);
+    LET $existing = (SELECT VALUE id FROM permissions WHERE name = $name LIMIT 1)[0];
+    LET $permission_id = IF $existing = NONE {
+      (CREATE permissions SET
+        name = $name,
+        description = string::concat($permission.action, ' ', $permission.resource),
+        resource = $permission.resource,
+        action = $permission.action
+      RETURN VALUE id)[0]
+    } ELSE {
+      $existing
+    };
+
+    CREATE roles_permissions SET role = $role, permission = $permission_id;
+  };
+
+  COMMIT TRANSACTION;
+"#;
+
+// Un restaurante ve sus roles más los de la plataforma, pero solo puede modificar los suyos
 pub struct SurrealRoleRepository {
   db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
 }
 
 impl SurrealRoleRepository {
-  pub fn new(connection: &DatabaseConnection) -> Self {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
     Self { 
-      db: connection.get_client()
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
     }
   }
 }
 
 #[async_trait]
 impl RoleRepository for SurrealRoleRepository {
-  async fn create(&self, role: &Role) -> Result<Role, Error> {
-    let result: Option<Role> = self.db.create("roles").content(role.clone()).await?;
+  async fn create(&self, role: &Role, permissions: &[RequiredPermission]) -> Result<Role, Error> {
+    let role_id = Uuid::new_v4().simple().to_string();
+    let permissions: Vec<_> = permissions.iter()
+      .map(|permission| json!({ "resource": permission.resource, "action": permission.action }))
+      .collect();
+
+    self.db
+      .query(CREATE_ROLE_QUERY)
+      .bind(("role_id", role_id.clone()))
+      .bind(("data", role.clone()))
+      .bind(("permissions", permissions))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .check()?;
+
+    self.find_by_id(role_id).await?.ok_or(Error::CreationFailed)
+  }
 
-    result.ok_or(Error::CreationFailed)
+  async fn find_by_name(&self, name: &str) -> Result<Option<Role>, Error> {
+    let role: Option<Role> = self.db
+      .query(r#"
+        SELECT * FROM roles WHERE name = $name
+          AND (restaurant_id = NONE OR restaurant_id = $restaurant_id)
+        LIMIT 1
+      "#)
+      .bind(("name", name.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(role)
   }
 
   async fn find_user_roles(&self, user_id: String) -> Result<Vec<Role>, Error> {
     let roles: Vec<Role> = self.db
       .query(r#"
-        SELECT role.* FROM user_role
-        RELATE type::thing("user", $user_id) -> role
-        WHERE role.is_active = true
+        SELECT VALUE role.* FROM users_roles
+        WHERE user = type::thing("users", $user_id) AND user.restaurant_id = $restaurant_id
+          AND location = NONE AND role.is_active = true
       "#)
       .bind(("user_id", user_id.clone().to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
 
     Ok(roles)
   }
 
-  async fn assign_role_to_user(&self, user_id: String, role_id: String) -> Result<(), Error> {
-    let result: Option<()> = self.db
+  async fn find_user_location_roles(&self, user_id: String, location_id: String) -> Result<Vec<Role>, Error> {
+    let roles: Vec<Role> = self.db
       .query(r#"
-        RELATE type::thing("users", $user_id) -> user_role -> type::thing("roles", $role_id) SET
-          assigned_at = time::now()
+        SELECT VALUE role.* FROM users_roles
+        WHERE user = type::thing("users", $user_id) AND user.restaurant_id = $restaurant_id
+          AND location = type::thing("locations", $location_id) AND location.is_active = true
+          AND role.is_active = true
       "#)
-      .bind(("user_id", user_id.clone()))
-      .bind(("role_id", role_id.clone()))
+      .bind(("user_id", user_id))
+      .bind(("location_id", location_id))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
 
-    match result {
-      Some(_) => Ok(()),
-      None => Err(Error::RoleNotFound)
+    Ok(roles)
+  }
+
+  // Asignar un rol que el usuario ya tiene en ese mismo ámbito no hace nada
+  async fn assign_role_to_user(&self, user_id: String, role_id: String, location_id: Option<String>, assigned_by: String) -> Result<(), Error> {
+    let assigned: Vec<Record> = self.db
+      .query(r#"
+        LET $user = (SELECT VALUE id FROM type::thing("users", $user_id) WHERE restaurant_id = $restaurant_id)[0];
+        LET $role = (SELECT VALUE id FROM type::thing("roles", $role_id)
+          WHERE is_active = true AND (restaurant_id = NONE OR restaurant_id = $restaurant_id))[0];
+        LET $existing = SELECT VALUE id FROM users_roles WHERE user = $user AND role = $role AND location = $location;
+        LET $assigned = IF $user = NONE OR $role = NONE {
+          []
+        } ELSE IF $existing != [] {
+          $existing
+        } ELSE {
+          (CREATE users_roles SET
+            user = $user,
+            role = $role,
+            location = $location,
+            assigned_by = type::thing("users", $assigned_by),
+            assigned_at = time::now()
+          RETURN VALUE id)
+        };
+        RETURN $assigned;
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("role_id", role_id))
+      .bind(("location", location_id.map(|id| Record::from(("locations", id.as_str())))))
+      .bind(("assigned_by", assigned_by))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(4)?;
+
+    if assigned.is_empty() {
+      return Err(Error::RoleNotFound);
     }
+
+    Ok(())
   }
 
   async fn find_by_id(&self, id: String) -> Result<Option<Role>, Error> {
     let role: Option<Role> = self.db
       .query(r#"
         SELECT * FROM roles WHERE id = type::thing("roles", $id) AND is_active = true
+          AND (restaurant_id = NONE OR restaurant_id = $restaurant_id)
       "#)
       .bind(("id", id.clone()))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
 
@@ -74,7 +184,8 @@ impl RoleRepository for SurrealRoleRepository {
 
   async fn find_all(&self) -> Result<Vec<Role>, Error> {
     let roles: Vec<Role> = self.db
-      .query("SELECT * FROM roles WHERE is_active = true")
+      .query("SELECT * FROM roles WHERE is_active = true AND (restaurant_id = NONE OR restaurant_id = $restaurant_id)")
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
 
@@ -82,7 +193,20 @@ impl RoleRepository for SurrealRoleRepository {
   }
 
   async fn update(&self, id: String, role: &Role) -> Result<Role, Error> {
-    let result: Option<Role> = self.db.update(("roles", id)).content(role.clone()).await?;
+    let result: Option<Role> = self.db
+      .query(r#"
+        UPDATE type::thing("roles", $id) SET
+          name = $role.name,
+          description = $role.description,
+          hierarchy_level = $role.hierarchy_level,
+          is_active = $role.is_active
+        WHERE restaurant_id = $restaurant_id
+      "#)
+      .bind(("id", id))
+      .bind(("role", role.clone()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
 
     result.ok_or(Error::RoleNotFound)
   }
@@ -92,9 +216,11 @@ impl RoleRepository for SurrealRoleRepository {
       .query(r#"
         UPDATE type::thing($tb, $id) SET
           is_active = false
+        WHERE restaurant_id = $restaurant_id
       "#)
       .bind(("tb", "roles"))
       .bind(("id", id.clone()))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
 
@@ -104,19 +230,28 @@ impl RoleRepository for SurrealRoleRepository {
     }
   }
 
-  async fn remove_role_from_user(&self, user_id: String, role_id: String) -> Result<(), Error> {
-    let result: Option<Role> = self.db
+  async fn remove_role_from_user(&self, user_id: String, role_id: String, location_id: Option<String>) -> Result<(), Error> {
+    let removed: Vec<Record> = self.db
       .query(r#"
-        DELETE RELATE type::thing("users", $user_id) -> user_role -> type::thing("roles", $role_id)
+        LET $assignments = SELECT VALUE id FROM users_roles
+          WHERE user = type::thing("users", $user_id)
+          AND role = type::thing("roles", $role_id)
+          AND location = $location
+          AND user.restaurant_id = $restaurant_id;
+        DELETE $assignments;
+        RETURN $assignments;
       "#)
       .bind(("user_id", user_id.clone()))
       .bind(("role_id", role_id.clone()))
+      .bind(("location", location_id.map(|id| Record::from(("locations", id.as_str())))))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
-      .take(0)?;
+      .take(2)?;
 
-    match result {
-      Some(_) => Ok(()),
-      None => Err(Error::RoleNotFound)
+    if removed.is_empty() {
+      return Err(Error::RoleNotFound);
     }
+
+    Ok(())
   }
 }
\ No newline at end of file
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::Datetime;
+use std::sync::Arc;
+
+use crate::domain::entities::staff_pin::StaffPin;
+use crate::domain::repositories::staff_pin_repository::StaffPinRepository;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Los PINs se filtran por el restaurante de su usuario; `release_expired_locks` es mantenimiento y recorre todos
+pub struct SurrealStaffPinRepository {
+  db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
+}
+
+impl SurrealStaffPinRepository {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
+    Self { 
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
+    }
+  }
+}
+
+#[async_trait]
+impl StaffPinRepository for SurrealStaffPinRepository {
+  async fn set_pin(&self, user_id: String, pin: &str) -> Result<StaffPin, Error> {
+    let result: Option<StaffPin> = self.db
+      .query(r#"
+        LET $user = (SELECT VALUE id FROM type::thing("users", $user_id) WHERE restaurant_id = $restaurant_id)[0];
+        DELETE staff_pins WHERE user_id = $user;
+        CREATE staff_pins SET
+          user_id = $user,
+          pin = CRYPTO::ARGON2::GENERATE($pin),
+          failed_attempts = 0,
+          created_at = time::now(),
+          updated_at = time::now()
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("pin", pin.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(2)?;
+
+    result.ok_or(Error::CreationFailed)
+  }
+
+  async fn find_by_user(&self, user_id: String) -> Result<Option<StaffPin>, Error> {
+    let pin: Option<StaffPin> = self.db
+      .query(r#"SELECT * FROM staff_pins WHERE user_id = type::thing("users", $user_id) AND user_id.restaurant_id = $restaurant_id LIMIT 1"#)
+      .bind(("user_id", user_id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(pin)
+  }
+
+  async fn verify(&self, user_id: String, pin: &str) -> Result<bool, Error> {
+    let result: Option<bool> = self.db
+      .query(r#"
+        LET $stored = (SELECT VALUE pin FROM staff_pins
+          WHERE user_id = type::thing("users", $user_id) AND user_id.restaurant_id = $restaurant_id
+          LIMIT 1)[0];
+        RETURN $stored != NONE AND CRYPTO::ARGON2::COMPARE($stored, $pin);
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("pin", pin.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(1)?;
+
+    Ok(result.unwrap_or(false))
+  }
+
+  async fn update_failed_attempts(&self, user_id: String, attempts: i32) -> Result<StaffPin, Error> {
+    let result: Option<StaffPin> = self.db
+      .query(r#"
+        UPDATE staff_pins
+        SET
+          failed_attempts = $attempts,
+          locked_until = IF $attempts = 0 THEN NONE ELSE locked_until END,
+          updated_at = time::now()
+        WHERE user_id = type::thing("users", $user_id) AND user_id.restaurant_id = $restaurant_id
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .bind(("attempts", attempts))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::UserUpdateError("Staff PIN not found".to_string()))
+  }
+
+  async fn lock(&self, user_id: String, locked_until: DateTime<Utc>) -> Result<StaffPin, Error> {
+    let result: Option<StaffPin> = self.db
+      .query(r#"
+        UPDATE staff_pins
+        SET
+          locked_until = $locked_until,
+          updated_at = time::now()
+        WHERE user_id = type::thing("users", $user_id) AND user_id.restaurant_id = $restaurant_id
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .bind(("locked_until", Datetime::from(locked_until)))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::UserUpdateError("Staff PIN not found".to_string()))
+  }
+
+  async fn delete(&self, user_id: String) -> Result<(), Error> {
+    let _: Vec<StaffPin> = self.db
+      .query(r#"DELETE staff_pins WHERE user_id = type::thing("users", $user_id) AND user_id.restaurant_id = $restaurant_id"#)
+      .bind(("user_id", user_id))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(0)?;
+
+    Ok(())
+  }
+
+  async fn release_expired_locks(&self, expired_before: DateTime<Utc>) -> Result<u64, Error> {
+    let released: Vec<StaffPin> = self.db
+      .query(r#"
+        UPDATE staff_pins
+        SET
+          failed_attempts = 0,
+          locked_until = NONE,
+          updated_at = time::now()
+        WHERE locked_until != NONE AND locked_until <= $expired_before
+      "#)
+      .bind(("expired_before", Datetime::from(expired_before)))
+      .await?
+      .take(0)?;
+
+    Ok(released.len() as u64)
+  }
+}
\ No newline at end of file
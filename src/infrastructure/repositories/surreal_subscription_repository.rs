@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::Datetime;
+use std::sync::Arc;
+
+use crate::domain::entities::subscription::Subscription;
+use crate::domain::repositories::subscription_repository::SubscriptionRepository;
+use crate::domain::error::Error;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// La facturación es de la plataforma: las suscripciones viven en su base, junto a los restaurantes.
+// El id del registro es el del restaurante, así que hay una sola por restaurante.
+pub struct SurrealSubscriptionRepository {
+  db: Arc<Surreal<Client>>,
+}
+
+impl SurrealSubscriptionRepository {
+  pub fn new(connection: &DatabaseConnection) -> Self {
+    Self {
+      db: connection.get_client()
+    }
+  }
+}
+
+#[async_trait]
+impl SubscriptionRepository for SurrealSubscriptionRepository {
+  async fn find_by_restaurant(&self, restaurant_id: String) -> Result<Option<Subscription>, Error> {
+    let subscription: Option<Subscription> = self.db
+      .query("SELECT * FROM type::thing($tb, $id)")
+      .bind(("tb", "subscriptions"))
+      .bind(("id", restaurant_id))
+      .await?
+      .take(0)?;
+
+    Ok(subscription)
+  }
+
+  async fn save(&self, subscription: &Subscription) -> Result<Subscription, Error> {
+    let result: Option<Subscription> = self.db
+      .query(r#"
+        UPSERT type::thing($tb, $id) SET
+          restaurant_id = $subscription.restaurant_id,
+          plan = $subscription.plan,
+          status = $subscription.status,
+          trial_ends_at = $trial_ends_at,
+          current_period_start = $current_period_start,
+          current_period_end = $current_period_end,
+          usage = $subscription.usage,
+          usage_refreshed_at = $usage_refreshed_at,
+          created_at = created_at OR time::now(),
+          updated_at = time::now()
+      "#)
+      .bind(("tb", "subscriptions"))
+      .bind(("id", subscription.restaurant_id.id.to_string()))
+      .bind(("subscription", subscription.clone()))
+      .bind(("trial_ends_at", subscription.trial_ends_at.map(Datetime::from)))
+      .bind(("current_period_start", Datetime::from(subscription.current_period_start)))
+      .bind(("current_period_end", Datetime::from(subscription.current_period_end)))
+      .bind(("usage_refreshed_at", subscription.usage_refreshed_at.map(Datetime::from)))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::CreationFailed)
+  }
+}
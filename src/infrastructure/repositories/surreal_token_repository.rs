@@ -30,7 +30,7 @@ impl TokenRepository for SurrealTokenRepository {
 
   async fn find_refresh_token(&self, token: &str) -> Result<Option<RefreshToken>, Error> {
     let refresh_token: Option<RefreshToken> = self.db
-      .query("SELECT * FROM refresh_token WHERE token = $token AND invalidated = false")
+      .query("SELECT * FROM refresh_tokens WHERE token = $token AND invalidated = false LIMIT 1")
       .bind(("token", token.to_string()))
       .await?
       .take(0)?;
@@ -38,33 +38,50 @@ impl TokenRepository for SurrealTokenRepository {
     Ok(refresh_token)
   }
 
+  async fn mark_refresh_token_used(&self, token: &str) -> Result<bool, Error> {
+    let updated: Vec<RefreshToken> = self.db
+      .query(r#"
+        UPDATE refresh_tokens
+        SET
+          used = true,
+          updated_at = time::now()
+        WHERE token = $token AND used = false AND invalidated = false
+      "#)
+      .bind(("token", token.to_string()))
+      .await?
+      .take(0)?;
+
+    Ok(!updated.is_empty())
+  }
+
   async fn invalidate_refresh_token(&self, user_id: String) -> Result<(), Error> {
-    let result: Option<RefreshToken> = self.db
+    let result: Vec<RefreshToken> = self.db
       .query(r#"
-        UPDATE refresh_token 
+        UPDATE refresh_tokens 
         SET 
           invalidated = true,
           updated_at = time::now()
-        WHERE user_id = $user_id
+        WHERE user_id = type::thing("users", $user_id) AND invalidated = false
       "#)
       .bind(("user_id", user_id))
       .await?
       .take(0)?;
 
-    match result {
-      Some(_) => Ok(()),
-      None => Err(Error::InvalidToken)
+    if result.is_empty() {
+      return Err(Error::InvalidToken);
     }
+
+    Ok(())
   }
 
   async fn invalidate_all_user_tokens(&self, user_id: String) -> Result<(), Error> {
-    let _: Option<RefreshToken> = self.db
+    let _: Vec<RefreshToken> = self.db
       .query(r#"
-        UPDATE refresh_token 
+        UPDATE refresh_tokens 
         SET
           invalidated = true,
           updated_at = time::now()
-        WHERE user_id = $user_id
+        WHERE user_id = type::thing("users", $user_id)
       "#)
       .bind(("user_id", user_id.clone()))
       .await?
@@ -73,16 +90,73 @@ impl TokenRepository for SurrealTokenRepository {
     Ok(())
   }
 
+  async fn find_active_sessions(&self, user_id: String) -> Result<Vec<RefreshToken>, Error> {
+    let sessions: Vec<RefreshToken> = self.db
+      .query(r#"
+        SELECT * FROM refresh_tokens
+        WHERE user_id = type::thing("users", $user_id)
+          AND used = false
+          AND invalidated = false
+          AND expires_at > time::now()
+        ORDER BY last_used_at DESC
+      "#)
+      .bind(("user_id", user_id))
+      .await?
+      .take(0)?;
+
+    Ok(sessions)
+  }
+
+  async fn revoke_session(&self, user_id: String, session_id: String) -> Result<bool, Error> {
+    let revoked: Vec<RefreshToken> = self.db
+      .query(r#"
+        UPDATE refresh_tokens
+        SET
+          invalidated = true,
+          updated_at = time::now()
+        WHERE user_id = type::thing("users", $user_id)
+          AND session_id = $session_id
+          AND invalidated = false
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("session_id", session_id))
+      .await?
+      .take(0)?;
+
+    Ok(!revoked.is_empty())
+  }
+
+  async fn set_session_location(&self, user_id: String, session_id: String, location_id: Option<String>) -> Result<bool, Error> {
+    let updated: Vec<RefreshToken> = self.db
+      .query(r#"
+        UPDATE refresh_tokens
+        SET
+          location_id = $location_id,
+          updated_at = time::now()
+        WHERE user_id = type::thing("users", $user_id)
+          AND session_id = $session_id
+          AND used = false
+          AND invalidated = false
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("session_id", session_id))
+      .bind(("location_id", location_id))
+      .await?
+      .take(0)?;
+
+    Ok(!updated.is_empty())
+  }
+
   async fn cleanup_expired_tokens(&self) -> Result<u64, Error> {
-    let result: Option<u64> = self.db
+    let deleted: Vec<RefreshToken> = self.db
       .query(r#"
-        DELETE refresh_token 
+        DELETE refresh_tokens 
         WHERE expires_at <= time::now() 
-        RETURN count()
+        RETURN BEFORE
       "#)
       .await?
       .take(0)?;
 
-    Ok(result.unwrap_or(0))
+    Ok(deleted.len() as u64)
   }
 }
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use surrealdb::Surreal;
+use surrealdb::engine::remote::ws::Client;
+use surrealdb::sql::Datetime;
+use std::sync::Arc;
+
+use crate::domain::entities::subscription::Usage;
+use crate::domain::repositories::usage_repository::UsageRepository;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Cuenta en la base del restaurante. `menu_items` y `orders` son de los módulos de menú y
+// pedidos: mientras no existan las tablas el recuento da 0.
+pub struct SurrealUsageRepository {
+  db: Arc<Surreal<Client>>,
+  tenant: TenantContext,
+}
+
+impl SurrealUsageRepository {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
+    Self {
+      db: connection.client_for(tenant),
+      tenant: tenant.clone(),
+    }
+  }
+}
+
+#[derive(Deserialize)]
+struct Count {
+  count: u64,
+}
+
+#[async_trait]
+impl UsageRepository for SurrealUsageRepository {
+  async fn count(&self, period_start: DateTime<Utc>) -> Result<Usage, Error> {
+    let mut response = self.db
+      .query(r#"
+        SELECT count() FROM locations WHERE restaurant_id = $restaurant_id AND is_active = true GROUP ALL;
+        SELECT count() FROM users WHERE restaurant_id = $restaurant_id
+          AND user_type INSIDE ['employee', 'admin'] AND is_active = true AND deleted_at = NONE GROUP ALL;
+        SELECT count() FROM menu_items WHERE restaurant_id = $restaurant_id GROUP ALL;
+        SELECT count() FROM orders WHERE restaurant_id = $restaurant_id AND created_at >= $period_start GROUP ALL;
+      "#)
+      .bind(("restaurant_id", self.tenant.record()))
+      .bind(("period_start", Datetime::from(period_start)))
+      .await?;
+
+    let mut counts = [0; 4];
+
+    for (index, count) in counts.iter_mut().enumerate() {
+      let result: Option<Count> = response.take(index)?;
+      *count = result.map(|result| result.count).unwrap_or(0);
+    }
+
+    let [locations, staff, menu_items, monthly_orders] = counts;
+
+    Ok(Usage { locations, staff, menu_items, monthly_orders })
+  }
+}
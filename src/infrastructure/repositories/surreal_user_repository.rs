@@ -1,23 +1,32 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use surrealdb::Surreal;
 use surrealdb::engine::remote::ws::Client;
 use std::sync::Arc;
-use surrealdb::sql::Thing as Record;
+use uuid::Uuid;
+use surrealdb::sql::{Datetime, Thing as Record};
 
 use crate::domain::entities::User;
-use crate::domain::entities::role::Role;
 use crate::domain::repositories::user_repository::UserRepository;
 use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
 use crate::infrastructure::database::surreal_connection::DatabaseConnection;
 
+// Solo ve los usuarios del tenant con el que se crea; las tareas de mantenimiento
+// (`release_expired_locks`, `purge_deleted`) recorren todos los restaurantes
 pub struct SurrealUserRepository {
   db: Arc<Surreal<Client>>,
+  // Base compartida: tokens y credenciales 2FA no se mueven a la base dedicada del restaurante
+  shared: Arc<Surreal<Client>>,
+  tenant: TenantContext,
 }
 
 impl SurrealUserRepository {
-  pub fn new(connection: &DatabaseConnection) -> Self {
+  pub fn new(connection: &DatabaseConnection, tenant: &TenantContext) -> Self {
     Self { 
-      db: connection.get_client()
+      db: connection.client_for(tenant),
+      shared: connection.get_client(),
+      tenant: tenant.clone(),
     }
   }
 }
@@ -26,7 +35,8 @@ impl SurrealUserRepository {
 impl UserRepository for SurrealUserRepository {
   async fn find_all(&self) -> Result<Vec<User>, Error> {
     let users: Vec<User> = self.db
-      .query("SELECT *, profile.* FROM users")
+      .query("SELECT *, profile.* FROM users WHERE restaurant_id = $restaurant_id")
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
 
@@ -36,7 +46,9 @@ impl UserRepository for SurrealUserRepository {
   async fn create(&self, user: &User) -> Result<User, Error> {
     let email = user.email.to_string();
     let password = user.password.to_string();
-    let id = user.id.clone().unwrap().id;
+    let id = user.id.clone()
+      .map(|id| id.id.to_string())
+      .unwrap_or_else(|| Uuid::new_v4().simple().to_string());
     let user_type = user.user_type.clone();
 
     let result: Option<User> = self.db
@@ -47,8 +59,10 @@ impl UserRepository for SurrealUserRepository {
             password = $hashed_password,
             status = 'pending_verification',
             user_type = $user_type,
+            restaurant_id = $restaurant_id,
             created_at = time::now(),
             updated_at = time::now(),
+            password_changed_at = time::now(),
             is_verified = false,
             failed_login_attempts = 0
         "#)
@@ -57,16 +71,18 @@ impl UserRepository for SurrealUserRepository {
         .bind(("email", email))
         .bind(("password", password))
         .bind(("user_type", user_type))
+        .bind(("restaurant_id", self.tenant.record()))
         .await?
-        .take(0)?;
+        .take(1)?;
 
     result.ok_or(Error::UserCreationError("".to_string()))
   }
 
   async fn find_by_email(&self, email: &str) -> Result<Option<User>, Error> {
     let user: Option<User> = self.db
-      .query("SELECT * FROM user WHERE email = $email")
+      .query("SELECT * FROM users WHERE email = $email AND restaurant_id = $restaurant_id LIMIT 1")
       .bind(("email", email.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
     
@@ -77,12 +93,16 @@ impl UserRepository for SurrealUserRepository {
     let user: Option<User> = self.db
       .query(r#"
         SELECT *,
-        (SELECT role FROM user_role WHERE user = $id) AS roles,
-        (SELECT permission FROM user_role RELATE->role_permission WHERE user_role.user = $id) AS permissions 
+        (SELECT VALUE role.* FROM users_roles WHERE user = $parent.id AND location = NONE AND role.is_active = true) AS roles,
+        (SELECT VALUE permission.* FROM roles_permissions
+          WHERE role INSIDE (SELECT VALUE role FROM users_roles WHERE user = $parent.id AND location = NONE)
+          AND permission.is_active = true) AS permissions
         FROM type::thing($tb, $id)
+        WHERE restaurant_id = $restaurant_id
       "#)
       .bind(("tb", "users"))
       .bind(("id", id))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
       .take(0)?;
     
@@ -95,9 +115,12 @@ impl UserRepository for SurrealUserRepository {
         UPDATE type::thing($tb, $id) 
         SET 
           is_active = false,
+          deleted_at = time::now(),
           updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
       "#)
       .bind(("tb", "users"))
+      .bind(("restaurant_id", self.tenant.record()))
       .bind(("id", id))
       .await?
       .take(0)?;
@@ -115,8 +138,10 @@ impl UserRepository for SurrealUserRepository {
         SET 
           failed_login_attempts = $attempts,
           updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
       "#)
       .bind(("tb", "users"))
+      .bind(("restaurant_id", self.tenant.record()))
       .bind(("id", user_id))
       .bind(("attempts", attempts))
       .await?
@@ -125,10 +150,68 @@ impl UserRepository for SurrealUserRepository {
     result.ok_or(Error::UserUpdateError("".to_string()))
   }
 
+  async fn lock_account(&self, user_id: String, locked_until: DateTime<Utc>) -> Result<User, Error> {
+    let result: Option<User> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id) 
+        SET 
+          locked_until = $locked_until,
+          updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
+      "#)
+      .bind(("tb", "users"))
+      .bind(("restaurant_id", self.tenant.record()))
+      .bind(("id", user_id))
+      .bind(("locked_until", Datetime::from(locked_until)))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::UserNotFound)
+  }
+
+  async fn unlock_account(&self, user_id: String) -> Result<User, Error> {
+    let result: Option<User> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id) 
+        SET 
+          failed_login_attempts = 0,
+          locked_until = NONE,
+          updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
+      "#)
+      .bind(("tb", "users"))
+      .bind(("restaurant_id", self.tenant.record()))
+      .bind(("id", user_id))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::UserNotFound)
+  }
+
+  async fn record_successful_login(&self, user_id: String) -> Result<User, Error> {
+    let result: Option<User> = self.db
+      .query(r#"
+        UPDATE type::thing($tb, $id) 
+        SET 
+          failed_login_attempts = 0,
+          locked_until = NONE,
+          last_login = time::now(),
+          updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
+      "#)
+      .bind(("tb", "users"))
+      .bind(("restaurant_id", self.tenant.record()))
+      .bind(("id", user_id))
+      .await?
+      .take(0)?;
+
+    result.ok_or(Error::UserUpdateError("".to_string()))
+  }
+
   async fn authenticate(&self, email: &str, password: &str) -> Result<Option<User>, Error> {
     let result: Option<User> = self.db
       .query(r#"
-        LET $user = (SELECT * FROM users WHERE email = $email);
+        LET $user = (SELECT * FROM users WHERE email = $email AND restaurant_id = $restaurant_id LIMIT 1)[0];
         IF $user.password != NONE AND CRYPTO::ARGON2::COMPARE($user.password, $password) {
           RETURN $user
         } ELSE {
@@ -137,40 +220,70 @@ impl UserRepository for SurrealUserRepository {
       "#)
       .bind(("email", email.to_string()))
       .bind(("password", password.to_string()))
+      .bind(("restaurant_id", self.tenant.record()))
       .await?
-      .take(0)?;
+      .take(1)?;
     
     Ok(result)
   }
 
-  async fn change_password(&self, user_id: String, new_password: &str) -> Result<User, Error> {
+  async fn change_password(&self, user_id: String, new_password: &str, history_size: usize) -> Result<User, Error> {
+    // `password_history` se asigna antes que `password` para guardar el hash anterior
     let result: Option<User> = self.db
       .query(r#"
         LET $hashed_password = CRYPTO::ARGON2::GENERATE($password);
         UPDATE type::thing($tb, $id) 
         SET 
+          password_history = array::slice(array::prepend(password_history ?? [], password), 0, $history_size),
           password = $hashed_password,
+          password_changed_at = time::now(),
           updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
       "#)
       .bind(("tb", "users"))
+      .bind(("restaurant_id", self.tenant.record()))
       .bind(("id", user_id.clone()))
       .bind(("password", new_password.to_string()))
+      .bind(("history_size", history_size))
       .await?
-      .take(0)?;
+      .take(1)?;
 
     result.ok_or(Error::UserUpdateError("Updated failed".to_string()))
   }
 
+  async fn is_recent_password(&self, user_id: String, password: &str, history: usize) -> Result<bool, Error> {
+    if history == 0 {
+      return Ok(false);
+    }
+
+    let result: Option<bool> = self.db
+      .query(r#"
+        LET $user = (SELECT password, password_history FROM type::thing("users", $user_id) WHERE restaurant_id = $restaurant_id)[0];
+        LET $recent = IF $user != NONE THEN array::slice(array::prepend($user.password_history ?? [], $user.password), 0, $history) ELSE [] END;
+        RETURN array::any($recent, |$hash: string| CRYPTO::ARGON2::COMPARE($hash, $password));
+      "#)
+      .bind(("user_id", user_id))
+      .bind(("password", password.to_string()))
+      .bind(("history", history))
+      .bind(("restaurant_id", self.tenant.record()))
+      .await?
+      .take(2)?;
+
+    Ok(result.unwrap_or(false))
+  }
+
   async fn set_verification_status(&self, user_id: String, is_verified: bool) -> Result<User, Error> {
     let result: Option<User> = self.db
       .query(r#"
         UPDATE type::thing($tb, $id) 
         SET 
           is_verified = $is_verified,
-          status = IF $is_verified == true THEN 'verified' ELSE 'pending_verification' END,
+          status = IF $is_verified == true THEN 'active' ELSE 'pending_verification' END,
           updated_at = time::now()
+        WHERE restaurant_id = $restaurant_id
       "#)
-      .bind(("tb", "users")) 
+      .bind(("tb", "users"))
+      .bind(("restaurant_id", self.tenant.record()))
       .bind(("id", user_id.clone()))
       .bind(("is_verified", is_verified))
       .await?
@@ -179,27 +292,64 @@ impl UserRepository for SurrealUserRepository {
     result.ok_or(Error::UserUpdateError("Updated failed".to_string()))
   }
 
-  async fn assign_roles(&self, user_id: String, roles: Vec<Role>) -> Result<User, Error> {
-    let _: Vec<Record> = self.db
-        .query(r#"
-          LET $user = type::thing($tb, $id);
-          FOR $role IN $roles {
-            CREATE user_role SET 
-              user = $user,
-              role = $role,
-              assigned_at = time::now(),
-              assigned_by = $user
-          };
-          SELECT * FROM type::thing($tb, $id);
-        "#)
-        .bind(("tb", "users"))
-        .bind(("id", user_id.clone()))
-        .bind(("roles", roles))
-        .await?
-        .take(0)?;
+  async fn release_expired_locks(&self, expired_before: DateTime<Utc>) -> Result<u64, Error> {
+    let released: Vec<User> = self.db
+      .query(r#"
+        UPDATE users
+        SET
+          failed_login_attempts = 0,
+          locked_until = NONE,
+          updated_at = time::now()
+        WHERE locked_until != NONE AND locked_until <= $expired_before
+      "#)
+      .bind(("expired_before", Datetime::from(expired_before)))
+      .await?
+      .take(0)?;
+
+    Ok(released.len() as u64)
+  }
+
+  async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64, Error> {
+    let users: Vec<Record> = self.db
+      .query("SELECT VALUE id FROM users WHERE deleted_at != NONE AND deleted_at <= $deleted_before")
+      .bind(("deleted_before", Datetime::from(deleted_before)))
+      .await?
+      .take(0)?;
 
-    self.find_by_id(user_id.clone())
+    if users.is_empty() {
+      return Ok(0);
+    }
+
+    // Primero lo de la base compartida: si luego falla la del tenant, la siguiente purga vuelve a
+    // encontrar a los usuarios y repite todo
+    self.shared
+      .query(r#"
+        BEGIN TRANSACTION;
+        DELETE refresh_tokens WHERE user_id INSIDE $users;
+        DELETE one_time_tokens WHERE user_id INSIDE $users;
+        DELETE revoked_tokens WHERE user_id INSIDE $users;
+        DELETE mfa_credentials WHERE user_id INSIDE $users;
+        COMMIT TRANSACTION;
+      "#)
+      .bind(("users", users.clone()))
       .await?
-      .ok_or(Error::UserUpdateError("Failed to assign roles".to_string()))
+      .check()?;
+
+    self.db
+      .query(r#"
+        BEGIN TRANSACTION;
+        DELETE profiles WHERE user INSIDE $users;
+        DELETE users_roles WHERE user INSIDE $users;
+        DELETE users_locations WHERE user INSIDE $users;
+        DELETE staff_pins WHERE user_id INSIDE $users;
+        DELETE external_identities WHERE user_id INSIDE $users;
+        DELETE users WHERE id INSIDE $users;
+        COMMIT TRANSACTION;
+      "#)
+      .bind(("users", users.clone()))
+      .await?
+      .check()?;
+
+    Ok(users.len() as u64)
   }
 }
\ No newline at end of file
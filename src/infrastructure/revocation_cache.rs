@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use std::sync::RwLock;
+
+use crate::domain::entities::revoked_token::RevokedToken;
+
+// Copia en memoria de la lista de revocación, compartida por todos los workers del proceso.
+// Solo guarda revocaciones conocidas: que no haya coincidencia aquí no garantiza nada
+// (otra instancia pudo revocar), por eso siempre se consulta también la base de datos.
+#[derive(Debug, Default)]
+pub struct RevocationCache {
+  entries: RwLock<Vec<RevokedToken>>,
+}
+
+impl RevocationCache {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn insert(&self, entry: RevokedToken) {
+    let mut entries = self.entries.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    entries.retain(|existing| !existing.is_expired());
+    entries.push(entry);
+  }
+
+  pub fn find_matching(&self, jti: Option<&str>, user_id: &str, issued_at: DateTime<Utc>) -> Option<RevokedToken> {
+    let entries = self.entries.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    entries
+      .iter()
+      .find(|entry| entry.matches(jti, user_id, issued_at))
+      .cloned()
+  }
+
+  pub fn remove_expired(&self) -> u64 {
+    let mut entries = self.entries.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let before = entries.len();
+
+    entries.retain(|existing| !existing.is_expired());
+
+    (before - entries.len()) as u64
+  }
+}
@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::use_cases::maintenance::{
+  cleanup_expired_tokens::CleanupExpiredTokensUseCase,
+  release_expired_locks::ReleaseExpiredLocksUseCase,
+  purge_deleted_records::PurgeDeletedRecordsUseCase,
+};
+use crate::application::use_cases::subscriptions::refresh_usage::RefreshUsageUseCase;
+use crate::domain::error::Error;
+use crate::domain::repositories::restaurant_repository::RestaurantRepository;
+use crate::domain::services::lockout::LockoutPolicy;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::config_env::Config;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::repositories::{
+  surreal_user_repository::SurrealUserRepository,
+  surreal_profile_repository::SurrealProfileRepository,
+  surreal_token_repository::SurrealTokenRepository,
+  surreal_one_time_token_repository::SurrealOneTimeTokenRepository,
+  surreal_oidc_authorization_repository::SurrealOidcAuthorizationRepository,
+  surreal_staff_pin_repository::SurrealStaffPinRepository,
+  surreal_api_key_repository::SurrealApiKeyRepository,
+  cached_revoked_token_repository::CachedRevokedTokenRepository,
+  surreal_restaurant_repository::SurrealRestaurantRepository,
+  surreal_subscription_repository::SurrealSubscriptionRepository,
+  surreal_usage_repository::SurrealUsageRepository,
+};
+use crate::infrastructure::revocation_cache::RevocationCache;
+use crate::infrastructure::scheduler::{Job, Scheduler};
+
+// Tareas incluidas; una tarea sin horario configurado no se registra
+pub fn built_in_jobs(config: &Config, db_connection: Arc<DatabaseConnection>, revocation_cache: Arc<RevocationCache>) -> Scheduler {
+  let scheduler_config = &config.scheduler;
+  let mut scheduler = Scheduler::new(db_connection.clone());
+
+  if let Some(schedule) = scheduler_config.token_cleanup.clone() {
+    scheduler = scheduler.register(
+      Arc::new(TokenCleanupJob { db_connection: db_connection.clone(), revocation_cache }),
+      schedule,
+    );
+  }
+
+  if let Some(schedule) = scheduler_config.lock_expiry.clone() {
+    scheduler = scheduler.register(
+      Arc::new(LockExpiryJob {
+        db_connection: db_connection.clone(),
+        lockout_policy: config.lockout_policy.clone(),
+        pin_lockout_policy: config.pin_lockout_policy.clone(),
+      }),
+      schedule,
+    );
+  }
+
+  if let Some(schedule) = scheduler_config.soft_delete_purge.clone() {
+    scheduler = scheduler.register(
+      Arc::new(SoftDeletePurgeJob { db_connection: db_connection.clone(), retention: scheduler_config.soft_delete_retention }),
+      schedule,
+    );
+  }
+
+  if let Some(schedule) = scheduler_config.usage_refresh.clone() {
+    scheduler = scheduler.register(
+      Arc::new(UsageRefreshJob { db_connection }),
+      schedule,
+    );
+  }
+
+  scheduler
+}
+
+struct TokenCleanupJob {
+  db_connection: Arc<DatabaseConnection>,
+  revocation_cache: Arc<RevocationCache>,
+}
+
+#[async_trait]
+impl Job for TokenCleanupJob {
+  fn name(&self) -> &'static str {
+    "token_cleanup"
+  }
+
+  // Los tokens solo están en la base de la plataforma: en las dedicadas únicamente hay autorizaciones OIDC
+  async fn run(&self) -> Result<String, Error> {
+    let mut summaries = Vec::new();
+
+    for tenant in self.db_connection.tenants() {
+      summaries.push(CleanupExpiredTokensUseCase::new(
+        SurrealTokenRepository::new(&self.db_connection),
+        SurrealOneTimeTokenRepository::new(&self.db_connection),
+        CachedRevokedTokenRepository::new(&self.db_connection, self.revocation_cache.clone()),
+        SurrealOidcAuthorizationRepository::new(&self.db_connection, &tenant),
+      ).execute().await?);
+    }
+
+    Ok(summaries.join("; "))
+  }
+}
+
+struct LockExpiryJob {
+  db_connection: Arc<DatabaseConnection>,
+  lockout_policy: LockoutPolicy,
+  pin_lockout_policy: LockoutPolicy,
+}
+
+#[async_trait]
+impl Job for LockExpiryJob {
+  fn name(&self) -> &'static str {
+    "lock_expiry"
+  }
+
+  // Los métodos de mantenimiento no filtran por tenant: recorren todos los restaurantes de cada base,
+  // la compartida y las dedicadas
+  async fn run(&self) -> Result<String, Error> {
+    let mut summaries = Vec::new();
+
+    for tenant in self.db_connection.tenants() {
+      summaries.push(ReleaseExpiredLocksUseCase::new(
+        SurrealUserRepository::new(&self.db_connection, &tenant),
+        SurrealStaffPinRepository::new(&self.db_connection, &tenant),
+        self.lockout_policy.clone(),
+        self.pin_lockout_policy.clone(),
+      ).execute().await?);
+    }
+
+    Ok(summaries.join("; "))
+  }
+}
+
+struct SoftDeletePurgeJob {
+  db_connection: Arc<DatabaseConnection>,
+  retention: Duration,
+}
+
+#[async_trait]
+impl Job for SoftDeletePurgeJob {
+  fn name(&self) -> &'static str {
+    "soft_delete_purge"
+  }
+
+  // Igual que en `LockExpiryJob`, la purga recorre todos los restaurantes
+  async fn run(&self) -> Result<String, Error> {
+    let mut summaries = Vec::new();
+
+    for tenant in self.db_connection.tenants() {
+      summaries.push(PurgeDeletedRecordsUseCase::new(
+        SurrealUserRepository::new(&self.db_connection, &tenant),
+        SurrealProfileRepository::new(&self.db_connection, &tenant),
+        SurrealApiKeyRepository::new(&self.db_connection, &tenant),
+        self.retention,
+      ).execute().await?);
+    }
+
+    Ok(summaries.join("; "))
+  }
+}
+
+
+struct UsageRefreshJob {
+  db_connection: Arc<DatabaseConnection>,
+}
+
+#[async_trait]
+impl Job for UsageRefreshJob {
+  fn name(&self) -> &'static str {
+    "usage_refresh"
+  }
+
+  // Un restaurante que falla no deja sin recuento a los demás; se reintenta en la siguiente ejecución
+  async fn run(&self) -> Result<String, Error> {
+    let restaurants = SurrealRestaurantRepository::new(&self.db_connection).find_all().await?;
+    let mut refreshed = 0;
+
+    for restaurant in restaurants.iter().filter(|restaurant| restaurant.is_active) {
+      let Some(id) = restaurant.id.as_ref() else { continue };
+
+      let result = RefreshUsageUseCase::new(
+        SurrealSubscriptionRepository::new(&self.db_connection),
+        SurrealUsageRepository::new(&self.db_connection, &TenantContext::from(restaurant)),
+      ).execute(id.id.to_string()).await;
+
+      match result {
+        Ok(true) => refreshed += 1,
+        Ok(false) => {},
+        Err(err) => log::error!("Failed to refresh usage of restaurant {}: {}", restaurant.slug, err),
+      }
+    }
+
+    Ok(format!("refreshed usage of {} restaurants", refreshed))
+  }
+}
\ No newline at end of file
@@ -0,0 +1,141 @@
+pub mod schedule;
+pub mod jobs;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::domain::entities::job_run::{JobOutcome, JobRun};
+use crate::domain::error::Error;
+use crate::domain::repositories::job_run_repository::JobRunRepository;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::repositories::surreal_job_run_repository::SurrealJobRunRepository;
+use schedule::CronSchedule;
+
+// Tarea de mantenimiento; devuelve un resumen de lo que hizo para el registro de ejecuciones
+#[async_trait]
+pub trait Job: Send + Sync {
+  fn name(&self) -> &'static str;
+  async fn run(&self) -> Result<String, Error>;
+}
+
+struct ScheduledJob {
+  job: Arc<dyn Job>,
+  schedule: CronSchedule,
+}
+
+// Planificador en proceso: cada tarea corre en su propio bucle, así que nunca se solapa consigo
+// misma; si una ejecución se alarga, los turnos perdidos se saltan y se espera al siguiente
+pub struct Scheduler {
+  db_connection: Arc<DatabaseConnection>,
+  jobs: Vec<ScheduledJob>,
+}
+
+impl Scheduler {
+  pub fn new(db_connection: Arc<DatabaseConnection>) -> Self {
+    Self { db_connection, jobs: Vec::new() }
+  }
+
+  pub fn register(mut self, job: Arc<dyn Job>, schedule: CronSchedule) -> Self {
+    self.jobs.push(ScheduledJob { job, schedule });
+    self
+  }
+
+  pub fn start(self) -> SchedulerHandle {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+    let tasks = self.jobs
+      .into_iter()
+      .map(|scheduled| {
+        log::info!("Scheduled job {} registered with schedule \"{}\"", scheduled.job.name(), scheduled.schedule);
+        tokio::spawn(run_job_loop(scheduled, self.db_connection.clone(), shutdown_rx.clone()))
+      })
+      .collect();
+
+    SchedulerHandle { shutdown_tx, tasks }
+  }
+}
+
+pub struct SchedulerHandle {
+  shutdown_tx: watch::Sender<bool>,
+  tasks: Vec<JoinHandle<()>>,
+}
+
+impl SchedulerHandle {
+  // Deja de programar ejecuciones y espera a que terminen las que están en curso
+  pub async fn shutdown(self) {
+    let _ = self.shutdown_tx.send(true);
+
+    for task in self.tasks {
+      if let Err(err) = task.await {
+        log::error!("Scheduled job task ended abnormally: {}", err);
+      }
+    }
+
+    log::info!("Scheduler stopped");
+  }
+}
+
+async fn run_job_loop(scheduled: ScheduledJob, db_connection: Arc<DatabaseConnection>, mut shutdown: watch::Receiver<bool>) {
+  let name = scheduled.job.name();
+  let repository = SurrealJobRunRepository::new(&db_connection);
+  let mut job_run = JobRun {
+    id: None,
+    name: name.to_string(),
+    schedule: scheduled.schedule.to_string(),
+    last_started_at: None,
+    last_finished_at: None,
+    last_outcome: None,
+    last_message: None,
+    next_run_at: None,
+  };
+
+  loop {
+    let Some(next_run_at) = scheduled.schedule.next_after(Utc::now()) else {
+      log::warn!("Scheduled job {} will never run again, stopping it", name);
+      return;
+    };
+
+    job_run.next_run_at = Some(next_run_at);
+    record(&repository, &job_run).await;
+
+    let wait = (next_run_at - Utc::now()).to_std().unwrap_or_default();
+
+    tokio::select! {
+      _ = tokio::time::sleep(wait) => {}
+      _ = shutdown.changed() => return,
+    }
+
+    job_run.last_started_at = Some(Utc::now());
+
+    match scheduled.job.run().await {
+      Ok(summary) => {
+        log::info!("Scheduled job {} succeeded: {}", name, summary);
+        job_run.last_outcome = Some(JobOutcome::Succeeded);
+        job_run.last_message = Some(summary);
+      }
+      Err(err) => {
+        log::error!("Scheduled job {} failed: {}", name, err);
+        job_run.last_outcome = Some(JobOutcome::Failed);
+        job_run.last_message = Some(err.to_string());
+      }
+    }
+
+    job_run.last_finished_at = Some(Utc::now());
+
+    if *shutdown.borrow() {
+      job_run.next_run_at = None;
+      record(&repository, &job_run).await;
+      return;
+    }
+  }
+}
+
+// No poder guardar el estado no debe detener la tarea
+async fn record(repository: &SurrealJobRunRepository, job_run: &JobRun) {
+  if let Err(err) = repository.save(job_run).await {
+    log::warn!("Could not record state of scheduled job {}: {}", job_run.name, err);
+  }
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, Datelike, Duration, DurationRound, Timelike, Utc};
+
+// Expresión tipo cron de 5 campos en UTC: "minuto hora día-del-mes mes día-de-la-semana".
+// Cada campo admite `*`, `*/n`, valores, rangos `a-b`, rangos con paso `a-b/n` y listas separadas por comas.
+// Día de la semana: 0-6 empezando en domingo (7 también es domingo).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+  expression: String,
+  minutes: u64,
+  hours: u64,
+  days_of_month: u64,
+  months: u64,
+  days_of_week: u64,
+  // Como en cron: si se restringen ambos días basta con que coincida uno
+  day_of_month_any: bool,
+  day_of_week_any: bool,
+}
+
+// Tope de búsqueda: una expresión como "0 0 30 2 *" nunca coincide
+const MAX_LOOKAHEAD_DAYS: i64 = 366 * 5;
+
+impl CronSchedule {
+  pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let limit = after + Duration::days(MAX_LOOKAHEAD_DAYS);
+    let mut candidate = after.duration_trunc(Duration::minutes(1)).ok()? + Duration::minutes(1);
+
+    while candidate <= limit {
+      if !contains(self.months, candidate.month()) {
+        let (year, month) = match candidate.month() {
+          12 => (candidate.year() + 1, 1),
+          month => (candidate.year(), month + 1),
+        };
+        candidate = candidate.with_day(1)?.with_hour(0)?.with_minute(0)?.with_year(year)?.with_month(month)?;
+        continue;
+      }
+
+      if !self.matches_day(candidate) {
+        candidate = candidate.with_hour(0)?.with_minute(0)? + Duration::days(1);
+        continue;
+      }
+
+      if !contains(self.hours, candidate.hour()) {
+        candidate = candidate.with_minute(0)? + Duration::hours(1);
+        continue;
+      }
+
+      if !contains(self.minutes, candidate.minute()) {
+        candidate += Duration::minutes(1);
+        continue;
+      }
+
+      return Some(candidate);
+    }
+
+    None
+  }
+
+  fn matches_day(&self, candidate: DateTime<Utc>) -> bool {
+    let day_of_month = contains(self.days_of_month, candidate.day());
+    let day_of_week = contains(self.days_of_week, candidate.weekday().num_days_from_sunday());
+
+    match (self.day_of_month_any, self.day_of_week_any) {
+      (true, true) => true,
+      (true, false) => day_of_week,
+      (false, true) => day_of_month,
+      (false, false) => day_of_month || day_of_week,
+    }
+  }
+}
+
+impl std::fmt::Display for CronSchedule {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.expression)
+  }
+}
+
+impl std::str::FromStr for CronSchedule {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let fields: Vec<&str> = s.split_whitespace().collect();
+
+    let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+      return Err(format!("Invalid schedule {:?}: expected 5 fields", s));
+    };
+
+    let mut days_of_week_mask = parse_field(days_of_week, 0, 7).map_err(|e| format!("Invalid schedule {:?}: {}", s, e))?;
+
+    // 7 es un alias del domingo
+    if contains(days_of_week_mask, 7) {
+      days_of_week_mask = (days_of_week_mask | 1) & !(1 << 7);
+    }
+
+    let invalid = |e: String| format!("Invalid schedule {:?}: {}", s, e);
+
+    Ok(Self {
+      expression: fields.join(" "),
+      minutes: parse_field(minutes, 0, 59).map_err(invalid)?,
+      hours: parse_field(hours, 0, 23).map_err(invalid)?,
+      days_of_month: parse_field(days_of_month, 1, 31).map_err(invalid)?,
+      months: parse_field(months, 1, 12).map_err(invalid)?,
+      days_of_week: days_of_week_mask,
+      day_of_month_any: days_of_month == "*",
+      day_of_week_any: days_of_week == "*",
+    })
+  }
+}
+
+fn contains(mask: u64, value: u32) -> bool {
+  mask & (1 << value) != 0
+}
+
+// Devuelve los valores permitidos como máscara de bits
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, String> {
+  let mut mask = 0u64;
+
+  for part in field.split(',') {
+    let (range, step) = match part.split_once('/') {
+      Some((range, step)) => (range, step.parse::<u32>().map_err(|_| format!("invalid step in {:?}", part))?),
+      None => (part, 1),
+    };
+
+    if step == 0 {
+      return Err(format!("step cannot be zero in {:?}", part));
+    }
+
+    let (start, end) = match range {
+      "*" => (min, max),
+      _ => match range.split_once('-') {
+        Some((start, end)) => (parse_value(start, part)?, parse_value(end, part)?),
+        // "5/15" equivale a "5-max/15"
+        None if part.contains('/') => (parse_value(range, part)?, max),
+        None => {
+          let value = parse_value(range, part)?;
+          (value, value)
+        }
+      },
+    };
+
+    if start < min || end > max || start > end {
+      return Err(format!("{:?} is out of range {}-{}", part, min, max));
+    }
+
+    for value in (start..=end).step_by(step as usize) {
+      mask |= 1 << value;
+    }
+  }
+
+  Ok(mask)
+}
+
+fn parse_value(value: &str, part: &str) -> Result<u32, String> {
+  value.parse().map_err(|_| format!("invalid value in {:?}", part))
+}
@@ -4,6 +4,17 @@ use std::sync::Arc;
 
 use crate::infrastructure::config_env::Config;
 use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::database::surreal_tenant_provisioner::SurrealTenantProvisioner;
+use crate::infrastructure::database::surreal_tenant_onboarding::SurrealTenantOnboarding;
+use crate::domain::services::tenant_database::TenantDatabaseProvisioner;
+use crate::domain::services::tenant_onboarding::TenantOnboarding;
+use crate::infrastructure::mailer::build_mailer;
+use crate::infrastructure::oidc::build_oidc_provider;
+use crate::infrastructure::oidc::mock_issuer::MockIssuer;
+use crate::infrastructure::revocation_cache::RevocationCache;
+use crate::infrastructure::memory_rate_limit_store::MemoryRateLimitStore;
+use crate::domain::services::rate_limit::RateLimitStore;
+use crate::infrastructure::scheduler::jobs::built_in_jobs;
 use crate::presentation::routes;
 
 pub async fn run(config: Config) -> std::io::Result<()> {
@@ -16,12 +27,47 @@ pub async fn run(config: Config) -> std::io::Result<()> {
 
   let database_connection = Arc::new(DatabaseConnection::new(&config_arc_db.database.url, &config_arc_db.database.namespace, &config_arc_db.database.database)
     .await
-    .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?);
+    .map_err(std::io::Error::other)?);
+
+  let tenant_provisioner = Arc::new(SurrealTenantProvisioner::new(database_connection.clone()));
+  let dedicated = tenant_provisioner.attach_existing().await.map_err(std::io::Error::other)?;
+  log::info!("Routing {} restaurants to their dedicated databases", dedicated);
+  let tenant_provisioner: Arc<dyn TenantDatabaseProvisioner> = tenant_provisioner;
+  let tenant_onboarding: Arc<dyn TenantOnboarding> = Arc::new(SurrealTenantOnboarding::new(database_connection.clone()));
+
+  let mailer = build_mailer(&config_arc.mailer).map_err(std::io::Error::other)?;
+  let revocation_cache = Arc::new(RevocationCache::new());
+  let rate_limit_store: Arc<dyn RateLimitStore> = Arc::new(MemoryRateLimitStore::new());
+  let oidc_provider = config_arc.oidc.as_ref().map(build_oidc_provider).transpose().map_err(std::io::Error::other)?;
+  let mock_issuer = match (&config_arc.oidc, config_arc.oidc_mock_issuer) {
+    (Some(oidc), true) => {
+      let issuer = format!("{}/mock-oidc", config_arc.mailer.app_url.trim_end_matches('/'));
+      log::warn!("Serving the mock OIDC issuer at {}, do not enable it in production", issuer);
+      Some(web::Data::new(MockIssuer::new(issuer, oidc.client_id.clone()).map_err(std::io::Error::other)?))
+    }
+    _ => None,
+  };
+  let scheduler = built_in_jobs(&config_arc, database_connection.clone(), revocation_cache.clone());
   
-  HttpServer::new(move || {
-    App::new()
-      .app_data(web::Data::new(database_connection.clone()))
-      .app_data(web::Data::new(config.clone()))
+  let server = HttpServer::new(move || {
+    let mut app = App::new()
+      .app_data(web::Data::from(database_connection.clone()))
+      .app_data(web::Data::from(config.clone()))
+      .app_data(web::Data::from(mailer.clone()))
+      .app_data(web::Data::from(revocation_cache.clone()))
+      .app_data(web::Data::from(rate_limit_store.clone()))
+      .app_data(web::Data::from(tenant_provisioner.clone()))
+      .app_data(web::Data::from(tenant_onboarding.clone()));
+
+    if let Some(provider) = &oidc_provider {
+      app = app.app_data(web::Data::from(provider.clone()));
+    }
+
+    if let Some(mock_issuer) = &mock_issuer {
+      app = app.app_data(mock_issuer.clone()).configure(routes::mock_oidc_routes::routes);
+    }
+
+    app
       .wrap(Logger::default())
       .configure(routes::health_check_routes::routes)
       .configure(routes::auth_routes::routes)
@@ -29,9 +75,24 @@ pub async fn run(config: Config) -> std::io::Result<()> {
       .configure(routes::users_routes::routes)
       .configure(routes::role_routes::routes)
       .configure(routes::permission_routes::routes)
+      .configure(routes::pos_routes::routes)
+      .configure(routes::api_keys_routes::routes)
+      .configure(routes::restaurants_routes::routes)
+      .configure(routes::tenants_routes::routes)
+      .configure(routes::locations_routes::routes)
+      .configure(routes::subscriptions_routes::routes)
+      .configure(routes::audit_routes::routes)
+      .configure(routes::well_known_routes::routes)
   })
   .bind(address)
-  .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?
-  .run()
-  .await
+  .map_err(std::io::Error::other)?
+  .run();
+
+  let scheduler = scheduler.start();
+
+  // El servidor termina con SIGINT/SIGTERM; después se detienen las tareas programadas
+  let result = server.await;
+  scheduler.shutdown().await;
+
+  result
 }
\ No newline at end of file
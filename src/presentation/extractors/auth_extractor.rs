@@ -0,0 +1,84 @@
+use actix_web::{dev::Payload, http::header, web, FromRequest, HttpMessage, HttpRequest};
+use futures_util::future::LocalBoxFuture;
+
+use crate::application::use_cases::auth::authenticate::AuthenticateUseCase;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::config_env::Config;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::repositories::{
+  surreal_user_repository::SurrealUserRepository,
+  surreal_role_repository::SurrealRoleRepository,
+  surreal_permission_repository::SurrealPermissionRepository,
+  cached_revoked_token_repository::CachedRevokedTokenRepository,
+  surreal_restaurant_repository::SurrealRestaurantRepository,
+};
+use crate::infrastructure::revocation_cache::RevocationCache;
+
+// Cualquier handler que reciba un `AuthenticatedUser` queda protegido por el header `Authorization: Bearer <token>`
+impl FromRequest for AuthenticatedUser {
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+    let req = req.clone();
+
+    Box::pin(async move {
+      // Un middleware (ej. `RequirePermission`) ya pudo resolver al usuario en esta petición
+      if let Some(auth_user) = req.extensions().get::<AuthenticatedUser>() {
+        return Ok(auth_user.clone());
+      }
+
+      let auth_user = authenticate(&req).await?;
+      req.extensions_mut().insert(auth_user.clone());
+
+      Ok(auth_user)
+    })
+  }
+}
+
+async fn authenticate(req: &HttpRequest) -> Result<AuthenticatedUser, Error> {
+  let token = bearer_token(req)?;
+
+  let config = req.app_data::<web::Data<Config>>()
+    .ok_or(Error::ConfigError("Config not registered".to_string()))?;
+  let db_connection = req.app_data::<web::Data<DatabaseConnection>>()
+    .ok_or(Error::ConnectionError("Database connection not registered".to_string()))?;
+
+  let token_service = TokenService::new(config.token_config.clone());
+
+  // El usuario vive en el restaurante de su token, que para un super admin puede no ser el de la petición
+  let token_tenant = TenantContext::from_restaurant_id(token_service.verify_access_token(&token)?.tid.as_deref());
+
+  let user_repo = SurrealUserRepository::new(db_connection, &token_tenant);
+  let role_repo = SurrealRoleRepository::new(db_connection, &token_tenant);
+  let permission_repo = SurrealPermissionRepository::new(db_connection, &token_tenant);
+  let revocation_cache = req.app_data::<web::Data<RevocationCache>>()
+    .ok_or(Error::ConfigError("Revocation cache not registered".to_string()))?;
+  let revoked_token_repo = CachedRevokedTokenRepository::new(db_connection, revocation_cache.clone().into_inner());
+
+  let auth_user = AuthenticateUseCase::new(user_repo, role_repo, permission_repo, revoked_token_repo, SurrealRestaurantRepository::new(db_connection), token_service)
+    .execute(&token)
+    .await?;
+
+  auth_user.require_tenant(&TenantContext::extract(req).await?)?;
+
+  Ok(auth_user)
+}
+
+pub fn bearer_token(req: &HttpRequest) -> Result<String, Error> {
+  let value = req.headers()
+    .get(header::AUTHORIZATION)
+    .ok_or(Error::MissingToken)?
+    .to_str()
+    .map_err(|_| Error::InvalidToken)?;
+
+  match value.split_once(' ') {
+    Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") && !token.trim().is_empty() => {
+      Ok(token.trim().to_string())
+    },
+    _ => Err(Error::InvalidToken),
+  }
+}
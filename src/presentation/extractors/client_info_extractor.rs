@@ -0,0 +1,71 @@
+use std::net::{IpAddr, SocketAddr};
+
+use actix_web::{dev::Payload, http::header, web, FromRequest, HttpRequest};
+use futures_util::future::{ready, Ready};
+
+use crate::domain::error::Error;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::infrastructure::config_env::Config;
+
+const FORWARDED_FOR_HEADER: &str = "X-Forwarded-For";
+
+// Nunca falla: los datos que falten simplemente no se guardan. El nombre del dispositivo
+// lo envía la app en `X-Device-Name`; la IP es la de `client_ip`.
+impl FromRequest for ClientInfo {
+  type Error = Error;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+    let header_value = |name| req.headers().get(name).and_then(|value| value.to_str().ok());
+    let ip = client_ip(req);
+
+    ready(Ok(ClientInfo::new(
+      header_value("X-Device-Name"),
+      ip.as_deref(),
+      header_value(header::USER_AGENT.as_str()),
+    )))
+  }
+}
+
+// IP de la conexión. Solo si llega de un proxy de confianza (`TRUSTED_PROXIES`) se recorre
+// `X-Forwarded-For` de derecha a izquierda y vale la primera IP que no es de un proxy: lo que
+// queda a su izquierda lo escribe el cliente y no sirve ni para los límites ni para la auditoría.
+pub fn client_ip(req: &HttpRequest) -> Option<String> {
+  let peer = req.peer_addr()?.ip();
+  let config = req.app_data::<web::Data<Config>>();
+  let trusted = config.map(|config| config.server.trusted_proxies.as_slice()).unwrap_or_default();
+
+  if !trusted.contains(&peer) {
+    return Some(peer.to_string());
+  }
+
+  let hops: Vec<&str> = req.headers()
+    .get_all(FORWARDED_FOR_HEADER)
+    .filter_map(|value| value.to_str().ok())
+    .flat_map(|value| value.split(','))
+    .map(str::trim)
+    .collect();
+
+  let mut client = peer;
+
+  for hop in hops.iter().rev() {
+    let Some(ip) = parse_hop(hop) else {
+      break;
+    };
+
+    client = ip;
+
+    if !trusted.contains(&ip) {
+      break;
+    }
+  }
+
+  Some(client.to_string())
+}
+
+// Algunos proxies añaden el puerto
+fn parse_hop(hop: &str) -> Option<IpAddr> {
+  hop.parse::<IpAddr>()
+    .or_else(|_| hop.parse::<SocketAddr>().map(|addr| addr.ip()))
+    .ok()
+}
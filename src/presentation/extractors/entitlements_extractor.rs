@@ -0,0 +1,41 @@
+use actix_web::{dev::Payload, web, FromRequest, HttpMessage, HttpRequest};
+use futures_util::future::LocalBoxFuture;
+
+use crate::application::use_cases::subscriptions::resolve_entitlements::ResolveEntitlementsUseCase;
+use crate::domain::entities::entitlements::Entitlements;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::repositories::{
+  surreal_subscription_repository::SurrealSubscriptionRepository,
+  surreal_plan_repository::SurrealPlanRepository,
+};
+
+// Plan del restaurante de la petición; se resuelve una vez y queda en la petición
+impl FromRequest for Entitlements {
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+    let req = req.clone();
+
+    Box::pin(async move {
+      if let Some(entitlements) = req.extensions().get::<Entitlements>() {
+        return Ok(entitlements.clone());
+      }
+
+      let db_connection = req.app_data::<web::Data<DatabaseConnection>>()
+        .ok_or(Error::ConnectionError("Database connection not registered".to_string()))?;
+      let tenant = TenantContext::extract(&req).await?;
+
+      let entitlements = ResolveEntitlementsUseCase::new(
+        SurrealSubscriptionRepository::new(db_connection),
+        SurrealPlanRepository::new(db_connection),
+      ).execute(&tenant).await?;
+
+      req.extensions_mut().insert(entitlements.clone());
+
+      Ok(entitlements)
+    })
+  }
+}
@@ -0,0 +1,5 @@
+pub mod auth_extractor;
+pub mod principal_extractor;
+pub mod client_info_extractor;
+pub mod tenant_extractor;
+pub mod entitlements_extractor;
\ No newline at end of file
@@ -0,0 +1,66 @@
+use actix_web::{dev::Payload, web, FromRequest, HttpMessage, HttpRequest};
+use futures_util::future::LocalBoxFuture;
+
+use crate::application::use_cases::api_keys::authenticate::AuthenticateApiKeyUseCase;
+use crate::domain::entities::api_key::ApiKey;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::principal::Principal;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::repositories::surreal_api_key_repository::SurrealApiKeyRepository;
+
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+
+// Acepta `X-Api-Key` (equipos) o `Authorization: Bearer` (usuarios); con ambos manda la clave
+impl FromRequest for Principal {
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+    let req = req.clone();
+    let mut payload = payload.take();
+
+    Box::pin(async move {
+      if let Some(principal) = req.extensions().get::<Principal>() {
+        return Ok(principal.clone());
+      }
+
+      let principal = match api_key(&req)? {
+        Some(key) => Principal::ApiKey(authenticate(&req, &key).await?),
+        None => Principal::User(AuthenticatedUser::from_request(&req, &mut payload).await?),
+      };
+
+      req.extensions_mut().insert(principal.clone());
+
+      Ok(principal)
+    })
+  }
+}
+
+async fn authenticate(req: &HttpRequest, key: &str) -> Result<ApiKey, Error> {
+  let db_connection = req.app_data::<web::Data<DatabaseConnection>>()
+    .ok_or(Error::ConnectionError("Database connection not registered".to_string()))?;
+
+  // La clave solo existe en su restaurante: sin `X-Tenant` o subdominio no se encuentra
+  let tenant = TenantContext::extract(req).await?;
+
+  AuthenticateApiKeyUseCase::new(SurrealApiKeyRepository::new(db_connection, &tenant))
+    .execute(key)
+    .await
+}
+
+pub fn api_key(req: &HttpRequest) -> Result<Option<String>, Error> {
+  match req.headers().get(API_KEY_HEADER) {
+    Some(value) => {
+      let key = value.to_str().map_err(|_| Error::InvalidToken)?.trim();
+
+      if key.is_empty() {
+        return Err(Error::InvalidToken);
+      }
+
+      Ok(Some(key.to_string()))
+    },
+    None => Ok(None),
+  }
+}
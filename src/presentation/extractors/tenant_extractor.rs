@@ -0,0 +1,92 @@
+use actix_web::{dev::Payload, web, FromRequest, HttpMessage, HttpRequest};
+use futures_util::future::LocalBoxFuture;
+
+use crate::application::use_cases::restaurants::resolve_tenant::ResolveTenantUseCase;
+use crate::domain::error::Error;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::config_env::Config;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::repositories::surreal_restaurant_repository::SurrealRestaurantRepository;
+use crate::presentation::extractors::auth_extractor::bearer_token;
+
+pub const TENANT_HEADER: &str = "X-Tenant";
+
+// Restaurante de la petición: header `X-Tenant`, subdominio o, si no viene ninguno, el claim `tid`
+// del bearer token. Sin nada de eso la petición es de la plataforma.
+impl FromRequest for TenantContext {
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+    let req = req.clone();
+
+    Box::pin(async move {
+      if let Some(tenant) = req.extensions().get::<TenantContext>() {
+        return Ok(tenant.clone());
+      }
+
+      let tenant = resolve(&req).await?;
+      req.extensions_mut().insert(tenant.clone());
+
+      Ok(tenant)
+    })
+  }
+}
+
+async fn resolve(req: &HttpRequest) -> Result<TenantContext, Error> {
+  let config = req.app_data::<web::Data<Config>>()
+    .ok_or(Error::ConfigError("Config not registered".to_string()))?;
+  let db_connection = req.app_data::<web::Data<DatabaseConnection>>()
+    .ok_or(Error::ConnectionError("Database connection not registered".to_string()))?;
+
+  let slug = match tenant_header(req)? {
+    Some(slug) => Some(slug),
+    None => subdomain(req, config.tenant_base_domain.as_deref()),
+  };
+
+  // Que el token sea del mismo restaurante lo comprueba la autenticación, aquí solo sirve de respaldo
+  let restaurant_id = match slug {
+    Some(_) => None,
+    None => token_tenant(req, config),
+  };
+
+  ResolveTenantUseCase::new(SurrealRestaurantRepository::new(db_connection))
+    .execute(slug.as_deref(), restaurant_id.as_deref())
+    .await
+}
+
+fn tenant_header(req: &HttpRequest) -> Result<Option<String>, Error> {
+  match req.headers().get(TENANT_HEADER) {
+    Some(value) => {
+      let slug = value.to_str().map_err(|_| Error::RestaurantNotFound)?.trim();
+
+      if slug.is_empty() {
+        return Err(Error::RestaurantNotFound);
+      }
+
+      Ok(Some(slug.to_lowercase()))
+    },
+    None => Ok(None),
+  }
+}
+
+// `{slug}.{base_domain}`; el propio dominio, `www` y los subdominios de más de un nivel no son restaurantes
+fn subdomain(req: &HttpRequest, base_domain: Option<&str>) -> Option<String> {
+  let base_domain = base_domain?;
+  let host = req.connection_info().host().to_lowercase();
+  let host = host.split(':').next()?;
+  let label = host.strip_suffix(base_domain)?.strip_suffix('.')?;
+
+  (!label.is_empty() && !label.contains('.') && label != "www").then(|| label.to_string())
+}
+
+// Un token inválido no decide nada: si el handler exige autenticación, fallará allí
+fn token_tenant(req: &HttpRequest, config: &Config) -> Option<String> {
+  let token = bearer_token(req).ok()?;
+
+  TokenService::new(config.token_config.clone())
+    .verify_access_token(&token)
+    .ok()?
+    .tid
+}
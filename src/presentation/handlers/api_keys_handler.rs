@@ -0,0 +1,66 @@
+use actix_web::{get, post, web, HttpResponse, ResponseError};
+
+use crate::application::dtos::api_keys::create_api_key_request::CreateApiKeyRequest;
+
+use crate::application::use_cases::api_keys::{
+  create::CreateApiKeyUseCase,
+  get_all::GetAllApiKeysUseCase,
+  revoke::RevokeApiKeyUseCase,
+};
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::repositories::surreal_api_key_repository::SurrealApiKeyRepository;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+#[post("/")]
+pub async fn create_api_key_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  request: web::Json<CreateApiKeyRequest>
+) -> HttpResponse {
+  let repo = SurrealApiKeyRepository::new(&db_connection, &tenant);
+
+  match CreateApiKeyUseCase::new(repo).execute(&auth_user, request.into_inner()).await {
+    Ok(response) => HttpResponse::Created().json(response),
+    Err(err @ (
+      Error::InvalidPermission(_) | Error::UnauthorizedAccess | Error::ValidationError(_) |
+      Error::InvalidInput(_) | Error::InvalidResource | Error::InvalidAction
+    )) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al crear la clave de API")
+  }
+}
+
+#[get("/")]
+pub async fn get_all_api_keys_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let repo = SurrealApiKeyRepository::new(&db_connection, &tenant);
+
+  match GetAllApiKeysUseCase::new(repo).execute(&auth_user).await {
+    Ok(api_keys) => HttpResponse::Ok().json(api_keys),
+    Err(err @ Error::InvalidPermission(_)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener las claves de API")
+  }
+}
+
+#[post("/{id}/revoke")]
+pub async fn revoke_api_key_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealApiKeyRepository::new(&db_connection, &tenant);
+
+  match RevokeApiKeyUseCase::new(repo).execute(&auth_user, &id.into_inner()).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ Error::InvalidPermission(_)) => err.error_response(),
+    Err(Error::InvalidInput(_)) => HttpResponse::NotFound().body("Clave de API no encontrada"),
+    Err(_) => HttpResponse::InternalServerError().body("Error al revocar la clave de API")
+  }
+}
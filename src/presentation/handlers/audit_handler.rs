@@ -0,0 +1,25 @@
+use actix_web::{get, web, HttpResponse, ResponseError};
+
+use crate::application::dtos::audit::audit_query_request::AuditQueryRequest;
+use crate::application::use_cases::audit::get_all::GetAuditEventsUseCase;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::repositories::surreal_audit_repository::SurrealAuditRepository;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+#[get("")]
+pub async fn get_audit_events_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  query: web::Query<AuditQueryRequest>
+) -> HttpResponse {
+  let repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match GetAuditEventsUseCase::new(repo).execute(&auth_user, query.into_inner()).await {
+    Ok(page) => HttpResponse::Ok().json(page),
+    Err(err @ (Error::UnauthorizedAccess | Error::InvalidInput(_))) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener el registro de auditoría")
+  }
+}
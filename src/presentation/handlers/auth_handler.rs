@@ -1,37 +1,92 @@
-use actix_web::{post, web, HttpResponse};
+use actix_web::{get, post, web, HttpResponse, ResponseError};
 
-use crate::application::dtos::register::register_request::RegisterRequest;
+use crate::application::dtos::register::{
+  register_request::RegisterRequest,
+  verify_email_request::VerifyEmailRequest,
+  resend_verification_request::ResendVerificationRequest,
+};
 use crate::application::dtos::login::login_request::LoginRequest;
+use crate::application::dtos::refresh::refresh_request::RefreshRequest;
+use crate::application::dtos::password::{
+  forgot_password_request::ForgotPasswordRequest,
+  reset_password_request::ResetPasswordRequest,
+};
+use crate::application::dtos::pos::pin_login_request::PinLoginRequest;
 
 use crate::infrastructure::repositories::{
   surreal_user_repository::SurrealUserRepository,
-  surreal_token_repository::SurrealTokenRepository
+  surreal_token_repository::SurrealTokenRepository,
+  surreal_one_time_token_repository::SurrealOneTimeTokenRepository,
+  surreal_mfa_repository::SurrealMfaRepository,
+  surreal_pos_device_repository::SurrealPosDeviceRepository,
+  surreal_staff_pin_repository::SurrealStaffPinRepository,
+  cached_revoked_token_repository::CachedRevokedTokenRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+  surreal_role_repository::SurrealRoleRepository,
+  surreal_restaurant_repository::SurrealRestaurantRepository,
 };
 
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::entitlements::Entitlements;
+use crate::domain::entities::one_time_token::TokenPurpose;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::infrastructure::config_env::Config;
 use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::revocation_cache::RevocationCache;
+use crate::domain::services::mailer::Mailer;
 use crate::domain::services::token::TokenService;
+use crate::domain::services::rate_limit::RateLimitScope;
+use crate::presentation::middlewares::rate_limiter::RateLimiter;
 
+use crate::application::use_cases::mfa::resolve_policy::ResolveMfaPolicyUseCase;
 use crate::application::use_cases::auth::{
   login::LoginUseCase,
   register::RegisterUseCase,
-  logout::LogoutUseCase
+  logout::LogoutUseCase,
+  refresh::RefreshTokenUseCase,
+  send_verification::SendVerificationEmailUseCase,
+  verify_email::VerifyEmailUseCase,
+  resend_verification::ResendVerificationUseCase,
+  forgot_password::ForgotPasswordUseCase,
+  reset_password::ResetPasswordUseCase,
+  pin_login::PinLoginUseCase
 };
 
-#[post("/register")]
+#[post("/register", wrap = "RateLimiter::new(RateLimitScope::Register)")]
+#[allow(clippy::too_many_arguments)]
 pub async fn register_handler(
   config: web::Data<Config>,
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  mailer: web::Data<dyn Mailer>,
+  client: ClientInfo,
+  entitlements: Entitlements,
+  // Solo para las altas de personal
+  creator: Option<AuthenticatedUser>,
   request: web::Json<RegisterRequest>
 ) -> HttpResponse {
 
   let token_service = TokenService::new(config.token_config.clone());
-  let repo = SurrealUserRepository::new(&db_connection);
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let role_repo = SurrealRoleRepository::new(&db_connection, &tenant);
   let token_repo = SurrealTokenRepository::new(&db_connection);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+  let send_verification = SendVerificationEmailUseCase::new(
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    token_service.clone(),
+    mailer.into_inner(),
+    config.mailer.app_url.clone()
+  );
   
-    match RegisterUseCase::new(repo, token_repo, token_service)
-      .execute(request.into_inner()).await {
+    match RegisterUseCase::new(repo, role_repo, token_repo, audit_repo, token_service, config.password_policy.clone(), send_verification)
+      .execute(request.into_inner(), creator.as_ref(), &client, &entitlements).await {
         Ok(_) => HttpResponse::Ok().finish(),
+        Err(err @ (
+          Error::PasswordPolicy(_) | Error::SubscriptionRequired | Error::PlanLimitReached(_) |
+          Error::UnauthorizedOperation | Error::InvalidOperation | Error::InvalidPermission(_) | Error::RoleHierarchyViolation
+        )) => err.error_response(),
         Err(_) => {
           //Todo: Log de errores
           //error!("Error en el registro: {:?}", err);
@@ -40,19 +95,40 @@ pub async fn register_handler(
       }
 }
 
-#[post("/login")]
+#[post("/login", wrap = "RateLimiter::new(RateLimitScope::Login)")]
 pub async fn login_handler(
   config: web::Data<Config>,
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  client: ClientInfo,
   request: web::Json<LoginRequest>
 ) -> HttpResponse {
-  let repo = SurrealUserRepository::new(&db_connection);
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
   let token_repo = SurrealTokenRepository::new(&db_connection);
+  let one_time_token_repo = SurrealOneTimeTokenRepository::new(&db_connection);
+  let mfa_repo = SurrealMfaRepository::new(&db_connection);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
   let token_service = TokenService::new(config.token_config.clone());
+
+  let mfa_policy = match ResolveMfaPolicyUseCase::new(SurrealRestaurantRepository::new(&db_connection), config.mfa_policy.clone())
+    .execute(&tenant).await {
+      Ok(policy) => policy,
+      Err(_) => return HttpResponse::InternalServerError().body("Error al iniciar sesión")
+    };
   
-  match LoginUseCase::new(repo, token_repo, token_service)
-    .execute(request.into_inner()).await {
+  match LoginUseCase::new(
+    repo,
+    token_repo,
+    one_time_token_repo,
+    mfa_repo,
+    audit_repo,
+    token_service,
+    config.lockout_policy.clone(),
+    mfa_policy,
+    config.password_policy.clone()
+  ).execute(request.into_inner(), &client).await {
       Ok(response) => HttpResponse::Ok().json(response),
+      Err(err @ (Error::AccountLocked(_) | Error::PasswordExpired)) => err.error_response(),
       Err(_) => {
         //Todo: Log de errores
         //error!("Error en el login: {:?}", err);
@@ -61,19 +137,166 @@ pub async fn login_handler(
     }
 }
 
+#[post("/pin-login", wrap = "RateLimiter::new(RateLimitScope::Login)")]
+pub async fn pin_login_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  request: web::Json<PinLoginRequest>
+) -> HttpResponse {
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let device_repo = SurrealPosDeviceRepository::new(&db_connection, &tenant);
+  let pin_repo = SurrealStaffPinRepository::new(&db_connection, &tenant);
+  let token_service = TokenService::new(config.token_config.clone());
+
+  match PinLoginUseCase::new(repo, device_repo, pin_repo, token_service, config.pin_lockout_policy.clone())
+    .execute(request.into_inner()).await {
+      Ok(response) => HttpResponse::Ok().json(response),
+      Err(err @ Error::AccountLocked(_)) => err.error_response(),
+      Err(_) => HttpResponse::Unauthorized().body("PIN o terminal inválido")
+    }
+}
+
+#[get("/verify")]
+pub async fn verify_email_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  query: web::Query<VerifyEmailRequest>
+) -> HttpResponse {
+  let token_service = TokenService::new(config.token_config.clone());
+
+  // El enlace no lleva el tenant: el usuario vive en el restaurante del token
+  let Ok(tenant) = one_time_token_tenant(&token_service, &query.token, TokenPurpose::EmailVerification) else {
+    return HttpResponse::BadRequest().body("Enlace de verificación inválido o vencido");
+  };
+
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let one_time_token_repo = SurrealOneTimeTokenRepository::new(&db_connection);
+
+  match VerifyEmailUseCase::new(repo, one_time_token_repo, token_service)
+    .execute(&query.token).await {
+      Ok(_) => HttpResponse::Ok().finish(),
+      Err(_) => HttpResponse::BadRequest().body("Enlace de verificación inválido o vencido")
+    }
+}
+
+#[post("/verify/resend", wrap = "RateLimiter::new(RateLimitScope::PasswordReset)")]
+pub async fn resend_verification_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  mailer: web::Data<dyn Mailer>,
+  request: web::Json<ResendVerificationRequest>
+) -> HttpResponse {
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let token_service = TokenService::new(config.token_config.clone());
+  let send_verification = SendVerificationEmailUseCase::new(
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    token_service,
+    mailer.into_inner(),
+    config.mailer.app_url.clone()
+  );
+
+  match ResendVerificationUseCase::new(
+    repo,
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    send_verification,
+    config.token_config.verification_resend_cooldown
+  ).execute(&request.email).await {
+    Ok(_) => HttpResponse::Accepted().finish(),
+    Err(err @ Error::TooManyRequests(_)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al reenviar la verificación")
+  }
+}
+
+#[post("/forgot-password", wrap = "RateLimiter::new(RateLimitScope::PasswordReset)")]
+pub async fn forgot_password_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  mailer: web::Data<dyn Mailer>,
+  request: web::Json<ForgotPasswordRequest>
+) -> HttpResponse {
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let one_time_token_repo = SurrealOneTimeTokenRepository::new(&db_connection);
+  let token_service = TokenService::new(config.token_config.clone());
+
+  // Siempre 202, exista o no el correo
+  match ForgotPasswordUseCase::new(repo, one_time_token_repo, token_service, mailer.into_inner(), config.mailer.app_url.clone())
+    .execute(request.into_inner()).await {
+      Ok(_) => HttpResponse::Accepted().finish(),
+      Err(err) => {
+        log::error!("Error en la recuperación de contraseña: {}", err);
+        HttpResponse::Accepted().finish()
+      }
+    }
+}
+
+#[post("/reset-password", wrap = "RateLimiter::new(RateLimitScope::PasswordReset)")]
+pub async fn reset_password_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  revocation_cache: web::Data<RevocationCache>,
+  request: web::Json<ResetPasswordRequest>
+) -> HttpResponse {
+  let token_service = TokenService::new(config.token_config.clone());
+
+  // Igual que en la verificación: el tenant sale del token, no de la petición
+  let Ok(tenant) = one_time_token_tenant(&token_service, &request.token, TokenPurpose::PasswordReset) else {
+    return HttpResponse::BadRequest().body("Enlace de restablecimiento inválido o vencido");
+  };
+
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let token_repo = SurrealTokenRepository::new(&db_connection);
+  let one_time_token_repo = SurrealOneTimeTokenRepository::new(&db_connection);
+  let revoked_token_repo = CachedRevokedTokenRepository::new(&db_connection, revocation_cache.into_inner());
+
+  match ResetPasswordUseCase::new(repo, token_repo, one_time_token_repo, revoked_token_repo, token_service, config.password_policy.clone())
+    .execute(request.into_inner()).await {
+      Ok(_) => HttpResponse::Ok().finish(),
+      Err(err @ Error::PasswordPolicy(_)) => err.error_response(),
+      Err(Error::InvalidToken | Error::TokenExpired) => HttpResponse::BadRequest().body("Enlace de restablecimiento inválido o vencido"),
+      Err(_) => HttpResponse::InternalServerError().body("Error al restablecer la contraseña")
+    }
+}
+
+#[post("/refresh")]
+pub async fn refresh_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  client: ClientInfo,
+  request: web::Json<RefreshRequest>
+) -> HttpResponse {
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let token_repo = SurrealTokenRepository::new(&db_connection);
+  let token_service = TokenService::new(config.token_config.clone());
+
+  match RefreshTokenUseCase::new(repo, token_repo, token_service)
+    .execute(request.into_inner(), &client).await {
+      Ok(response) => HttpResponse::Ok().json(response),
+      Err(_) => HttpResponse::Unauthorized().body("Refresh token inválido")
+    }
+}
+
 #[post("/logout")]
 pub async fn logout_handler(
   config: web::Data<Config>,
   db_connection: web::Data<DatabaseConnection>,
+  revocation_cache: web::Data<RevocationCache>,
+  auth_user: AuthenticatedUser,
 ) -> HttpResponse {
   let repo = SurrealTokenRepository::new(&db_connection);
+  let revoked_token_repo = CachedRevokedTokenRepository::new(&db_connection, revocation_cache.into_inner());
   let token_service = TokenService::new(config.token_config.clone());
 
-  //Todo: Obtener el token del header
-  let token = "token";
-
-  match LogoutUseCase::new(repo, token_service).execute(token).await {
+  match LogoutUseCase::new(repo, revoked_token_repo, token_service).execute(&auth_user).await {
     Ok(_) => HttpResponse::Ok().finish(),
     Err(_) => HttpResponse::Unauthorized().body("Token inválido")
   }
+}
+
+fn one_time_token_tenant(token_service: &TokenService, token: &str, purpose: TokenPurpose) -> Result<TenantContext, Error> {
+  let claims = token_service.verify_one_time_token(token, purpose)?;
+  Ok(TenantContext::from_restaurant_id(claims.tid.as_deref()))
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+use actix_web::{post, get, put, delete, web, HttpResponse, ResponseError};
+
+use crate::application::dtos::locations::{
+  create_location_request::CreateLocationRequest,
+  update_location_request::UpdateLocationRequest,
+};
+
+use crate::application::use_cases::locations::{
+  create::CreateLocationUseCase,
+  get_all::GetAllLocationsUseCase,
+  get_by_id::GetLocationByIdUseCase,
+  get_mine::GetMyLocationsUseCase,
+  update::UpdateLocationUseCase,
+  remove::RemoveLocationUseCase,
+  assign_user::AssignLocationUserUseCase,
+  unassign_user::UnassignLocationUserUseCase,
+};
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::entities::entitlements::Entitlements;
+use crate::domain::error::Error;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::repositories::{
+  surreal_location_repository::SurrealLocationRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+};
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+#[post("/")]
+pub async fn create_location_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  entitlements: Entitlements,
+  request: web::Json<CreateLocationRequest>
+) -> HttpResponse {
+  let repo = SurrealLocationRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match CreateLocationUseCase::new(repo, audit_repo).execute(&auth_user, &client, &entitlements, &request.into_inner()).await {
+    Ok(location) => HttpResponse::Created().json(location),
+    Err(err @ (Error::InvalidPermission(_) | Error::ValidationError(_) | Error::LocationAlreadyExists(_) | Error::SubscriptionRequired | Error::PlanLimitReached(_))) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al crear la sucursal")
+  }
+}
+
+#[get("/")]
+pub async fn get_all_locations_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let repo = SurrealLocationRepository::new(&db_connection, &tenant);
+
+  match GetAllLocationsUseCase::new(repo).execute(&auth_user).await {
+    Ok(locations) => HttpResponse::Ok().json(locations),
+    Err(err @ Error::InvalidPermission(_)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener las sucursales")
+  }
+}
+
+#[get("/mine")]
+pub async fn get_my_locations_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let repo = SurrealLocationRepository::new(&db_connection, &auth_user.tenant());
+
+  match GetMyLocationsUseCase::new(repo).execute(&auth_user).await {
+    Ok(locations) => HttpResponse::Ok().json(locations),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener las sucursales")
+  }
+}
+
+#[get("/{id}")]
+pub async fn get_location_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealLocationRepository::new(&db_connection, &tenant);
+
+  match GetLocationByIdUseCase::new(repo).execute(&auth_user, &id.into_inner()).await {
+    Ok(location) => HttpResponse::Ok().json(location),
+    Err(err @ (Error::InvalidPermission(_) | Error::LocationMismatch | Error::LocationNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener la sucursal")
+  }
+}
+
+#[put("/{id}")]
+pub async fn update_location_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  id: web::Path<String>,
+  request: web::Json<UpdateLocationRequest>
+) -> HttpResponse {
+  let repo = SurrealLocationRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match UpdateLocationUseCase::new(repo, audit_repo).execute(&auth_user, &client, &id.into_inner(), &request.into_inner()).await {
+    Ok(location) => HttpResponse::Ok().json(location),
+    Err(err @ (
+      Error::InvalidPermission(_) | Error::LocationMismatch | Error::LocationNotFound |
+      Error::ValidationError(_) | Error::LocationAlreadyExists(_)
+    )) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al actualizar la sucursal")
+  }
+}
+
+#[delete("/{id}")]
+pub async fn delete_location_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealLocationRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match RemoveLocationUseCase::new(repo, audit_repo).execute(&auth_user, &client, &id.into_inner()).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::InvalidPermission(_) | Error::LocationNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al eliminar la sucursal")
+  }
+}
+
+#[post("/{id}/users/{user_id}")]
+pub async fn assign_location_user_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  path: web::Path<(String, String)>
+) -> HttpResponse {
+  let (location_id, user_id) = path.into_inner();
+  let repo = SurrealLocationRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match AssignLocationUserUseCase::new(repo, audit_repo).execute(&auth_user, &client, &location_id, &user_id).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::InvalidPermission(_) | Error::LocationMismatch | Error::LocationNotFound | Error::UserNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al asignar el usuario a la sucursal")
+  }
+}
+
+#[delete("/{id}/users/{user_id}")]
+pub async fn unassign_location_user_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  path: web::Path<(String, String)>
+) -> HttpResponse {
+  let (location_id, user_id) = path.into_inner();
+  let repo = SurrealLocationRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match UnassignLocationUserUseCase::new(repo, audit_repo).execute(&auth_user, &client, &location_id, &user_id).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::InvalidPermission(_) | Error::LocationMismatch | Error::UserNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al quitar el usuario de la sucursal")
+  }
+}
@@ -0,0 +1,199 @@
+use actix_web::{post, put, web, HttpResponse, ResponseError};
+
+use crate::application::dtos::mfa::{
+  mfa_code_request::MfaCodeRequest,
+  mfa_challenge_request::MfaChallengeRequest,
+  verify_mfa_request::VerifyMfaRequest,
+  mfa_policy_request::MfaPolicyRequest,
+};
+
+use crate::application::use_cases::mfa::{
+  enroll::EnrollMfaUseCase,
+  confirm::ConfirmMfaUseCase,
+  verify::VerifyMfaUseCase,
+  regenerate_recovery_codes::RegenerateRecoveryCodesUseCase,
+  disable::DisableMfaUseCase,
+  resolve_policy::ResolveMfaPolicyUseCase,
+  set_policy::SetMfaPolicyUseCase,
+};
+
+use crate::infrastructure::repositories::{
+  surreal_user_repository::SurrealUserRepository,
+  surreal_token_repository::SurrealTokenRepository,
+  surreal_one_time_token_repository::SurrealOneTimeTokenRepository,
+  surreal_mfa_repository::SurrealMfaRepository,
+  surreal_restaurant_repository::SurrealRestaurantRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+  surreal_role_repository::SurrealRoleRepository,
+};
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::services::token::TokenService;
+use crate::domain::services::totp::TotpService;
+use crate::domain::services::rate_limit::RateLimitScope;
+use crate::presentation::middlewares::rate_limiter::RateLimiter;
+use crate::infrastructure::config_env::Config;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+#[post("/enroll")]
+pub async fn enroll_mfa_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let use_case = EnrollMfaUseCase::new(
+    SurrealUserRepository::new(&db_connection, &auth_user.tenant()),
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    SurrealMfaRepository::new(&db_connection),
+    TokenService::new(config.token_config.clone()),
+    TotpService::new(config.totp_config.clone())
+  );
+
+  match use_case.execute(&auth_user).await {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(err @ (Error::MfaAlreadyEnabled | Error::UnauthorizedAccess)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al configurar la verificación en dos pasos")
+  }
+}
+
+#[post("/challenge/enroll")]
+pub async fn enroll_mfa_challenge_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  request: web::Json<MfaChallengeRequest>
+) -> HttpResponse {
+  let use_case = EnrollMfaUseCase::new(
+    SurrealUserRepository::new(&db_connection, &tenant),
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    SurrealMfaRepository::new(&db_connection),
+    TokenService::new(config.token_config.clone()),
+    TotpService::new(config.totp_config.clone())
+  );
+
+  match use_case.execute_with_challenge(&request.mfa_token).await {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(err @ Error::MfaAlreadyEnabled) => err.error_response(),
+    Err(Error::InvalidToken | Error::TokenExpired) => HttpResponse::Unauthorized().body("Token de verificación inválido o vencido"),
+    Err(_) => HttpResponse::InternalServerError().body("Error al configurar la verificación en dos pasos")
+  }
+}
+
+#[post("/confirm")]
+pub async fn confirm_mfa_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  request: web::Json<MfaCodeRequest>
+) -> HttpResponse {
+  let use_case = ConfirmMfaUseCase::new(
+    SurrealMfaRepository::new(&db_connection),
+    TotpService::new(config.totp_config.clone())
+  );
+
+  match use_case.execute(&auth_user, &request.into_inner()).await {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(err @ (Error::InvalidMfaCode | Error::MfaNotEnrolled | Error::MfaAlreadyEnabled | Error::UnauthorizedAccess)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al activar la verificación en dos pasos")
+  }
+}
+
+#[post("/verify", wrap = "RateLimiter::new(RateLimitScope::Login)")]
+pub async fn verify_mfa_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  client: ClientInfo,
+  request: web::Json<VerifyMfaRequest>
+) -> HttpResponse {
+  let use_case = VerifyMfaUseCase::new(
+    SurrealUserRepository::new(&db_connection, &tenant),
+    SurrealTokenRepository::new(&db_connection),
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    SurrealMfaRepository::new(&db_connection),
+    TokenService::new(config.token_config.clone()),
+    TotpService::new(config.totp_config.clone()),
+    config.lockout_policy.clone()
+  );
+
+  match use_case.execute(request.into_inner(), &client).await {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(err @ (Error::InvalidMfaCode | Error::AccountLocked(_) | Error::MfaNotEnrolled | Error::InvalidInput(_))) => err.error_response(),
+    Err(_) => HttpResponse::Unauthorized().body("Token de verificación inválido o vencido")
+  }
+}
+
+#[post("/recovery-codes")]
+pub async fn regenerate_recovery_codes_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  request: web::Json<MfaCodeRequest>
+) -> HttpResponse {
+  let use_case = RegenerateRecoveryCodesUseCase::new(
+    SurrealMfaRepository::new(&db_connection),
+    TotpService::new(config.totp_config.clone())
+  );
+
+  match use_case.execute(&auth_user, &request.into_inner()).await {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(err @ (Error::InvalidMfaCode | Error::MfaNotEnrolled | Error::UnauthorizedAccess)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al generar los códigos de recuperación")
+  }
+}
+
+#[post("/disable")]
+pub async fn disable_mfa_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  request: web::Json<MfaCodeRequest>
+) -> HttpResponse {
+  // La política es la del restaurante del usuario, no la del header de la petición
+  let mfa_policy = match ResolveMfaPolicyUseCase::new(SurrealRestaurantRepository::new(&db_connection), config.mfa_policy.clone())
+    .execute(&auth_user.tenant()).await {
+      Ok(policy) => policy,
+      Err(_) => return HttpResponse::InternalServerError().body("Error al desactivar la verificación en dos pasos")
+    };
+
+  let use_case = DisableMfaUseCase::new(
+    SurrealMfaRepository::new(&db_connection),
+    TotpService::new(config.totp_config.clone()),
+    mfa_policy
+  );
+
+  match use_case.execute(&auth_user, &request.into_inner()).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::InvalidMfaCode | Error::MfaNotEnrolled | Error::MfaRequired | Error::UnauthorizedAccess)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al desactivar la verificación en dos pasos")
+  }
+}
+
+// Tipos de usuario del restaurante que deben usar 2FA
+#[put("/policy")]
+pub async fn set_mfa_policy_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  request: web::Json<MfaPolicyRequest>
+) -> HttpResponse {
+  let use_case = SetMfaPolicyUseCase::new(
+    SurrealRestaurantRepository::new(&db_connection),
+    SurrealRoleRepository::new(&db_connection, &auth_user.tenant()),
+    SurrealAuditRepository::new(&db_connection, &auth_user.tenant()),
+    config.mfa_policy.clone()
+  );
+
+  match use_case.execute(&auth_user, &client, &request.into_inner()).await {
+    Ok(policy) => HttpResponse::Ok().json(policy),
+    Err(err @ (
+      Error::InvalidPermission(_) | Error::InvalidOperation | Error::ValidationError(_) |
+      Error::RestaurantNotFound | Error::UnauthorizedAccess | Error::RoleHierarchyViolation
+    )) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al cambiar la política de verificación en dos pasos")
+  }
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use actix_web::{get, post, web, HttpResponse};
+
+use crate::infrastructure::oidc::mock_issuer::{MockAuthorizeRequest, MockIssuer, MockTokenRequest};
+
+// Proveedor OIDC de pruebas (OIDC_MOCK_ISSUER); ver `MockIssuer`
+#[get("/.well-known/openid-configuration")]
+pub async fn mock_oidc_discovery_handler(issuer: web::Data<MockIssuer>) -> HttpResponse {
+  HttpResponse::Ok().json(issuer.metadata())
+}
+
+#[get("/jwks")]
+pub async fn mock_oidc_jwks_handler(issuer: web::Data<MockIssuer>) -> HttpResponse {
+  HttpResponse::Ok().json(issuer.jwks())
+}
+
+#[get("/authorize")]
+pub async fn mock_oidc_authorize_handler(
+  issuer: web::Data<MockIssuer>,
+  request: web::Query<MockAuthorizeRequest>
+) -> HttpResponse {
+  match issuer.authorize(request.into_inner()) {
+    Ok(location) => HttpResponse::Found().insert_header(("Location", location)).finish(),
+    Err(err) => HttpResponse::BadRequest().body(err.to_string())
+  }
+}
+
+// Errores con el formato de OAuth 2.0 (RFC 6749, sección 5.2)
+#[post("/token")]
+pub async fn mock_oidc_token_handler(
+  issuer: web::Data<MockIssuer>,
+  request: web::Form<MockTokenRequest>
+) -> HttpResponse {
+  match issuer.token(request.into_inner()) {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(_) => HttpResponse::BadRequest().json(serde_json::json!({ "error": "invalid_grant" }))
+  }
+}
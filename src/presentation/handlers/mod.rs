@@ -4,3 +4,16 @@ pub mod roles_handler;
 pub mod permissions_handler;
 pub mod profile_handler;
 pub mod users_handler;
+
+pub mod mfa_handler;
+pub mod pos_handler;
+pub mod api_keys_handler;
+pub mod well_known_handler;
+pub mod sessions_handler;
+pub mod audit_handler;
+pub mod oidc_handler;
+pub mod mock_oidc_handler;
+pub mod restaurants_handler;
+pub mod locations_handler;
+pub mod tenants_handler;
+pub mod subscriptions_handler;
\ No newline at end of file
@@ -0,0 +1,99 @@
+use actix_web::{get, web, HttpResponse, ResponseError};
+
+use crate::application::dtos::oidc::oidc_callback_request::OidcCallbackRequest;
+use crate::application::use_cases::oidc::{
+  authorize::StartOidcLoginUseCase,
+  callback::CompleteOidcLoginUseCase,
+};
+use crate::application::use_cases::mfa::resolve_policy::ResolveMfaPolicyUseCase;
+
+use crate::infrastructure::repositories::{
+  surreal_user_repository::SurrealUserRepository,
+  surreal_token_repository::SurrealTokenRepository,
+  surreal_one_time_token_repository::SurrealOneTimeTokenRepository,
+  surreal_mfa_repository::SurrealMfaRepository,
+  surreal_external_identity_repository::SurrealExternalIdentityRepository,
+  surreal_oidc_authorization_repository::SurrealOidcAuthorizationRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+  surreal_restaurant_repository::SurrealRestaurantRepository,
+  surreal_role_repository::SurrealRoleRepository,
+};
+
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::domain::services::oidc::OidcProvider;
+use crate::domain::services::rate_limit::RateLimitScope;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::infrastructure::config_env::Config;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::presentation::middlewares::rate_limiter::RateLimiter;
+
+// El proveedor solo se registra si OIDC está configurado
+#[get("/oidc/authorize", wrap = "RateLimiter::new(RateLimitScope::Login)")]
+pub async fn oidc_authorize_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  provider: Option<web::Data<dyn OidcProvider>>,
+) -> HttpResponse {
+  let (Some(oidc_config), Some(provider)) = (config.oidc.clone(), provider) else {
+    return Error::OidcNotConfigured.error_response();
+  };
+
+  let authorization_repo = SurrealOidcAuthorizationRepository::new(&db_connection, &tenant);
+
+  match StartOidcLoginUseCase::new(authorization_repo, provider.into_inner(), oidc_config).execute().await {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(err @ Error::IdentityProviderError(_)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al iniciar el login externo")
+  }
+}
+
+#[get("/oidc/callback", wrap = "RateLimiter::new(RateLimitScope::Login)")]
+pub async fn oidc_callback_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  provider: Option<web::Data<dyn OidcProvider>>,
+  client: ClientInfo,
+  request: web::Query<OidcCallbackRequest>
+) -> HttpResponse {
+  let (Some(oidc_config), Some(provider)) = (config.oidc.clone(), provider) else {
+    return Error::OidcNotConfigured.error_response();
+  };
+
+  let mfa_policy = match ResolveMfaPolicyUseCase::new(SurrealRestaurantRepository::new(&db_connection), config.mfa_policy.clone())
+    .execute(&tenant).await {
+      Ok(policy) => policy,
+      Err(_) => return HttpResponse::InternalServerError().body("Error en el login externo")
+    };
+
+  match CompleteOidcLoginUseCase::new(
+    SurrealUserRepository::new(&db_connection, &tenant),
+    SurrealRoleRepository::new(&db_connection, &tenant),
+    SurrealTokenRepository::new(&db_connection),
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    SurrealMfaRepository::new(&db_connection),
+    SurrealExternalIdentityRepository::new(&db_connection, &tenant),
+    SurrealOidcAuthorizationRepository::new(&db_connection, &tenant),
+    SurrealAuditRepository::new(&db_connection, &tenant),
+    provider.into_inner(),
+    oidc_config,
+    TokenService::new(config.token_config.clone()),
+    config.lockout_policy.clone(),
+    mfa_policy,
+  ).execute(request.into_inner(), &client).await {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(err @ (
+      Error::InvalidToken
+      | Error::TokenExpired
+      | Error::InvalidInput(_)
+      | Error::UnverifiedExternalEmail
+      | Error::UnauthorizedAccess
+      | Error::AccountLocked(_)
+      | Error::IdentityProviderError(_)
+    )) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error en el login externo")
+  }
+}
@@ -1,4 +1,4 @@
-use actix_web::{post, get, put, delete, web, HttpResponse};
+use actix_web::{post, get, put, delete, web, HttpResponse, ResponseError};
 
 use crate::application::dtos::permissions::{
   create_permission_request::CreatePermissionRequest,
@@ -13,14 +13,22 @@ use crate::application::use_cases::permissions::{
   remove::RemovePermissionUseCase
 };
 
-use crate::infrastructure::repositories::surreal_permission_repository::SurrealPermissionRepository;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::repositories::{
+  surreal_permission_repository::SurrealPermissionRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+};
 use crate::infrastructure::database::surreal_connection::DatabaseConnection;
 
 #[get("/")]
 pub async fn get_all_permissions_handler(
-  db_connection: web::Data<DatabaseConnection>
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext
 ) -> HttpResponse {
-  let repo = SurrealPermissionRepository::new(&db_connection);
+  let repo = SurrealPermissionRepository::new(&db_connection, &tenant);
 
   match GetAllPermissionsUseCase::new(repo).execute().await {
     Ok(permissions) => HttpResponse::Ok().json(permissions),
@@ -31,9 +39,10 @@ pub async fn get_all_permissions_handler(
 #[get("/{id}")]
 pub async fn get_permission_by_id_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
   id: web::Path<String>
 ) -> HttpResponse {
-  let repo = SurrealPermissionRepository::new(&db_connection);
+  let repo = SurrealPermissionRepository::new(&db_connection, &tenant);
 
   match GetPermissionByIdUseCase::new(repo).execute(&id.into_inner()).await {
     Ok(permission) => HttpResponse::Ok().json(permission),
@@ -44,14 +53,20 @@ pub async fn get_permission_by_id_handler(
 #[post("/")]
 pub async fn create_permission_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
   request: web::Json<CreatePermissionRequest>
 ) -> HttpResponse {
   
-  let repo = SurrealPermissionRepository::new(&db_connection);
+  // El catálogo lo mantiene la plataforma, en su base
+  let repo = SurrealPermissionRepository::new(&db_connection, &TenantContext::platform());
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
   
-  match CreatePermissionUseCase::new(repo)
-    .execute(&request.into_inner()).await {
+  match CreatePermissionUseCase::new(repo, audit_repo)
+    .execute(&auth_user, &client, &request.into_inner()).await {
       Ok(response) => HttpResponse::Ok().json(response),
+      Err(err @ Error::UnauthorizedAccess) => err.error_response(),
       Err(_) => {
         //Todo: Log de errores
         //error!("Error en el registro: {:?}", err);
@@ -63,15 +78,21 @@ pub async fn create_permission_handler(
 #[put("/{id}")]
 pub async fn update_permission_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
   request: web::Json<UpdatePermissionRequest>,
   id: web::Path<String>
 ) -> HttpResponse {
 
-  let repo = SurrealPermissionRepository::new(&db_connection);
+  // El catálogo lo mantiene la plataforma, en su base
+  let repo = SurrealPermissionRepository::new(&db_connection, &TenantContext::platform());
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
 
-  match UpdatePermissionUseCase::new(repo)
-    .execute(&id.into_inner(),&request.into_inner()).await {
+  match UpdatePermissionUseCase::new(repo, audit_repo)
+    .execute(&auth_user, &client, &id.into_inner(),&request.into_inner()).await {
       Ok(response) => HttpResponse::Ok().json(response),
+      Err(err @ Error::UnauthorizedAccess) => err.error_response(),
       Err(_) => HttpResponse::InternalServerError().body("Error al actualizar permiso")
     }
 }
@@ -79,13 +100,19 @@ pub async fn update_permission_handler(
 #[delete("/{id}")]
 pub async fn delete_permission_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
   id: web::Path<String>
 ) -> HttpResponse {
 
-  let repo = SurrealPermissionRepository::new(&db_connection);
+  // El catálogo lo mantiene la plataforma, en su base
+  let repo = SurrealPermissionRepository::new(&db_connection, &TenantContext::platform());
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
 
-  match RemovePermissionUseCase::new(repo).execute(&id.into_inner()).await {
+  match RemovePermissionUseCase::new(repo, audit_repo).execute(&auth_user, &client, &id.into_inner()).await {
     Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ Error::UnauthorizedAccess) => err.error_response(),
     Err(_) => HttpResponse::InternalServerError().body("Error al eliminar permiso")
   }
 }
\ No newline at end of file
@@ -0,0 +1,104 @@
+use actix_web::{delete, get, post, web, HttpResponse, ResponseError};
+
+use crate::application::dtos::pos::{
+  register_device_request::RegisterDeviceRequest,
+  set_pin_request::SetPinRequest,
+};
+
+use crate::application::use_cases::pos::{
+  register_device::RegisterDeviceUseCase,
+  get_devices::GetDevicesUseCase,
+  revoke_device::RevokeDeviceUseCase,
+  set_pin::SetPinUseCase,
+  remove_pin::RemovePinUseCase,
+};
+
+use crate::infrastructure::repositories::{
+  surreal_pos_device_repository::SurrealPosDeviceRepository,
+  surreal_staff_pin_repository::SurrealStaffPinRepository,
+};
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+#[post("/devices")]
+pub async fn register_device_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  request: web::Json<RegisterDeviceRequest>
+) -> HttpResponse {
+  let repo = SurrealPosDeviceRepository::new(&db_connection, &tenant);
+
+  match RegisterDeviceUseCase::new(repo).execute(&auth_user, &request.into_inner()).await {
+    Ok(response) => HttpResponse::Created().json(response),
+    Err(err @ (Error::InvalidPermission(_) | Error::UnauthorizedAccess | Error::ValidationError(_))) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al registrar el terminal")
+  }
+}
+
+#[get("/devices")]
+pub async fn get_devices_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let repo = SurrealPosDeviceRepository::new(&db_connection, &tenant);
+
+  match GetDevicesUseCase::new(repo).execute(&auth_user).await {
+    Ok(devices) => HttpResponse::Ok().json(devices),
+    Err(err @ Error::InvalidPermission(_)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener los terminales")
+  }
+}
+
+#[post("/devices/{id}/revoke")]
+pub async fn revoke_device_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealPosDeviceRepository::new(&db_connection, &tenant);
+
+  match RevokeDeviceUseCase::new(repo).execute(&auth_user, &id.into_inner()).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ Error::InvalidPermission(_)) => err.error_response(),
+    Err(Error::InvalidInput(_)) => HttpResponse::NotFound().body("Terminal no encontrado"),
+    Err(_) => HttpResponse::InternalServerError().body("Error al revocar el terminal")
+  }
+}
+
+#[post("/pin")]
+pub async fn set_pin_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  request: web::Json<SetPinRequest>
+) -> HttpResponse {
+  let repo = SurrealStaffPinRepository::new(&db_connection, &auth_user.tenant());
+
+  match SetPinUseCase::new(repo).execute(&auth_user, &request.into_inner()).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::ValidationError(_) | Error::UnauthorizedAccess)) => err.error_response(),
+    Err(Error::UnauthorizedOperation) => HttpResponse::Forbidden().body("Solo el personal puede tener PIN"),
+    Err(_) => HttpResponse::InternalServerError().body("Error al guardar el PIN")
+  }
+}
+
+#[delete("/pin/{user_id}")]
+pub async fn remove_pin_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  user_id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealStaffPinRepository::new(&db_connection, &tenant);
+
+  match RemovePinUseCase::new(repo).execute(&auth_user, &user_id.into_inner()).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ Error::InvalidPermission(_)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al eliminar el PIN")
+  }
+}
@@ -13,24 +13,22 @@ use crate::application::use_cases::profile::{
   remove::RemoveProfileUseCase
 };
 
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::value_objects::tenant::TenantContext;
 use crate::infrastructure::repositories::surreal_profile_repository::SurrealProfileRepository;
 use crate::infrastructure::database::surreal_connection::DatabaseConnection;
-use crate::domain::services::token::TokenService;
-use crate::infrastructure::config_env::Config;
 
 #[post("/")]
 pub async fn create_profile_handler(
   db_connection: web::Data<DatabaseConnection>,
-  config: web::Data<Config>,
+  auth_user: AuthenticatedUser,
   request: web::Json<CreateProfileRequest>
 ) -> HttpResponse {
   
-  let repo = SurrealProfileRepository::new(&db_connection);
-  let token_service = TokenService::new(config.token_config.clone());
-  let token = "token";
+  let repo = SurrealProfileRepository::new(&db_connection, &auth_user.tenant());
 
-  match CreateProfileUseCase::new(repo, token_service)
-    .execute(token, &request.into_inner()).await {
+  match CreateProfileUseCase::new(repo)
+    .execute(&auth_user, &request.into_inner()).await {
       Ok(response) => HttpResponse::Ok().json(response),
       Err(_) => {
         //Todo: Log de errores
@@ -43,16 +41,14 @@ pub async fn create_profile_handler(
 #[put("/")]
 pub async fn update_profile_handler(
   db_connection: web::Data<DatabaseConnection>,
-  config: web::Data<Config>,
+  auth_user: AuthenticatedUser,
   request: web::Json<UpdateProfileRequest>,
 ) -> HttpResponse {
 
-  let repo = SurrealProfileRepository::new(&db_connection);
-  let token_service = TokenService::new(config.token_config.clone());
-  let token = "token";
+  let repo = SurrealProfileRepository::new(&db_connection, &auth_user.tenant());
 
-  match UpdateProfileUseCase::new(repo, token_service)
-    .execute(token,&request.into_inner()).await {
+  match UpdateProfileUseCase::new(repo)
+    .execute(&auth_user,&request.into_inner()).await {
       Ok(response) => HttpResponse::Ok().json(response),
       Err(_) => HttpResponse::InternalServerError().body("Error al actualizar perfil")
     }
@@ -61,14 +57,12 @@ pub async fn update_profile_handler(
 #[delete("/")]
 pub async fn delete_profile_handler(
   db_connection: web::Data<DatabaseConnection>,
-  config: web::Data<Config>,
+  auth_user: AuthenticatedUser,
 ) -> HttpResponse {
 
-  let repo = SurrealProfileRepository::new(&db_connection);
-  let token_service = TokenService::new(config.token_config.clone());
-  let token = "token";
+  let repo = SurrealProfileRepository::new(&db_connection, &auth_user.tenant());
 
-  match RemoveProfileUseCase::new(repo, token_service).execute(token).await {
+  match RemoveProfileUseCase::new(repo).execute(&auth_user).await {
     Ok(_) => HttpResponse::Ok().finish(),
     Err(_) => HttpResponse::InternalServerError().body("Error al eliminar perfil")
   }
@@ -77,13 +71,11 @@ pub async fn delete_profile_handler(
 #[get("/")]
 pub async fn get_profile_handler(
   db_connection: web::Data<DatabaseConnection>,
-  config: web::Data<Config>,
+  auth_user: AuthenticatedUser,
 ) -> HttpResponse {
-  let repo = SurrealProfileRepository::new(&db_connection);
-  let token_service = TokenService::new(config.token_config.clone());
-  let token = "token";
+  let repo = SurrealProfileRepository::new(&db_connection, &auth_user.tenant());
   
-  match GetProfileByUserUseCase::new(repo, token_service).execute(token).await {
+  match GetProfileByUserUseCase::new(repo).execute(&auth_user).await {
     Ok(profile) => HttpResponse::Ok().json(profile),
     Err(_) => HttpResponse::InternalServerError().body("Error al obtener perfil")
   }
@@ -92,9 +84,11 @@ pub async fn get_profile_handler(
 #[get("/{id}")]
 pub async fn get_profile_by_id_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  _auth_user: AuthenticatedUser,
   id: web::Path<String>
 ) -> HttpResponse {
-  let repo = SurrealProfileRepository::new(&db_connection);
+  let repo = SurrealProfileRepository::new(&db_connection, &tenant);
 
   match GetProfileByIdUseCase::new(repo).execute(&id.into_inner()).await {
     Ok(profile) => HttpResponse::Ok().json(profile),
@@ -0,0 +1,127 @@
+use actix_web::{delete, get, post, put, web, HttpResponse, ResponseError};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+
+use crate::application::dtos::restaurants::create_restaurant_request::CreateRestaurantRequest;
+use crate::application::dtos::subscriptions::change_plan_request::ChangePlanRequest;
+
+use crate::application::use_cases::restaurants::{
+  create::CreateRestaurantUseCase,
+  get_all::GetAllRestaurantsUseCase,
+  deprovision::DeprovisionRestaurantUseCase,
+  export::ExportRestaurantUseCase,
+};
+use crate::application::use_cases::subscriptions::{
+  start::StartSubscriptionUseCase,
+  change_plan::ChangePlanUseCase,
+};
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::services::tenant_database::TenantDatabaseProvisioner;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::infrastructure::repositories::{
+  surreal_restaurant_repository::SurrealRestaurantRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+  surreal_subscription_repository::SurrealSubscriptionRepository,
+  surreal_plan_repository::SurrealPlanRepository,
+};
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+#[post("/")]
+pub async fn create_restaurant_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  provisioner: web::Data<dyn TenantDatabaseProvisioner>,
+  request: web::Json<CreateRestaurantRequest>
+) -> HttpResponse {
+  let repo = SurrealRestaurantRepository::new(&db_connection);
+  // El alta la hace la plataforma y queda en su registro
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &auth_user.tenant());
+  let start_subscription = StartSubscriptionUseCase::new(
+    SurrealSubscriptionRepository::new(&db_connection),
+    SurrealPlanRepository::new(&db_connection),
+  );
+
+  match CreateRestaurantUseCase::new(repo, audit_repo, provisioner.into_inner(), start_subscription).execute(&auth_user, &client, &request.into_inner()).await {
+    Ok(restaurant) => HttpResponse::Created().json(restaurant),
+    Err(err @ (Error::UnauthorizedAccess | Error::ValidationError(_) | Error::RestaurantAlreadyExists(_) | Error::PlanNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al crear el restaurante")
+  }
+}
+
+#[get("/")]
+pub async fn get_all_restaurants_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let repo = SurrealRestaurantRepository::new(&db_connection);
+
+  match GetAllRestaurantsUseCase::new(repo).execute(&auth_user).await {
+    Ok(restaurants) => HttpResponse::Ok().json(restaurants),
+    Err(err @ Error::UnauthorizedAccess) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener los restaurantes")
+  }
+}
+
+#[get("/{id}/export")]
+pub async fn export_restaurant_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  provisioner: web::Data<dyn TenantDatabaseProvisioner>,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealRestaurantRepository::new(&db_connection);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &auth_user.tenant());
+
+  match ExportRestaurantUseCase::new(repo, audit_repo, provisioner.into_inner()).execute(&auth_user, &client, &id.into_inner()).await {
+    Ok((slug, dump)) => HttpResponse::Ok()
+      .content_type("application/sql")
+      .insert_header(ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![DispositionParam::Filename(format!("{}.surql", slug))],
+      })
+      .body(dump),
+    Err(err @ (Error::UnauthorizedAccess | Error::RestaurantNotFound | Error::TenantNotDedicated)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al exportar el restaurante")
+  }
+}
+
+#[delete("/{id}")]
+pub async fn deprovision_restaurant_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  provisioner: web::Data<dyn TenantDatabaseProvisioner>,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealRestaurantRepository::new(&db_connection);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &auth_user.tenant());
+
+  match DeprovisionRestaurantUseCase::new(repo, audit_repo, provisioner.into_inner()).execute(&auth_user, &client, &id.into_inner()).await {
+    Ok(_) => HttpResponse::NoContent().finish(),
+    Err(err @ (Error::UnauthorizedAccess | Error::RestaurantNotFound | Error::TenantNotDedicated)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al dar de baja el restaurante")
+  }
+}
+
+#[put("/{id}/subscription")]
+pub async fn change_subscription_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  id: web::Path<String>,
+  request: web::Json<ChangePlanRequest>
+) -> HttpResponse {
+  let repo = SurrealRestaurantRepository::new(&db_connection);
+  let subscription_repo = SurrealSubscriptionRepository::new(&db_connection);
+  let plan_repo = SurrealPlanRepository::new(&db_connection);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &auth_user.tenant());
+
+  match ChangePlanUseCase::new(repo, subscription_repo, plan_repo, audit_repo).execute(&auth_user, &client, &id.into_inner(), &request.into_inner()).await {
+    Ok(subscription) => HttpResponse::Ok().json(subscription),
+    Err(err @ (Error::UnauthorizedAccess | Error::RestaurantNotFound | Error::PlanNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al cambiar el plan del restaurante")
+  }
+}
\ No newline at end of file
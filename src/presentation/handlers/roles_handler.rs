@@ -1,8 +1,9 @@
-use actix_web::{post, get, put, delete, web, HttpResponse};
+use actix_web::{post, get, put, delete, web, HttpResponse, ResponseError};
 
 use crate::application::dtos::roles::{
   create_role_request::CreateRoleRequest,
   update_role_request::UpdateRoleRequest,
+  role_inheritance_request::RoleInheritanceRequest,
 }; 
 
 use crate::application::use_cases::roles::{
@@ -10,23 +11,41 @@ use crate::application::use_cases::roles::{
   update::UpdateRoleUseCase,
   get_all::GetAllRolesUseCase,
   get_by_id::GetRoleByIdUseCase,
+  get_templates::GetRoleTemplatesUseCase,
+  set_inheritance::SetRoleInheritanceUseCase,
   remove::RemoveRoleUseCase
 };
 
-use crate::infrastructure::repositories::surreal_role_repository::SurrealRoleRepository;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::repositories::{
+  surreal_role_repository::SurrealRoleRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+  surreal_restaurant_repository::SurrealRestaurantRepository,
+};
 use crate::infrastructure::database::surreal_connection::DatabaseConnection;
 
 #[post("/")]
 pub async fn create_role_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
   request: web::Json<CreateRoleRequest>
 ) -> HttpResponse {
   
-  let repo = SurrealRoleRepository::new(&db_connection);
+  let repo = SurrealRoleRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
   
-  match CreateRoleUseCase::new(repo)
-    .execute(&request.into_inner()).await {
+  match CreateRoleUseCase::new(repo, audit_repo)
+    .execute(&auth_user, &client, &request.into_inner()).await {
       Ok(response) => HttpResponse::Ok().json(response),
+      Err(err @ (
+        Error::ValidationError(_) | Error::InvalidInput(_) | Error::InvalidResource | Error::InvalidAction |
+        Error::InvalidPermission(_) | Error::RoleHierarchyViolation | Error::RoleAlreadyExists(_)
+      )) => err.error_response(),
       Err(_) => {
         //Todo: Log de errores
         //error!("Error en el registro: {:?}", err);
@@ -38,15 +57,20 @@ pub async fn create_role_handler(
 #[put("/{id}")]
 pub async fn update_role_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
   request: web::Json<UpdateRoleRequest>,
   id: web::Path<String>
 ) -> HttpResponse {
 
-  let repo = SurrealRoleRepository::new(&db_connection);
+  let repo = SurrealRoleRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
 
-  match UpdateRoleUseCase::new(repo)
-    .execute(&id.into_inner(),&request.into_inner()).await {
+  match UpdateRoleUseCase::new(repo, audit_repo)
+    .execute(&auth_user, &client, &id.into_inner(),&request.into_inner()).await {
       Ok(response) => HttpResponse::Ok().json(response),
+      Err(err @ (Error::ValidationError(_) | Error::RoleNotFound | Error::RoleHierarchyViolation | Error::RoleAlreadyExists(_))) => err.error_response(),
       Err(_) => HttpResponse::InternalServerError().body("Error al actualizar rol")
     }
 }
@@ -54,22 +78,28 @@ pub async fn update_role_handler(
 #[delete("/{id}")]
 pub async fn delete_role_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
   id: web::Path<String>
 ) -> HttpResponse {
 
-  let repo = SurrealRoleRepository::new(&db_connection);
+  let repo = SurrealRoleRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
 
-  match RemoveRoleUseCase::new(repo).execute(&id.into_inner()).await {
+  match RemoveRoleUseCase::new(repo, audit_repo).execute(&auth_user, &client, &id.into_inner()).await {
     Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::RoleNotFound | Error::RoleHierarchyViolation)) => err.error_response(),
     Err(_) => HttpResponse::InternalServerError().body("Error al eliminar rol")
   }
 }
 
 #[get("/")]
 pub async fn get_all_roles_handler(
-  db_connection: web::Data<DatabaseConnection>
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext
 ) -> HttpResponse {
-  let repo = SurrealRoleRepository::new(&db_connection);
+  let repo = SurrealRoleRepository::new(&db_connection, &tenant);
 
   print!("Llega a get_all_roles_handler");
 
@@ -79,12 +109,39 @@ pub async fn get_all_roles_handler(
   }
 }
 
+#[get("/templates")]
+pub async fn get_role_templates_handler() -> HttpResponse {
+  HttpResponse::Ok().json(GetRoleTemplatesUseCase.execute())
+}
+
+#[put("/inheritance")]
+pub async fn set_role_inheritance_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  request: web::Json<RoleInheritanceRequest>
+) -> HttpResponse {
+
+  // El restaurante es de la plataforma; la auditoría, del tenant
+  let restaurant_repo = SurrealRestaurantRepository::new(&db_connection);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match SetRoleInheritanceUseCase::new(restaurant_repo, audit_repo)
+    .execute(&auth_user, &client, request.enabled).await {
+      Ok(restaurant) => HttpResponse::Ok().json(restaurant),
+      Err(err @ (Error::InvalidPermission(_) | Error::InvalidOperation | Error::RestaurantNotFound)) => err.error_response(),
+      Err(_) => HttpResponse::InternalServerError().body("Error al cambiar la herencia de roles")
+    }
+}
+
 #[get("/{id}")]
 pub async fn get_role_by_id_handler(
   db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
   id: web::Path<String>
 ) -> HttpResponse {
-  let repo = SurrealRoleRepository::new(&db_connection);
+  let repo = SurrealRoleRepository::new(&db_connection, &tenant);
 
   match GetRoleByIdUseCase::new(repo).execute(&id.into_inner()).await {
     Ok(role) => HttpResponse::Ok().json(role),
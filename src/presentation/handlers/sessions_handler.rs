@@ -0,0 +1,87 @@
+use actix_web::{delete, get, web, HttpResponse, ResponseError};
+
+use crate::application::use_cases::sessions::{
+  get_mine::GetMySessionsUseCase,
+  get_by_user::GetUserSessionsUseCase,
+  revoke::RevokeSessionUseCase,
+  revoke_all::RevokeAllSessionsUseCase,
+};
+
+use crate::infrastructure::repositories::{
+  surreal_user_repository::SurrealUserRepository,
+  surreal_token_repository::SurrealTokenRepository,
+  cached_revoked_token_repository::CachedRevokedTokenRepository,
+};
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::domain::services::token::TokenService;
+use crate::infrastructure::config_env::Config;
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::revocation_cache::RevocationCache;
+
+#[get("/me/sessions")]
+pub async fn get_my_sessions_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let repo = SurrealTokenRepository::new(&db_connection);
+
+  match GetMySessionsUseCase::new(repo).execute(&auth_user).await {
+    Ok(sessions) => HttpResponse::Ok().json(sessions),
+    Err(err @ Error::UnauthorizedAccess) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener las sesiones")
+  }
+}
+
+#[delete("/me/sessions/{id}")]
+pub async fn revoke_session_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealTokenRepository::new(&db_connection);
+
+  match RevokeSessionUseCase::new(repo).execute(&auth_user, &id.into_inner()).await {
+    Ok(_) => HttpResponse::NoContent().finish(),
+    Err(err @ (Error::UnauthorizedAccess | Error::SessionNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al cerrar la sesión")
+  }
+}
+
+// Cerrar sesión en todos los dispositivos
+#[delete("/me/sessions")]
+pub async fn revoke_all_sessions_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  revocation_cache: web::Data<RevocationCache>,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let repo = SurrealTokenRepository::new(&db_connection);
+  let revoked_token_repo = CachedRevokedTokenRepository::new(&db_connection, revocation_cache.into_inner());
+  let token_service = TokenService::new(config.token_config.clone());
+
+  match RevokeAllSessionsUseCase::new(repo, revoked_token_repo, token_service).execute(&auth_user).await {
+    Ok(_) => HttpResponse::NoContent().finish(),
+    Err(err @ Error::UnauthorizedAccess) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al cerrar las sesiones")
+  }
+}
+
+#[get("/{id}/sessions")]
+pub async fn get_user_sessions_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let token_repo = SurrealTokenRepository::new(&db_connection);
+
+  match GetUserSessionsUseCase::new(repo, token_repo).execute(&auth_user, &id.into_inner()).await {
+    Ok(sessions) => HttpResponse::Ok().json(sessions),
+    Err(err @ (Error::InvalidPermission(_) | Error::UserNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener las sesiones del usuario")
+  }
+}
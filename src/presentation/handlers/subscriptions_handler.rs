@@ -0,0 +1,42 @@
+use actix_web::{get, web, HttpResponse, ResponseError};
+
+use crate::application::use_cases::subscriptions::{
+  get_plans::GetPlansUseCase,
+  get_current::GetSubscriptionUseCase,
+};
+
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::infrastructure::repositories::{
+  surreal_subscription_repository::SurrealSubscriptionRepository,
+  surreal_plan_repository::SurrealPlanRepository,
+};
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+
+// Catálogo público, para la página de precios y el alta
+#[get("/")]
+pub async fn get_plans_handler(
+  db_connection: web::Data<DatabaseConnection>,
+) -> HttpResponse {
+  let repo = SurrealPlanRepository::new(&db_connection);
+
+  match GetPlansUseCase::new(repo).execute().await {
+    Ok(plans) => HttpResponse::Ok().json(plans),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener los planes")
+  }
+}
+
+#[get("/")]
+pub async fn get_subscription_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let subscription_repo = SurrealSubscriptionRepository::new(&db_connection);
+  let plan_repo = SurrealPlanRepository::new(&db_connection);
+
+  match GetSubscriptionUseCase::new(subscription_repo, plan_repo).execute(&auth_user).await {
+    Ok(subscription) => HttpResponse::Ok().json(subscription),
+    Err(err @ (Error::InvalidPermission(_) | Error::SubscriptionRequired | Error::PlanNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener la suscripción")
+  }
+}
@@ -0,0 +1,67 @@
+use actix_web::{post, web, HttpResponse, ResponseError};
+
+use crate::application::dtos::restaurants::signup_request::SignupRequest;
+use crate::application::use_cases::auth::send_verification::SendVerificationEmailUseCase;
+use crate::application::use_cases::restaurants::signup::SignupRestaurantUseCase;
+use crate::application::use_cases::subscriptions::start::StartSubscriptionUseCase;
+
+use crate::domain::error::Error;
+use crate::domain::services::mailer::Mailer;
+use crate::domain::services::rate_limit::RateLimitScope;
+use crate::domain::services::tenant_onboarding::TenantOnboarding;
+use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::client_info::ClientInfo;
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::config_env::Config;
+use crate::infrastructure::repositories::{
+  surreal_restaurant_repository::SurrealRestaurantRepository,
+  surreal_token_repository::SurrealTokenRepository,
+  surreal_one_time_token_repository::SurrealOneTimeTokenRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+  surreal_subscription_repository::SurrealSubscriptionRepository,
+  surreal_plan_repository::SurrealPlanRepository,
+};
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::presentation::middlewares::rate_limiter::RateLimiter;
+
+#[post("/signup", wrap = "RateLimiter::new(RateLimitScope::Register)")]
+pub async fn signup_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  mailer: web::Data<dyn Mailer>,
+  onboarding: web::Data<dyn TenantOnboarding>,
+  client: ClientInfo,
+  request: web::Json<SignupRequest>
+) -> HttpResponse {
+  let token_service = TokenService::new(config.token_config.clone());
+  let repo = SurrealRestaurantRepository::new(&db_connection);
+  let token_repo = SurrealTokenRepository::new(&db_connection);
+  // Igual que el alta desde la plataforma, queda en su registro
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &TenantContext::platform());
+  let send_verification = SendVerificationEmailUseCase::new(
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    token_service.clone(),
+    mailer.into_inner(),
+    config.mailer.app_url.clone()
+  );
+
+  match SignupRestaurantUseCase::new(
+    repo,
+    token_repo,
+    SurrealOneTimeTokenRepository::new(&db_connection),
+    audit_repo,
+    onboarding.into_inner(),
+    token_service,
+    config.password_policy.clone(),
+    config.mfa_policy.clone(),
+    send_verification,
+    StartSubscriptionUseCase::new(
+      SurrealSubscriptionRepository::new(&db_connection),
+      SurrealPlanRepository::new(&db_connection),
+    ),
+  ).execute(&request.into_inner(), &client).await {
+    Ok(response) => HttpResponse::Created().json(response),
+    Err(err @ (Error::ValidationError(_) | Error::PasswordPolicy(_) | Error::RestaurantAlreadyExists(_) | Error::PlanNotFound)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al registrar el restaurante")
+  }
+}
@@ -1,84 +1,266 @@
-use actix_web::{post, get, web, HttpResponse};
+use actix_web::{post, get, put, delete, web, HttpResponse, ResponseError};
 
 use crate::application::dtos::users::{
   change_password_request::ChangePasswordRequest,
-  verification_status_user_request::VerificationStatusUserRequest
+  verification_status_user_request::VerificationStatusUserRequest,
+  switch_location_request::SwitchLocationRequest,
 };
+use crate::application::dtos::roles::assign_role_request::{AssignRoleRequest, RoleScopeQuery};
 
 use crate::application::use_cases::users::{
   change_password::ChangePasswordUseCase,
   verification_user::VerificationUserUseCase,
   disable_user::DisableUserUseCase,
   get_all::GetAllUsersUseCase,
+  unlock_user::UnlockUserUseCase,
+  impersonate::ImpersonateUserUseCase,
+  stop_impersonation::StopImpersonationUseCase,
+  switch_location::SwitchLocationUseCase,
+};
+use crate::application::use_cases::roles::{
+  assign::AssignRoleUseCase,
+  unassign::UnassignRoleUseCase,
+  get_tree::GetRoleTreeUseCase,
 };
 
-use crate::infrastructure::repositories::surreal_user_repository::SurrealUserRepository;
-use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::domain::entities::authenticated_user::AuthenticatedUser;
+use crate::domain::error::Error;
+use crate::domain::value_objects::tenant::TenantContext;
 use crate::domain::services::token::TokenService;
+use crate::domain::value_objects::client_info::ClientInfo;
 use crate::infrastructure::config_env::Config;
+use crate::infrastructure::repositories::{
+  surreal_user_repository::SurrealUserRepository,
+  surreal_token_repository::SurrealTokenRepository,
+  cached_revoked_token_repository::CachedRevokedTokenRepository,
+  surreal_audit_repository::SurrealAuditRepository,
+  surreal_role_repository::SurrealRoleRepository,
+  surreal_location_repository::SurrealLocationRepository,
+  surreal_permission_repository::SurrealPermissionRepository,
+  surreal_restaurant_repository::SurrealRestaurantRepository,
+};
+use crate::infrastructure::database::surreal_connection::DatabaseConnection;
+use crate::infrastructure::revocation_cache::RevocationCache;
 
 #[get("/")]
 pub async fn get_all_users_handler(
   db_connection: web::Data<DatabaseConnection>,
-  config: web::Data<Config>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
 ) -> HttpResponse {
-  let repo = SurrealUserRepository::new(&db_connection);
-  let token_service = TokenService::new(config.token_config.clone());
-
-  let token = "token";
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
   
-  match GetAllUsersUseCase::new(repo, token_service).execute(token).await {
+  match GetAllUsersUseCase::new(repo).execute(&auth_user).await {
     Ok(users) => HttpResponse::Ok().json(users),
+    Err(err @ Error::InvalidPermission(_)) => err.error_response(),
     Err(_) => HttpResponse::InternalServerError().body("Error al obtener usuarios")
   }
 }
 
-#[post("/verification")]
+#[post("/{id}/verification")]
 pub async fn verification_user_handler(
   db_connection: web::Data<DatabaseConnection>,
-  config: web::Data<Config>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  id: web::Path<String>,
   request: web::Json<VerificationStatusUserRequest>
 ) -> HttpResponse {
-  let repo = SurrealUserRepository::new(&db_connection);
-  let token_service = TokenService::new(config.token_config.clone());
-
-  let token = "token";
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let role_repo = SurrealRoleRepository::new(&db_connection, &tenant);
   
-  match VerificationUserUseCase::new(repo, token_service).execute(token, &request.into_inner()).await {
+  match VerificationUserUseCase::new(repo, role_repo).execute(&auth_user, &id.into_inner(), &request.into_inner()).await {
     Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::InvalidPermission(_) | Error::UserNotFound | Error::RoleHierarchyViolation)) => err.error_response(),
     Err(_) => HttpResponse::InternalServerError().body("Error al verificar usuario")
   }
 }
 
 #[post("/change-password")]
 pub async fn change_password_handler(
-  db_connection: web::Data<DatabaseConnection>,
   config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  revocation_cache: web::Data<RevocationCache>,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
   request: web::Json<ChangePasswordRequest>
 ) -> HttpResponse {
-  let repo = SurrealUserRepository::new(&db_connection);
+  let repo = SurrealUserRepository::new(&db_connection, &auth_user.tenant());
+  let token_repo = SurrealTokenRepository::new(&db_connection);
+  let revoked_token_repo = CachedRevokedTokenRepository::new(&db_connection, revocation_cache.into_inner());
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &auth_user.tenant());
   let token_service = TokenService::new(config.token_config.clone());
 
-  let token = "token";
-
-  match ChangePasswordUseCase::new(repo, token_service).execute(token, &request.into_inner()).await {
+  match ChangePasswordUseCase::new(repo, token_repo, revoked_token_repo, audit_repo, token_service, config.password_policy.clone())
+    .execute(&auth_user, &client, &request.into_inner()).await {
     Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::UnauthorizedAccess | Error::PasswordPolicy(_))) => err.error_response(),
     Err(_) => HttpResponse::InternalServerError().body("Error al cambiar la contraseña")
   }
 }
 
 #[post("/disable")]
 pub async fn disable_user_handler(
-  db_connection: web::Data<DatabaseConnection>,
   config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  revocation_cache: web::Data<RevocationCache>,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
 ) -> HttpResponse {
-  let repo = SurrealUserRepository::new(&db_connection);
+  let repo = SurrealUserRepository::new(&db_connection, &auth_user.tenant());
+  let token_repo = SurrealTokenRepository::new(&db_connection);
+  let revoked_token_repo = CachedRevokedTokenRepository::new(&db_connection, revocation_cache.into_inner());
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &auth_user.tenant());
   let token_service = TokenService::new(config.token_config.clone());
-
-  let token = "token";
   
-  match DisableUserUseCase::new(repo, token_service).execute(token).await {
+  match DisableUserUseCase::new(repo, token_repo, revoked_token_repo, audit_repo, token_service).execute(&auth_user, &client).await {
     Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ Error::UnauthorizedAccess) => err.error_response(),
     Err(_) => HttpResponse::InternalServerError().body("Error al deshabilitar usuario")
   }
+}
+
+#[post("/{id}/unlock")]
+pub async fn unlock_user_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let role_repo = SurrealRoleRepository::new(&db_connection, &tenant);
+
+  match UnlockUserUseCase::new(repo, role_repo).execute(&auth_user, &id.into_inner()).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::InvalidPermission(_) | Error::UserNotFound | Error::RoleHierarchyViolation)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al desbloquear usuario")
+  }
+}
+
+#[post("/{id}/impersonate")]
+pub async fn impersonate_user_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  id: web::Path<String>
+) -> HttpResponse {
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+  let token_service = TokenService::new(config.token_config.clone());
+
+  match ImpersonateUserUseCase::new(repo, audit_repo, token_service).execute(&auth_user, &client, &id.into_inner()).await {
+    Ok(response) => HttpResponse::Ok().json(response),
+    Err(err @ (Error::UnauthorizedAccess | Error::UserNotFound | Error::InvalidInput(_))) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al suplantar al usuario")
+  }
+}
+
+#[post("/impersonation/stop")]
+pub async fn stop_impersonation_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  revocation_cache: web::Data<RevocationCache>,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+) -> HttpResponse {
+  let revoked_token_repo = CachedRevokedTokenRepository::new(&db_connection, revocation_cache.into_inner());
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &auth_user.tenant());
+  let token_service = TokenService::new(config.token_config.clone());
+
+  match StopImpersonationUseCase::new(revoked_token_repo, audit_repo, token_service).execute(&auth_user, &client).await {
+    Ok(_) => HttpResponse::Ok().finish(),
+    Err(err @ (Error::InvalidInput(_) | Error::InvalidToken)) => err.error_response(),
+    Err(_) => HttpResponse::InternalServerError().body("Error al terminar la suplantación")
+  }
+}
+
+// Cambia la sucursal de la sesión del token con el que se llama
+#[put("/me/location")]
+pub async fn switch_location_handler(
+  config: web::Data<Config>,
+  db_connection: web::Data<DatabaseConnection>,
+  revocation_cache: web::Data<RevocationCache>,
+  auth_user: AuthenticatedUser,
+  request: web::Json<SwitchLocationRequest>
+) -> HttpResponse {
+  let tenant = auth_user.tenant();
+  let user_repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let location_repo = SurrealLocationRepository::new(&db_connection, &tenant);
+  let token_repo = SurrealTokenRepository::new(&db_connection);
+  let revoked_token_repo = CachedRevokedTokenRepository::new(&db_connection, revocation_cache.into_inner());
+  let token_service = TokenService::new(config.token_config.clone());
+
+  match SwitchLocationUseCase::new(user_repo, location_repo, token_repo, revoked_token_repo, token_service)
+    .execute(&auth_user, &request.into_inner()).await {
+      Ok(response) => HttpResponse::Ok().json(response),
+      Err(err @ (Error::UnauthorizedAccess | Error::LocationAccessDenied | Error::InvalidToken)) => err.error_response(),
+      Err(_) => HttpResponse::InternalServerError().body("Error al cambiar de sucursal")
+    }
+}
+
+// Roles del usuario y los que gestiona por debajo de su nivel
+#[get("/me/roles")]
+pub async fn get_my_role_tree_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  auth_user: AuthenticatedUser,
+) -> HttpResponse {
+  let role_repo = SurrealRoleRepository::new(&db_connection, &auth_user.tenant());
+  let permission_repo = SurrealPermissionRepository::new(&db_connection, &auth_user.tenant());
+  let restaurant_repo = SurrealRestaurantRepository::new(&db_connection);
+
+  match GetRoleTreeUseCase::new(role_repo, permission_repo, restaurant_repo).execute(&auth_user).await {
+    Ok(tree) => HttpResponse::Ok().json(tree),
+    Err(_) => HttpResponse::InternalServerError().body("Error al obtener el árbol de roles")
+  }
+}
+
+#[post("/{id}/roles")]
+pub async fn assign_role_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  id: web::Path<String>,
+  request: web::Json<AssignRoleRequest>
+) -> HttpResponse {
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let role_repo = SurrealRoleRepository::new(&db_connection, &tenant);
+  let location_repo = SurrealLocationRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match AssignRoleUseCase::new(repo, role_repo, location_repo, audit_repo)
+    .execute(&auth_user, &client, &id.into_inner(), &request.into_inner()).await {
+      Ok(_) => HttpResponse::Ok().finish(),
+      Err(err @ (
+        Error::InvalidPermission(_) | Error::LocationMismatch | Error::LocationNotFound |
+        Error::RoleNotFound | Error::UserNotFound | Error::ValidationError(_) | Error::RoleHierarchyViolation
+      )) => err.error_response(),
+      Err(_) => HttpResponse::InternalServerError().body("Error al asignar el rol")
+    }
+}
+
+#[delete("/{id}/roles/{role_id}")]
+pub async fn unassign_role_handler(
+  db_connection: web::Data<DatabaseConnection>,
+  tenant: TenantContext,
+  auth_user: AuthenticatedUser,
+  client: ClientInfo,
+  path: web::Path<(String, String)>,
+  query: web::Query<RoleScopeQuery>
+) -> HttpResponse {
+  let (user_id, role_id) = path.into_inner();
+  let repo = SurrealUserRepository::new(&db_connection, &tenant);
+  let role_repo = SurrealRoleRepository::new(&db_connection, &tenant);
+  let audit_repo = SurrealAuditRepository::new(&db_connection, &tenant);
+
+  match UnassignRoleUseCase::new(repo, role_repo, audit_repo)
+    .execute(&auth_user, &client, &user_id, &role_id, query.location_id.as_deref()).await {
+      Ok(_) => HttpResponse::Ok().finish(),
+      Err(err @ (
+        Error::InvalidPermission(_) | Error::LocationMismatch | Error::RoleNotFound | Error::UserNotFound |
+        Error::RoleHierarchyViolation
+      )) => err.error_response(),
+      Err(_) => HttpResponse::InternalServerError().body("Error al quitar el rol")
+    }
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use actix_web::{get, web, HttpResponse};
+
+use crate::domain::services::token::TokenService;
+use crate::infrastructure::config_env::Config;
+
+// Público: los demás servicios descargan aquí las claves para validar los access tokens
+#[get("/jwks.json")]
+pub async fn jwks_handler(config: web::Data<Config>) -> HttpResponse {
+  let token_service = TokenService::new(config.token_config.clone());
+
+  HttpResponse::Ok()
+    .insert_header(("Cache-Control", "public, max-age=300"))
+    .json(token_service.jwks())
+}
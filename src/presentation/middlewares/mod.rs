@@ -0,0 +1,3 @@
+pub mod require_permission;
+pub mod rate_limiter;
+pub mod require_feature;
\ No newline at end of file
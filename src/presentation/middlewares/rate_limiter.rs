@@ -0,0 +1,157 @@
+use std::rc::Rc;
+use std::pin::Pin;
+use std::future::{ready, Ready};
+
+use actix_web::dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::error::PayloadError;
+use actix_web::web::{self, Bytes};
+use actix_web::FromRequest;
+use futures_util::future::LocalBoxFuture;
+use futures_util::Stream;
+use ring::digest::{digest, SHA256};
+use serde::Deserialize;
+
+use crate::domain::error::Error;
+use crate::domain::services::rate_limit::{RateLimit, RateLimitDecision, RateLimitScope, RateLimitStore};
+use crate::domain::value_objects::tenant::TenantContext;
+use crate::infrastructure::config_env::Config;
+use crate::presentation::extractors::principal_extractor::API_KEY_HEADER;
+use crate::presentation::extractors::client_info_extractor::client_ip;
+
+// Limita peticiones con los límites que la configuración asigna al grupo, por IP (ver `client_ip`), por email
+// (campo `email` del cuerpo JSON, dentro del tenant de la petición) y por `X-Api-Key`:
+// `#[post("/login", wrap = "RateLimiter::new(RateLimitScope::Login)")]` o `web::scope(..).wrap(..)`
+#[derive(Clone)]
+pub struct RateLimiter {
+  scope: RateLimitScope,
+}
+
+impl RateLimiter {
+  pub fn new(scope: RateLimitScope) -> Self {
+    Self { scope }
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimiter
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = actix_web::Error;
+  type Transform = RateLimiterMiddleware<S>;
+  type InitError = ();
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    ready(Ok(RateLimiterMiddleware {
+      service: Rc::new(service),
+      scope: self.scope,
+    }))
+  }
+}
+
+pub struct RateLimiterMiddleware<S> {
+  service: Rc<S>,
+  scope: RateLimitScope,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimiterMiddleware<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = actix_web::Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, mut req: ServiceRequest) -> Self::Future {
+    let service = self.service.clone();
+    let scope = self.scope;
+
+    Box::pin(async move {
+      let config = req.app_data::<web::Data<Config>>().cloned();
+      let store = req.app_data::<web::Data<dyn RateLimitStore>>().cloned();
+
+      let (Some(config), Some(store)) = (config, store) else {
+        log::warn!("Rate limiter for {} is not configured, letting the request through", scope.as_str());
+        return service.call(req).await;
+      };
+
+      let rules = config.rate_limits.rules(scope).clone();
+      let mut checks: Vec<(String, RateLimit)> = Vec::new();
+
+      if let Some(limit) = rules.per_ip {
+        if let Some(ip) = client_ip(req.request()) {
+          checks.push((format!("{}:ip:{}", scope.as_str(), ip), limit));
+        }
+      }
+
+      if let Some(limit) = rules.per_api_key {
+        if let Some(key) = req.headers().get(API_KEY_HEADER).and_then(|value| value.to_str().ok()) {
+          checks.push((format!("{}:api_key:{}", scope.as_str(), fingerprint(key.trim())), limit));
+        }
+      }
+
+      if let Some(limit) = rules.per_email {
+        if let Some(email) = email_from_body(&mut req).await? {
+          // El mismo email puede existir en varios restaurantes: cada uno lleva su cuenta
+          let tenant = TenantContext::extract(req.request()).await?
+            .record()
+            .map_or_else(|| "platform".to_string(), |restaurant| restaurant.id.to_string());
+
+          checks.push((format!("{}:email:{}:{}", scope.as_str(), tenant, email), limit));
+        }
+      }
+
+      for (key, limit) in checks {
+        match store.acquire(&key, &limit).await {
+          Ok(RateLimitDecision::Allowed) => {}
+          Ok(RateLimitDecision::Limited { retry_after }) => {
+            log::warn!("Rate limit exceeded for {}", key);
+            // Redondeo hacia arriba: reintentar antes de tiempo volvería a fallar
+            return Err(Error::TooManyRequests(retry_after.as_secs_f64().ceil() as u64).into());
+          }
+          // Si el almacén no responde se deja pasar: mejor sin límite que sin servicio
+          Err(err) => log::error!("Rate limit store failed for {}: {}", key, err),
+        }
+      }
+
+      service.call(req).await
+    })
+  }
+}
+
+#[derive(Deserialize)]
+struct EmailField {
+  email: Option<String>,
+}
+
+// Lee el cuerpo para sacar el email y lo vuelve a dejar en la petición para el handler
+async fn email_from_body(req: &mut ServiceRequest) -> Result<Option<String>, actix_web::Error> {
+  let body = req.extract::<Bytes>().await?;
+
+  let email = serde_json::from_slice::<EmailField>(&body)
+    .ok()
+    .and_then(|field| field.email)
+    .map(|email| email.trim().to_lowercase())
+    .filter(|email| !email.is_empty());
+
+  let stream: Pin<Box<dyn Stream<Item = Result<Bytes, PayloadError>>>> =
+    Box::pin(futures_util::stream::once(async move { Ok(body) }));
+  req.set_payload(Payload::from(stream));
+
+  Ok(email)
+}
+
+// La clave en claro no se usa como nombre del bucket: un almacén compartido la expondría
+fn fingerprint(key: &str) -> String {
+  digest(&SHA256, key.as_bytes())
+    .as_ref()
+    .iter()
+    .take(16)
+    .map(|byte| format!("{:02x}", byte))
+    .collect()
+}
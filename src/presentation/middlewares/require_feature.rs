@@ -0,0 +1,69 @@
+use std::rc::Rc;
+use std::future::{ready, Ready};
+
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use futures_util::future::LocalBoxFuture;
+
+use crate::domain::entities::entitlements::Entitlements;
+use crate::domain::entities::plan::Feature;
+
+// Exige que el plan del restaurante incluya un módulo:
+// `web::scope("/api/v1/pos").wrap(RequireFeature::new(Feature::Pos))`.
+// Responde 402 si la suscripción no está al día y 403 si el plan no incluye el módulo.
+#[derive(Clone)]
+pub struct RequireFeature {
+  feature: Feature,
+}
+
+impl RequireFeature {
+  pub fn new(feature: Feature) -> Self {
+    Self { feature }
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequireFeature
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = actix_web::Error;
+  type Transform = RequireFeatureMiddleware<S>;
+  type InitError = ();
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    ready(Ok(RequireFeatureMiddleware {
+      service: Rc::new(service),
+      feature: self.feature,
+    }))
+  }
+}
+
+pub struct RequireFeatureMiddleware<S> {
+  service: Rc<S>,
+  feature: Feature,
+}
+
+impl<S, B> Service<ServiceRequest> for RequireFeatureMiddleware<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = actix_web::Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, mut req: ServiceRequest) -> Self::Future {
+    let service = self.service.clone();
+    let feature = self.feature;
+
+    Box::pin(async move {
+      req.extract::<Entitlements>().await?.require_feature(feature)?;
+
+      service.call(req).await
+    })
+  }
+}
@@ -0,0 +1,75 @@
+use std::rc::Rc;
+use std::future::{ready, Ready};
+
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::HttpMessage;
+use futures_util::future::LocalBoxFuture;
+
+use crate::domain::entities::principal::Principal;
+use crate::domain::entities::permission::{Action, Resource};
+use crate::domain::services::permission_guard::RequiredPermission;
+
+// Exige un permiso sobre un scope o una ruta, tanto a usuarios (bearer) como a equipos (`X-Api-Key`):
+// `web::scope("/api/v1/roles").wrap(RequirePermission::new(Resource::Users, Action::Manage))`
+#[derive(Clone)]
+pub struct RequirePermission {
+  required: RequiredPermission,
+}
+
+impl RequirePermission {
+  pub fn new(resource: Resource, action: Action) -> Self {
+    Self { required: RequiredPermission::new(resource, action) }
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequirePermission
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = actix_web::Error;
+  type Transform = RequirePermissionMiddleware<S>;
+  type InitError = ();
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    ready(Ok(RequirePermissionMiddleware {
+      service: Rc::new(service),
+      required: self.required.clone(),
+    }))
+  }
+}
+
+pub struct RequirePermissionMiddleware<S> {
+  service: Rc<S>,
+  required: RequiredPermission,
+}
+
+impl<S, B> Service<ServiceRequest> for RequirePermissionMiddleware<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = actix_web::Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, mut req: ServiceRequest) -> Self::Future {
+    let service = self.service.clone();
+    let required = self.required.clone();
+
+    Box::pin(async move {
+      let principal = req.extract::<Principal>().await?;
+      principal.require(&required)?;
+
+      if let Principal::User(auth_user) = principal {
+        req.extensions_mut().insert(auth_user);
+      }
+
+      service.call(req).await
+    })
+  }
+}
@@ -1,2 +1,4 @@
 pub mod handlers;
-pub mod routes;
\ No newline at end of file
+pub mod routes;
+pub mod extractors;
+pub mod middlewares;
\ No newline at end of file
@@ -0,0 +1,17 @@
+use actix_web::web;
+use crate::presentation::handlers::api_keys_handler::{
+  create_api_key_handler, get_all_api_keys_handler, revoke_api_key_handler
+};
+use crate::domain::entities::plan::Feature;
+use crate::presentation::middlewares::require_feature::RequireFeature;
+
+// La gestión de claves la hace un administrador con sesión de usuario, no otra clave
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/api/v1/api-keys")
+      .wrap(RequireFeature::new(Feature::ApiKeys))
+      .service(create_api_key_handler)
+      .service(get_all_api_keys_handler)
+      .service(revoke_api_key_handler)
+  );
+}
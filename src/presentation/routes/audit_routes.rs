@@ -0,0 +1,10 @@
+use actix_web::web;
+use crate::presentation::handlers::audit_handler::get_audit_events_handler;
+
+// Solo lectura y solo para super admins; los eventos se escriben desde los casos de uso
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/api/v1/audit")
+      .service(get_audit_events_handler)
+  );
+}
\ No newline at end of file
@@ -1,12 +1,39 @@
 use actix_web::web;
-use crate::presentation::handlers::auth_handler::{register_handler, login_handler, logout_handler};
+use crate::presentation::handlers::auth_handler::{
+  register_handler, login_handler, pin_login_handler, refresh_handler, logout_handler, verify_email_handler, resend_verification_handler,
+  forgot_password_handler, reset_password_handler
+};
+use crate::presentation::handlers::oidc_handler::{oidc_authorize_handler, oidc_callback_handler};
+use crate::presentation::handlers::mfa_handler::{
+  enroll_mfa_handler, enroll_mfa_challenge_handler, confirm_mfa_handler, verify_mfa_handler,
+  regenerate_recovery_codes_handler, disable_mfa_handler, set_mfa_policy_handler
+};
 
-//TODO: Agregar middleware para validar token solo en ruta para logout
+// El logout requiere un bearer token valido, lo resuelve el extractor `AuthenticatedUser`
 pub fn routes(config: &mut web::ServiceConfig) {
   config.service(
     web::scope("/api/v1/auth")
       .service(register_handler)
       .service(login_handler)
+      .service(pin_login_handler)
+      .service(refresh_handler)
+      .service(verify_email_handler)
+      .service(resend_verification_handler)
+      .service(forgot_password_handler)
+      .service(reset_password_handler)
       .service(logout_handler)
+      .service(oidc_authorize_handler)
+      .service(oidc_callback_handler)
+      .service(
+        // `challenge/*` y `verify` usan el token "mfa pending" del login, el resto un bearer token
+        web::scope("/mfa")
+          .service(enroll_mfa_handler)
+          .service(enroll_mfa_challenge_handler)
+          .service(confirm_mfa_handler)
+          .service(verify_mfa_handler)
+          .service(regenerate_recovery_codes_handler)
+          .service(disable_mfa_handler)
+          .service(set_mfa_policy_handler)
+      )
   );
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use actix_web::web;
+use crate::presentation::handlers::locations_handler::{
+  create_location_handler, get_all_locations_handler, get_my_locations_handler, get_location_handler,
+  update_location_handler, delete_location_handler, assign_location_user_handler, unassign_location_user_handler
+};
+
+// Sucursales del restaurante; los permisos se comprueban en cada caso de uso porque el encargado
+// de una sucursal puede gestionar la suya sin tener permisos en todo el restaurante
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/api/v1/locations")
+      .service(create_location_handler)
+      .service(get_all_locations_handler)
+      // `/mine` antes que `/{id}` para que no se tome como id
+      .service(get_my_locations_handler)
+      .service(get_location_handler)
+      .service(update_location_handler)
+      .service(delete_location_handler)
+      .service(assign_location_user_handler)
+      .service(unassign_location_user_handler)
+  );
+}
@@ -0,0 +1,15 @@
+use actix_web::web;
+use crate::presentation::handlers::mock_oidc_handler::{
+  mock_oidc_discovery_handler, mock_oidc_jwks_handler, mock_oidc_authorize_handler, mock_oidc_token_handler
+};
+
+// Solo se monta con OIDC_MOCK_ISSUER=true
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/mock-oidc")
+      .service(mock_oidc_discovery_handler)
+      .service(mock_oidc_jwks_handler)
+      .service(mock_oidc_authorize_handler)
+      .service(mock_oidc_token_handler)
+  );
+}
@@ -3,4 +3,13 @@ pub mod auth_routes;
 pub mod role_routes;
 pub mod permission_routes;
 pub mod profile_routes;
-pub mod users_routes;
\ No newline at end of file
+pub mod users_routes;
+pub mod pos_routes;
+pub mod api_keys_routes;
+pub mod well_known_routes;
+pub mod audit_routes;
+pub mod mock_oidc_routes;
+pub mod restaurants_routes;
+pub mod locations_routes;
+pub mod tenants_routes;
+pub mod subscriptions_routes;
\ No newline at end of file
@@ -1,12 +1,20 @@
 use actix_web::web;
+
+use crate::domain::entities::permission::{Action, Resource};
+use crate::presentation::middlewares::require_permission::RequirePermission;
+use crate::presentation::middlewares::rate_limiter::RateLimiter;
+use crate::domain::services::rate_limit::RateLimitScope;
 use crate::presentation::handlers::permissions_handler::{
   create_permission_handler, get_all_permissions_handler, get_permission_by_id_handler, update_permission_handler, delete_permission_handler
 };
 
-//TODO: Agregar middleware para validar token estas rutas seran disponibles solo para usuarios con el rol de super admin
+// Solo usuarios con el permiso users:manage (rol super admin)
 pub fn routes(config: &mut web::ServiceConfig) {
   config.service(
     web::scope("/api/v1/permissions")
+      .wrap(RequirePermission::new(Resource::Users, Action::Manage))
+      // Por fuera del permiso: cuenta también las peticiones con claves rechazadas
+      .wrap(RateLimiter::new(RateLimitScope::ApiKeys))
       .service(create_permission_handler)
       .service(get_all_permissions_handler)
       .service(get_permission_by_id_handler)
@@ -0,0 +1,19 @@
+use actix_web::web;
+use crate::presentation::handlers::pos_handler::{
+  register_device_handler, get_devices_handler, revoke_device_handler, set_pin_handler, remove_pin_handler
+};
+use crate::domain::entities::plan::Feature;
+use crate::presentation::middlewares::require_feature::RequireFeature;
+
+// Administración de terminales y PINs, solo en los planes con POS; el login por PIN vive en /api/v1/auth/pin-login
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/api/v1/pos")
+      .wrap(RequireFeature::new(Feature::Pos))
+      .service(register_device_handler)
+      .service(get_devices_handler)
+      .service(revoke_device_handler)
+      .service(set_pin_handler)
+      .service(remove_pin_handler)
+  );
+}
@@ -0,0 +1,18 @@
+use actix_web::web;
+use crate::presentation::handlers::restaurants_handler::{
+  create_restaurant_handler, get_all_restaurants_handler,
+  export_restaurant_handler, deprovision_restaurant_handler,
+  change_subscription_handler
+};
+
+// Alta, listado, exportación, baja y plan de los tenants; solo para los super admins de la plataforma
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/api/v1/restaurants")
+      .service(create_restaurant_handler)
+      .service(get_all_restaurants_handler)
+      .service(export_restaurant_handler)
+      .service(deprovision_restaurant_handler)
+      .service(change_subscription_handler)
+  );
+}
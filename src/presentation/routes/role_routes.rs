@@ -1,16 +1,28 @@
 use actix_web::web;
+
+use crate::domain::entities::permission::{Action, Resource};
+use crate::presentation::middlewares::require_permission::RequirePermission;
+use crate::presentation::middlewares::rate_limiter::RateLimiter;
+use crate::domain::services::rate_limit::RateLimitScope;
 use crate::presentation::handlers::roles_handler::{
-  create_role_handler, get_all_roles_handler, get_role_by_id_handler, update_role_handler, delete_role_handler
+  create_role_handler, get_all_roles_handler, get_role_templates_handler, get_role_by_id_handler, update_role_handler, delete_role_handler,
+  set_role_inheritance_handler
 };
 
-//TODO: Agregar middleware para validar token estas rutas seran disponibles solo para usuarios con el rol de super admin
+// Solo usuarios con el permiso users:manage (rol super admin)
 pub fn routes(config: &mut web::ServiceConfig) {
   print!("Llega a roles_routes");
   
   config.service(
     web::scope("/api/v1/roles")
+      .wrap(RequirePermission::new(Resource::Users, Action::Manage))
+      // Por fuera del permiso: cuenta también las peticiones con claves rechazadas
+      .wrap(RateLimiter::new(RateLimitScope::ApiKeys))
       .service(create_role_handler)
       .service(get_all_roles_handler)
+      // Antes de /{id} para que "templates" e "inheritance" no se tomen por un id
+      .service(get_role_templates_handler)
+      .service(set_role_inheritance_handler)
       .service(get_role_by_id_handler)
       .service(update_role_handler)
       .service(delete_role_handler)
@@ -0,0 +1,17 @@
+use actix_web::web;
+use crate::presentation::handlers::subscriptions_handler::{
+  get_plans_handler, get_subscription_handler
+};
+
+// Planes y suscripción del restaurante; el cambio de plan va por /api/v1/restaurants
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/api/v1/plans")
+      .service(get_plans_handler)
+  );
+
+  config.service(
+    web::scope("/api/v1/subscription")
+      .service(get_subscription_handler)
+  );
+}
@@ -0,0 +1,10 @@
+use actix_web::web;
+use crate::presentation::handlers::tenants_handler::signup_handler;
+
+// Alta de restaurantes por su cuenta; es pública, sin tenant ni sesión
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/api/v1/tenants")
+      .service(signup_handler)
+  );
+}
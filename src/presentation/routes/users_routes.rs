@@ -4,7 +4,20 @@ use crate::presentation::handlers::users_handler::{
   get_all_users_handler,
   verification_user_handler,
   change_password_handler,
-  disable_user_handler
+  disable_user_handler,
+  unlock_user_handler,
+  impersonate_user_handler,
+  stop_impersonation_handler,
+  switch_location_handler,
+  get_my_role_tree_handler,
+  assign_role_handler,
+  unassign_role_handler
+};
+use crate::presentation::handlers::sessions_handler::{
+  get_my_sessions_handler,
+  revoke_session_handler,
+  revoke_all_sessions_handler,
+  get_user_sessions_handler
 };
 //TODO: Separar rutas get all y disable user for super admin only y verification user and change password for usuarios autenticados
 pub fn routes(config: &mut web::ServiceConfig) {
@@ -14,5 +27,17 @@ pub fn routes(config: &mut web::ServiceConfig) {
       .service(verification_user_handler)
       .service(change_password_handler)
       .service(disable_user_handler)
+      .service(unlock_user_handler)
+      .service(impersonate_user_handler)
+      .service(stop_impersonation_handler)
+      // `/me/...` antes que `/{id}/...` para que "me" no se tome como id
+      .service(switch_location_handler)
+      .service(get_my_role_tree_handler)
+      .service(get_my_sessions_handler)
+      .service(revoke_session_handler)
+      .service(revoke_all_sessions_handler)
+      .service(get_user_sessions_handler)
+      .service(assign_role_handler)
+      .service(unassign_role_handler)
   );
 }
\ No newline at end of file
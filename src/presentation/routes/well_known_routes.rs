@@ -0,0 +1,9 @@
+use actix_web::web;
+use crate::presentation::handlers::well_known_handler::jwks_handler;
+
+pub fn routes(config: &mut web::ServiceConfig) {
+  config.service(
+    web::scope("/.well-known")
+      .service(jwks_handler)
+  );
+}